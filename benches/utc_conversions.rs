@@ -0,0 +1,42 @@
+// Benchmarks bulk conversions to and from UTC, which look up the leap
+// second list.
+//
+// Run with `cargo bench --bench utc_conversions`
+
+use std::hint::black_box;
+
+use astrotime::{DateTime, Duration, Gregorian, Instant, Utc};
+
+const ITERATIONS: i64 = 1_000_000;
+
+fn main() {
+    let start: Instant =
+        From::from(DateTime::<Gregorian, Utc>::new(1960, 1, 1, 0, 0, 0, 0).unwrap());
+    let step = Duration::new(1901, 0); // about 60 years over all iterations
+
+    let timer = std::time::Instant::now();
+    let mut i = start;
+    for _ in 0..ITERATIONS {
+        let dt: DateTime<Gregorian, Utc> = From::from(black_box(i));
+        black_box(dt);
+        i = i + step;
+    }
+    report("Instant -> DateTime<Gregorian, Utc>", timer.elapsed());
+
+    let timer = std::time::Instant::now();
+    let mut dt: DateTime<Gregorian, Utc> = From::from(start);
+    for _ in 0..ITERATIONS {
+        let i: Instant = From::from(black_box(dt));
+        black_box(i);
+        dt = dt + step;
+    }
+    report("DateTime<Gregorian, Utc> -> Instant", timer.elapsed());
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn report(name: &str, elapsed: std::time::Duration) {
+    println!(
+        "{name}: {:.1} ns/iter ({ITERATIONS} iterations)",
+        elapsed.as_nanos() as f64 / ITERATIONS as f64
+    );
+}
@@ -40,6 +40,23 @@ fn main() {
     };
     println!("J1900.0: {:?}", j1900);
 
+    // ----------------------------------------------------
+    let ntp = {
+        // JD 2415020.5 (APPROX - modified because of UTC)
+        let e = DateTime::<Gregorian, Utc>::new(1900, 1, 1, 0, 0, 0, 0).unwrap();
+        let e: Instant = From::from(e);
+        e - Epoch::TimeStandard.as_instant()
+    };
+    println!("ntp: {:?}", ntp);
+
+    // ----------------------------------------------------
+    let b1950 = {
+        // JD 2433282.42345905 (from B = 1900.0 + (JD - 2415020.31352) / 365.242198781)
+        let e = Instant::from_julian_day_str("2433282.42345905").unwrap();
+        e - Epoch::TimeStandard.as_instant()
+    };
+    println!("B1950.0: {:?}", b1950);
+
     // ----------------------------------------------------
     // FIXME UTC
     let unix = {
@@ -59,6 +76,15 @@ fn main() {
     };
     println!("ts: {:?}", ts);
 
+    // ----------------------------------------------------
+    let y1977 = {
+        // JD 2443144.5 (APPROX - modified because of UTC)
+        let e = DateTime::<Gregorian, Utc>::new(1977, 1, 1, 0, 0, 0, 0).unwrap();
+        let e: Instant = From::from(e);
+        e - Epoch::TimeStandard.as_instant()
+    };
+    println!("Y1977: {:?}", y1977);
+
     // ----------------------------------------------------
     let j1991_25 = {
         // JD 2448349.0625 (verified at https://www.astronomyclub.xyz/celestial-sphere-2/epochs-for-coordinate-systems.html
@@ -112,8 +138,11 @@ Julian Epoch: Duration { secs: -211087684832, attos: -184000000000000000 }
 Julian Cal: Duration { secs: -62356694432, attos: -184000000000000000 }
 Gregorian Cal: Duration { secs: -62356521632, attos: -184000000000000000 }
 J1900.0: Duration { secs: -2429956832, attos: -184000000000000000 }
+ntp: Duration { secs: -2429913591, attos: 0 }
+B1950.0: Duration { secs: -852083445, attos: -322080000000000000 }
 unix: Duration { secs: -220924832, attos: -184000000000000000 }
 ts: Duration { secs: 0, attos: 0 }
+Y1977: Duration { secs: 16, attos: 0 }
 J1991.25: Duration { secs: 449674167, attos: 816000000000000000 }
 Y2K: Duration { secs: 725759967, attos: 816000000000000000 }
 J2000.0: Duration { secs: 725803167, attos: 816000000000000000 }
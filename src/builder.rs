@@ -0,0 +1,256 @@
+use core::marker::PhantomData;
+
+use crate::calendar::Calendar;
+use crate::date_time::DateTime;
+use crate::error::Error;
+use crate::standard::Standard;
+
+/// A builder for a `DateTime`, which accumulates fields and then checks them
+/// all at once in `build`()
+///
+/// Fields which are not given default to the start of the period: year 1,
+/// month 1, day 1, and zero for the time of day.
+///
+/// ```
+/// # use astrotime::{DateTime, DateTimeBuilder, Gregorian, Utc};
+/// let dt = DateTimeBuilder::<Gregorian, Utc>::new()
+///     .year(2016)
+///     .month(12)
+///     .day(31)
+///     .hour(23)
+///     .minute(59)
+///     .second(60)
+///     .build()
+///     .unwrap();
+/// assert_eq!(dt, DateTime::new(2016, 12, 31, 23, 59, 60, 0).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DateTimeBuilder<C: Calendar, S: Standard> {
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    attosecond: u64,
+    _cal: PhantomData<C>,
+    _std: PhantomData<S>,
+}
+
+impl<C: Calendar, S: Standard> Default for DateTimeBuilder<C, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: Calendar, S: Standard> DateTimeBuilder<C, S> {
+    /// A builder with every field at its default
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            year: 1,
+            month: 1,
+            day: 1,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            attosecond: 0,
+            _cal: PhantomData,
+            _std: PhantomData,
+        }
+    }
+
+    /// Set the year
+    #[must_use]
+    pub const fn year(mut self, year: i32) -> Self {
+        self.year = year;
+        self
+    }
+
+    /// Set the month, from 1 .. 12
+    #[must_use]
+    pub const fn month(mut self, month: u8) -> Self {
+        self.month = month;
+        self
+    }
+
+    /// Set the day of the month, from 1 .. 31
+    #[must_use]
+    pub const fn day(mut self, day: u8) -> Self {
+        self.day = day;
+        self
+    }
+
+    /// Set the hour, from 0 .. 23
+    #[must_use]
+    pub const fn hour(mut self, hour: u8) -> Self {
+        self.hour = hour;
+        self
+    }
+
+    /// Set the minute, from 0 .. 59
+    #[must_use]
+    pub const fn minute(mut self, minute: u8) -> Self {
+        self.minute = minute;
+        self
+    }
+
+    /// Set the second, from 0 .. 60
+    #[must_use]
+    pub const fn second(mut self, second: u8) -> Self {
+        self.second = second;
+        self
+    }
+
+    /// Set the attosecond, from 0 .. `999_999_999_999_999_999`
+    #[must_use]
+    pub const fn attosecond(mut self, attosecond: u64) -> Self {
+        self.attosecond = attosecond;
+        self
+    }
+
+    /// Check the fields and build the `DateTime`
+    ///
+    /// Unlike `DateTime::new`(), second 60 is only accepted at 23:59 on a day
+    /// which ends in a leap second, so it is never accepted under a continuous
+    /// time `Standard`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::FieldRangeError` naming the first field (from the
+    /// month down to the attosecond) which is out of range.
+    #[allow(clippy::manual_range_contains)]
+    pub fn build(&self) -> Result<DateTime<C, S>, Error> {
+        if self.month < 1 || self.month > 12 {
+            return Err(Error::FieldRangeError("month"));
+        }
+        if self.day < 1 || self.day > C::month_days(self.month, self.year) {
+            return Err(Error::FieldRangeError("day"));
+        }
+        if self.hour > 23 {
+            return Err(Error::FieldRangeError("hour"));
+        }
+        if self.minute > 59 {
+            return Err(Error::FieldRangeError("minute"));
+        }
+        if self.second > 60 {
+            return Err(Error::FieldRangeError("second"));
+        }
+        if self.attosecond > 999_999_999_999_999_999 {
+            return Err(Error::FieldRangeError("attosecond"));
+        }
+
+        let dt = unsafe {
+            DateTime::new_unchecked(
+                self.year,
+                self.month,
+                self.day,
+                self.hour,
+                self.minute,
+                self.second,
+                self.attosecond,
+            )
+        };
+        if self.second == 60 && (self.hour != 23 || self.minute != 59 || dt.leap_second_sign() != 1)
+        {
+            return Err(Error::FieldRangeError("second"));
+        }
+        Ok(dt)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::DateTimeBuilder;
+    use crate::calendar::{Gregorian, Julian};
+    use crate::date_time::DateTime;
+    use crate::error::Error;
+    use crate::standard::{Tt, Utc, UtcSmeared};
+
+    #[test]
+    fn test_builder() {
+        crate::setup_logging();
+
+        let dt = DateTimeBuilder::<Gregorian, Tt>::new()
+            .year(1969)
+            .month(7)
+            .day(20)
+            .hour(20)
+            .minute(17)
+            .second(40)
+            .attosecond(5)
+            .build()
+            .unwrap();
+        assert_eq!(dt, DateTime::new(1969, 7, 20, 20, 17, 40, 5).unwrap());
+
+        // Missing fields start the period
+        let dt = DateTimeBuilder::<Julian, Tt>::new()
+            .year(2000)
+            .month(3)
+            .build()
+            .unwrap();
+        assert_eq!(dt, DateTime::new(2000, 3, 1, 0, 0, 0, 0).unwrap());
+        let dt = DateTimeBuilder::<Gregorian, Tt>::default().build().unwrap();
+        assert_eq!(dt, DateTime::default());
+
+        // The failing field is named
+        let b = DateTimeBuilder::<Gregorian, Utc>::new()
+            .year(2023)
+            .month(2)
+            .day(29);
+        assert!(matches!(b.build(), Err(Error::FieldRangeError("day"))));
+        assert!(matches!(
+            b.day(1).month(13).build(),
+            Err(Error::FieldRangeError("month"))
+        ));
+        assert!(matches!(
+            b.day(1).minute(60).build(),
+            Err(Error::FieldRangeError("minute"))
+        ));
+        assert!(matches!(
+            b.day(1).attosecond(1_000_000_000_000_000_000).build(),
+            Err(Error::FieldRangeError("attosecond"))
+        ));
+
+        // Leap seconds only where they happened
+        let b = DateTimeBuilder::<Gregorian, Utc>::new()
+            .year(2016)
+            .month(12)
+            .day(31)
+            .hour(23)
+            .minute(59)
+            .second(60);
+        assert!(b.build().is_ok());
+        assert!(matches!(
+            b.day(30).build(),
+            Err(Error::FieldRangeError("second"))
+        ));
+        assert!(matches!(
+            b.minute(58).build(),
+            Err(Error::FieldRangeError("second"))
+        ));
+        let tt = DateTimeBuilder::<Gregorian, Tt>::new()
+            .year(2016)
+            .month(12)
+            .day(31)
+            .hour(23)
+            .minute(59)
+            .second(60);
+        assert!(matches!(tt.build(), Err(Error::FieldRangeError("second"))));
+
+        // Smeared leap seconds are never shown, on either side of midnight
+        for (year, month, day) in [(2016, 12, 31), (2017, 1, 1)] {
+            let smeared = DateTimeBuilder::<Gregorian, UtcSmeared>::new()
+                .year(year)
+                .month(month)
+                .day(day)
+                .hour(23)
+                .minute(59)
+                .second(60);
+            assert!(matches!(
+                smeared.build(),
+                Err(Error::FieldRangeError("second"))
+            ));
+        }
+    }
+}
@@ -83,14 +83,9 @@ pub trait Calendar {
             365*y
 
             // leap year first approximation
-                + y/4
-
-            // For dates before 1 B.C. (year 0) March 1st, we need to subtract 1 more day since
-            // 1 B.C. (year 0) is a leap year that our calculations above didn't catch.
-            // (To be branchless, we just use the sign bit from the year; i64 type stays negative)
-
-                //- (if y<0 { 1 } else { 0 })
-                + (y>>63)
+            // (Floored division, so that years before 1 B.C. (year 0) count their leap days
+            // correctly; truncating division would be off by one in every fourth year)
+                + y.div_euclid(4)
 
             // The number of days between march 1st and the start of the mth month
             // after march (brilliant!) (306 is the days in the 10 months from mar-dec)
@@ -103,9 +98,9 @@ pub trait Calendar {
         if <Self as Calendar>::is_gregorian() {
             day = day
             // leap year second approximation, Gregorian
-                - y/100
+                - y.div_euclid(100)
             // leap year third approximation, Gregorian
-                + y/400;
+                + y.div_euclid(400);
         }
 
         // revert back to january 1 basis (we were at march 1st, we need to move ahead)
@@ -130,10 +125,7 @@ pub trait Calendar {
     /// # Errors
     ///
     /// Will return a `Error::RangeError` if `day_number` is out of range.
-    ///
-    /// # Panics
-    ///
-    /// Panics on assertions that should only fail if there is a bug.
+    /// This never panics.
     #[allow(clippy::cast_sign_loss)]
     #[allow(clippy::cast_possible_truncation)]
     fn from_day_number(mut day_number: i64) -> Result<(i32, u8, u8), Error> {
@@ -159,16 +151,18 @@ pub trait Calendar {
             365_2500
         };
 
-        // Calculate the year (march 1st basis)
-        let mut offset_year: i64 = (10_000 * day_number + 14780) / days_in_year_times_10000;
+        // Estimate the year (march 1st basis). The leap days put the start of
+        // year y within 2 days of y * 365.2425 (or y * 365.25 for Julian), so
+        // this is never more than one year out in either direction.
+        let mut offset_year: i64 =
+            (10_000 * day_number + 14780).div_euclid(days_in_year_times_10000);
 
         // Caculate the remaining days
         let calc_remaining_days = |day_number: i64, offset_year: i64| -> i64 {
-            let zeroeth_year = offset_year >> 63;
-            let mut remaining_days =
-                day_number - 365 * offset_year - offset_year / 4 - zeroeth_year;
+            let mut remaining_days = day_number - 365 * offset_year - offset_year.div_euclid(4);
             if <Self as Calendar>::is_gregorian() {
-                remaining_days = remaining_days + offset_year / 100 - offset_year / 400;
+                remaining_days =
+                    remaining_days + offset_year.div_euclid(100) - offset_year.div_euclid(400);
             }
             remaining_days
         };
@@ -176,8 +170,17 @@ pub trait Calendar {
         if remaining_days < 0 {
             offset_year -= 1;
             remaining_days = calc_remaining_days(day_number, offset_year);
+        } else {
+            let next_remaining_days = calc_remaining_days(day_number, offset_year + 1);
+            if next_remaining_days >= 0 {
+                offset_year += 1;
+                remaining_days = next_remaining_days;
+            }
         }
 
+        // Now 0 <= remaining_days < 366, so the month and day below are always
+        // in range
+
         let offset_month = (100 * remaining_days + 52) / 3060;
 
         // come back from our march-1st basis
@@ -185,12 +188,12 @@ pub trait Calendar {
         let year = offset_year + (offset_month + 2) / 12;
 
         let month = (offset_month + 2) % 12;
-        assert!(month >= 0);
-        assert!(month < 12);
+        debug_assert!(month >= 0);
+        debug_assert!(month < 12);
 
         let day = remaining_days - (offset_month * 306 + 5) / 10;
-        assert!(day < 31);
-        assert!(day >= 0);
+        debug_assert!(day < 31);
+        debug_assert!(day >= 0);
 
         Ok((year as i32, (month + 1) as u8, (day + 1) as u8))
     }
@@ -215,6 +218,49 @@ pub trait Calendar {
     }
 }
 
+// The number of days from the start of the Julian calendar to the start of the
+// Gregorian calendar. Day numbers in each calendar count whole days from that
+// calendar's own epoch, so converting a day number between the calendars is a
+// constant shift by this amount across the entire proleptic range, even though
+// the difference between their month/day labels grows by about three days every
+// four centuries.
+pub const JULIAN_TO_GREGORIAN_EPOCH_DAYS: i64 = (Epoch::GregorianCalendar.as_instant().0.secs
+    - Epoch::JulianCalendar.as_instant().0.secs)
+    / 86400;
+
+/// Convert a (year, month, day) in the proleptic Gregorian calendar into the
+/// (year, month, day) of the same day in the proleptic Julian calendar
+///
+/// # Errors
+///
+/// Will return `Error::RangeError` if the input is not a valid Gregorian date,
+/// or if the Julian date would be out of range.
+pub fn gregorian_to_julian(year: i32, month: u8, day: u8) -> Result<(i32, u8, u8), Error> {
+    let dn = checked_day_number::<Gregorian>(year, month, day)?;
+    Julian::from_day_number(dn + JULIAN_TO_GREGORIAN_EPOCH_DAYS)
+}
+
+/// Convert a (year, month, day) in the proleptic Julian calendar into the
+/// (year, month, day) of the same day in the proleptic Gregorian calendar
+///
+/// # Errors
+///
+/// Will return `Error::RangeError` if the input is not a valid Julian date, or
+/// if the Gregorian date would be out of range.
+pub fn julian_to_gregorian(year: i32, month: u8, day: u8) -> Result<(i32, u8, u8), Error> {
+    let dn = checked_day_number::<Julian>(year, month, day)?;
+    Gregorian::from_day_number(dn - JULIAN_TO_GREGORIAN_EPOCH_DAYS)
+}
+
+// The day number of a date, which unlike `Calendar::day_number`() must have a
+// day within the month
+fn checked_day_number<C: Calendar>(year: i32, month: u8, day: u8) -> Result<i64, Error> {
+    if !(1..=12).contains(&month) || day < 1 || day > C::month_days(month, year) {
+        return Err(Error::RangeError);
+    }
+    C::day_number(year, month, i64::from(day))
+}
+
 #[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Julian;
@@ -236,8 +282,76 @@ impl Calendar for Gregorian {
 }
 
 #[cfg(test)]
+#[allow(clippy::unreadable_literal)]
 mod test {
-    use super::{Calendar, Gregorian, Julian};
+    use super::{gregorian_to_julian, julian_to_gregorian, Calendar, Gregorian, Julian};
+    use crate::error::Error;
+
+    #[test]
+    fn test_convert_raw_dates() {
+        crate::setup_logging();
+
+        // The Gregorian reform
+        assert_eq!(julian_to_gregorian(1582, 10, 5).unwrap(), (1582, 10, 15));
+        assert_eq!(gregorian_to_julian(1582, 10, 15).unwrap(), (1582, 10, 5));
+        assert_eq!(julian_to_gregorian(1582, 10, 4).unwrap(), (1582, 10, 14));
+
+        // A Julian leap day that the Gregorian calendar does not have
+        assert_eq!(julian_to_gregorian(1900, 2, 29).unwrap(), (1900, 3, 13));
+        assert!(matches!(
+            gregorian_to_julian(1900, 2, 29),
+            Err(Error::RangeError)
+        ));
+
+        // The proleptic far past
+        assert_eq!(julian_to_gregorian(-4713, 1, 1).unwrap(), (-4714, 11, 24));
+        assert_eq!(
+            gregorian_to_julian(-2000000, 1, 1).unwrap(),
+            (-1999959, 1, 27)
+        );
+        assert_eq!(
+            julian_to_gregorian(-1999959, 1, 27).unwrap(),
+            (-2000000, 1, 1)
+        );
+
+        assert!(gregorian_to_julian(2000, 13, 1).is_err());
+        assert!(julian_to_gregorian(2000, 4, 31).is_err());
+        assert!(julian_to_gregorian(i32::MAX, 12, 31).is_err());
+    }
+
+    #[test]
+    fn test_from_day_number_across_the_range() {
+        fn check<C: Calendar>(min: i64, max: i64) {
+            // A dense sample across the whole range, every day near its ends
+            // and around the epoch, and everything out of range is an error
+            let step = (max - min) / 1_000_000;
+            let sample = (0..=1_000_000)
+                .map(|i| min + i * step)
+                .chain(min..min + 2000)
+                .chain(max - 2000..=max)
+                .chain(-2000..2000);
+            for day_number in sample {
+                let (year, month, day) = C::from_day_number(day_number).unwrap();
+                assert!((1..=12).contains(&month));
+                assert!(day >= 1 && day <= C::month_days(month, year));
+                assert_eq!(
+                    C::day_number(year, month, i64::from(day)).unwrap(),
+                    day_number
+                );
+            }
+            for day_number in [i64::MIN, min - 1, max + 1, i64::MAX] {
+                assert!(matches!(
+                    C::from_day_number(day_number),
+                    Err(Error::RangeError)
+                ));
+            }
+        }
+
+        crate::setup_logging();
+
+        check::<Gregorian>(-784_352_296_671, 784_352_295_938);
+        check::<Julian>(-784_368_402_798, 784_368_402_065);
+    }
 
     #[test]
     fn test_gregorian_julian_date_matches() {
@@ -404,4 +518,52 @@ mod test {
         assert_eq!(m, 12);
         assert_eq!(d, 31);
     }
+
+    #[test]
+    fn test_calendar_negative_leap_years() {
+        crate::setup_logging();
+
+        // Every day before 1 B.C. gets exactly one day number, including around
+        // the leap days of negative years
+        for cal_is_gregorian in [true, false] {
+            for year in [-2000000, -400, -100, -8, -5, -4, -3, -1, 0] {
+                let dn = |m, d| {
+                    if cal_is_gregorian {
+                        Gregorian::day_number(year, m, d).unwrap()
+                    } else {
+                        Julian::day_number(year, m, d).unwrap()
+                    }
+                };
+                let leap = if cal_is_gregorian {
+                    Gregorian::is_year_leap(year)
+                } else {
+                    Julian::is_year_leap(year)
+                };
+                let next_year = if cal_is_gregorian {
+                    Gregorian::day_number(year + 1, 1, 1).unwrap()
+                } else {
+                    Julian::day_number(year + 1, 1, 1).unwrap()
+                };
+                assert_eq!(dn(3, 1) - dn(2, 28), if leap { 2 } else { 1 });
+                assert_eq!(next_year - dn(1, 1), if leap { 366 } else { 365 });
+            }
+        }
+
+        assert_eq!(
+            Julian::from_day_number(Julian::day_number(-4, 2, 29).unwrap()).unwrap(),
+            (-4, 2, 29)
+        );
+        assert_eq!(
+            Julian::from_day_number(Julian::day_number(-4, 3, 1).unwrap()).unwrap(),
+            (-4, 3, 1)
+        );
+        assert_eq!(
+            Gregorian::from_day_number(Gregorian::day_number(-400, 2, 29).unwrap()).unwrap(),
+            (-400, 2, 29)
+        );
+        assert_eq!(
+            Gregorian::from_day_number(Gregorian::day_number(-300, 3, 1).unwrap()).unwrap(),
+            (-300, 3, 1)
+        );
+    }
 }
@@ -1,17 +1,28 @@
-use std::cmp::{Ordering, PartialEq};
-use std::convert::TryFrom;
-use std::fmt;
-use std::hash::{Hash, Hasher};
-use std::marker::PhantomData;
-use std::ops::{Add, Sub};
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+use core::cmp::{Ordering, PartialEq};
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::{Add, Sub};
+use core::str::FromStr;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::calendar::{Calendar, Gregorian, Julian};
+use crate::calendar::{Calendar, Gregorian, Julian, JULIAN_TO_GREGORIAN_EPOCH_DAYS};
 use crate::duration::Duration;
+use crate::era::Era;
 use crate::error::Error;
-use crate::standard::Standard;
+use crate::fixed_offset::FixedOffsetDateTime;
+use crate::instant::Instant;
+use crate::leaps::LeapPolicy;
+use crate::month::Month;
+use crate::period::Period;
+use crate::standard::{Standard, Utc};
+use crate::weekday::Weekday;
 
 /// A calendar date and time, with attosecond precision, representing the
 /// time elapsed since the start of the Common Era in a traditional way
@@ -43,15 +54,18 @@ use crate::standard::Standard;
 /// normal usage period of the `Calendar`.
 ///
 /// The oldest date representable is `-2147483648-01-01 00:00:00.000000000000000000`
+/// (`DateTime::MIN`)
 ///
 /// The newest date representable is `2147483647-12-31 23:59:59.999999999999999999`
+/// (`DateTime::MAX`)
 ///
 /// Internally this is stored in a packed format and is 128 bits in size.
 ///
 /// This represents the same thing that an `Instant` does, but it makes `Calendar` data
 /// easier to work with, and has such date precomputed and packed within.
-#[derive(Clone, Copy)] // is also Send
+// is also Send
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[allow(clippy::unsafe_derive_deserialize)]
 pub struct DateTime<C: Calendar, S: Standard> {
     packed: u64,
     attos: u64,
@@ -65,7 +79,7 @@ const SECOND_BITS: u64 = 0x0000_0000_FC00_0000;
 const MINUTE_BITS: u64 = 0x0000_0000_03F0_0000;
 const HOUR_BITS: u64 = 0x0000_0000_000F_8000;
 const DAY0_BITS: u64 = 0x0000_0000_0000_7C00;
-const _RESERVED_BITS: u64 = 0x0000_0000_0000_03F0;
+const RESERVED_BITS: u64 = 0x0000_0000_0000_03F0;
 const MONTH0_BITS: u64 = 0x0000_0000_0000_000F;
 // We pack all values (except attos) into a u64 at the following offsets:
 const YEAR_OFFSET: usize = 32;
@@ -76,15 +90,26 @@ const DAY0_OFFSET: usize = 10;
 const MONTH0_OFFSET: usize = 0;
 
 // Pack a value into the packed field
+//
+// A value too wide for its field would spill into the neighboring fields, so
+// this is checked in debug builds.
 #[inline]
-fn pack(packed: &mut u64, bits: u64, offset: usize, value: u64) {
+const fn pack(packed: &mut u64, bits: u64, offset: usize, value: u64) {
+    debug_assert!(
+        value <= bits >> offset,
+        "value does not fit in its packed field"
+    );
     *packed &= !bits; // zero
     *packed |= value << offset; // set
 }
 
 // Pack a value into the packed field, only if you know it's already zero
 #[inline]
-fn pack_without_clearing(packed: &mut u64, offset: usize, value: u64) {
+const fn pack_without_clearing(packed: &mut u64, bits: u64, offset: usize, value: u64) {
+    debug_assert!(
+        value <= bits >> offset,
+        "value does not fit in its packed field"
+    );
     *packed |= value << offset; // set
 }
 
@@ -94,7 +119,49 @@ const fn unpack(packed: u64, bits: u64, offset: usize) -> u64 {
     (packed & bits) >> offset
 }
 
+// In order to preserve as much precision as we can when computing the time of
+// day as a float, we count in units of 10^-14 seconds (10,000 attoseconds). A
+// 24-hour duration of these won't overflow a u64. Anything smaller would.
+const TIME_OF_DAY_FACTOR: u64 = 100_000_000_000_000;
+
+// Compute a key from the packed field whose unsigned integer ordering is
+// chronological (when compared alongside the attoseconds).
+//
+// The packed field is not itself chronological: the year is signed (two's
+// complement) and the second and minute are packed above the hour, day and
+// month. So we flip the sign bit of the year, and repack the lower fields in
+// order of significance (month, day, hour, minute, second) into the lower 26
+// bits. The reserved bits are dropped.
+#[inline]
+const fn chronological_key(packed: u64) -> u64 {
+    ((packed ^ (1 << 63)) & YEAR_BITS)
+        | unpack(packed, MONTH0_BITS, MONTH0_OFFSET) << 22
+        | unpack(packed, DAY0_BITS, DAY0_OFFSET) << 17
+        | unpack(packed, HOUR_BITS, HOUR_OFFSET) << 12
+        | unpack(packed, MINUTE_BITS, MINUTE_OFFSET) << 6
+        | unpack(packed, SECOND_BITS, SECOND_OFFSET)
+}
+
+// The fraction of a second as written in ISO 8601: a `.` and the digits without
+// trailing zeroes, or nothing if it is zero
+pub fn iso8601_fraction(attosecond: u64) -> String {
+    if attosecond == 0 {
+        return String::new();
+    }
+    let fraction = format!("{attosecond:018}");
+    format!(".{}", fraction.trim_end_matches('0'))
+}
+
 impl<C: Calendar, S: Standard> DateTime<C, S> {
+    /// The oldest `DateTime`, `-2147483648-01-01 00:00:00.000000000000000000`
+    pub const MIN: Self = unsafe { Self::new_unchecked(i32::MIN, 1, 1, 0, 0, 0, 0) };
+
+    /// The newest `DateTime`, `2147483647-12-31 23:59:59.999999999999999999`
+    ///
+    /// Adding any positive `Duration` to this panics.
+    pub const MAX: Self =
+        unsafe { Self::new_unchecked(i32::MAX, 12, 31, 23, 59, 59, 999_999_999_999_999_999) };
+
     /// Create a new `DateTime` with the given parts.
     ///
     /// # Safety
@@ -104,7 +171,7 @@ impl<C: Calendar, S: Standard> DateTime<C, S> {
     #[allow(clippy::cast_sign_loss)]
     #[allow(clippy::cast_lossless)]
     #[must_use]
-    pub unsafe fn new_unchecked(
+    pub const unsafe fn new_unchecked(
         year: i32,
         month: u8,
         day: u8,
@@ -114,12 +181,12 @@ impl<C: Calendar, S: Standard> DateTime<C, S> {
         attosecond: u64,
     ) -> Self {
         let mut packed: u64 = 0;
-        pack_without_clearing(&mut packed, YEAR_OFFSET, year as u64);
-        pack_without_clearing(&mut packed, SECOND_OFFSET, second as u64);
-        pack_without_clearing(&mut packed, MINUTE_OFFSET, minute as u64);
-        pack_without_clearing(&mut packed, HOUR_OFFSET, hour as u64);
-        pack_without_clearing(&mut packed, DAY0_OFFSET, (day - 1) as u64);
-        pack_without_clearing(&mut packed, MONTH0_OFFSET, (month - 1) as u64);
+        pack_without_clearing(&mut packed, YEAR_BITS, YEAR_OFFSET, year as u32 as u64);
+        pack_without_clearing(&mut packed, SECOND_BITS, SECOND_OFFSET, second as u64);
+        pack_without_clearing(&mut packed, MINUTE_BITS, MINUTE_OFFSET, minute as u64);
+        pack_without_clearing(&mut packed, HOUR_BITS, HOUR_OFFSET, hour as u64);
+        pack_without_clearing(&mut packed, DAY0_BITS, DAY0_OFFSET, (day - 1) as u64);
+        pack_without_clearing(&mut packed, MONTH0_BITS, MONTH0_OFFSET, (month - 1) as u64);
 
         Self {
             packed,
@@ -193,13 +260,144 @@ impl<C: Calendar, S: Standard> DateTime<C, S> {
         Self::new(year, month, day, hour, minute, second, attosecond)
     }
 
+    /// Create a new `DateTime` from the given parts, with a historical year
+    ///
+    /// Historical years have no year zero: year 1 `Era::Ad` is ISO year 1, year
+    /// 1 `Era::Bc` is ISO year 0, and in general year _n_ `Era::Bc` is ISO year
+    /// 1-_n_. Other values must be within normal ranges. See `DateTime` for
+    /// details.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if the year is zero or does not fit in
+    /// the range of years, or if any other input is outside of the normal range
+    /// (see `new`()).
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_historical(
+        era: Era,
+        year: u32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        attosecond: u64,
+    ) -> Result<Self, Error> {
+        if year == 0 {
+            return Err(Error::RangeError);
+        }
+        let year = match era {
+            Era::Ad => i64::from(year),
+            Era::Bc => 1 - i64::from(year),
+        };
+        let year = i32::try_from(year).map_err(|_| Error::RangeError)?;
+        Self::new(year, month, day, hour, minute, second, attosecond)
+    }
+
+    /// The packed representation of this `DateTime`, as a pair of integers
+    ///
+    /// This is a compact form for storage or transmission which can be turned
+    /// back into a `DateTime` with `from_packed`(). The second integer is the
+    /// attosecond. The first packs the other fields as follows (bit 0 being the
+    /// least significant):
+    ///
+    /// * bits 32 .. 63: year (as a two's complement i32)
+    /// * bits 26 .. 31: second
+    /// * bits 20 .. 25: minute
+    /// * bits 15 .. 19: hour
+    /// * bits 10 .. 14: day of the month, minus one
+    /// * bits 4 .. 9: reserved, always zero
+    /// * bits 0 .. 3: month, minus one
+    ///
+    /// This layout is stable. Note that comparing packed values as integers does
+    /// not give chronological order.
+    #[must_use]
+    pub const fn to_packed(&self) -> (u64, u64) {
+        (self.packed, self.attos)
+    }
+
+    /// Create a `DateTime` from the packed representation produced by
+    /// `to_packed`()
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if any of the packed fields is out of its
+    /// normal range (see `new`()), or if any reserved bit is set, which can only
+    /// happen if the data was corrupted.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_packed(packed: u64, attos: u64) -> Result<Self, Error> {
+        if packed & RESERVED_BITS != 0 {
+            return Err(Error::RangeError);
+        }
+        let dt = Self::new(
+            unpack(packed, YEAR_BITS, YEAR_OFFSET) as i32,
+            unpack(packed, MONTH0_BITS, MONTH0_OFFSET) as u8 + 1,
+            unpack(packed, DAY0_BITS, DAY0_OFFSET) as u8 + 1,
+            unpack(packed, HOUR_BITS, HOUR_OFFSET) as u8,
+            unpack(packed, MINUTE_BITS, MINUTE_OFFSET) as u8,
+            unpack(packed, SECOND_BITS, SECOND_OFFSET) as u8,
+            attos,
+        )?;
+        debug_assert_eq!(dt.packed, packed);
+        Ok(dt)
+    }
+
+    /// Check that every field is within its normal range
+    ///
+    /// A `DateTime` made with `new_unchecked`(), or deserialized from untrusted
+    /// data, may not be. Second 60 is only valid at 23:59 on a day which ends
+    /// in a leap second, so it is never valid under a continuous time
+    /// `Standard`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::FieldRangeError` naming the first field (from the
+    /// month down to the attosecond) which is out of range, or naming
+    /// `"reserved"` if a reserved bit of the packed representation is set.
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.packed & RESERVED_BITS != 0 {
+            return Err(Error::FieldRangeError("reserved"));
+        }
+        if self.month() > 12 {
+            return Err(Error::FieldRangeError("month"));
+        }
+        if self.day() > C::month_days(self.month(), self.year()) {
+            return Err(Error::FieldRangeError("day"));
+        }
+        if self.hour() > 23 {
+            return Err(Error::FieldRangeError("hour"));
+        }
+        if self.minute() > 59 {
+            return Err(Error::FieldRangeError("minute"));
+        }
+        if self.second() > 60
+            || (self.second() == 60
+                && (self.hour() != 23 || self.minute() != 59 || self.leap_second_sign() != 1))
+        {
+            return Err(Error::FieldRangeError("second"));
+        }
+        if self.attos > 999_999_999_999_999_999 {
+            return Err(Error::FieldRangeError("attosecond"));
+        }
+        Ok(())
+    }
+
+    /// Whether every field is within its normal range
+    ///
+    /// See `validate`().
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
     /// Create a new `DateTime` from the given parts.
     ///
     /// Values that are out of normal ranges are allowed, including values that are negative.
     /// This function will adjust the input your provide into a normal form.
     ///
     /// The types we are working with are large i64 types, but they can still overflow.
-    /// Overflow is not detected or reported (FIXME).
+    /// Overflow is not detected or reported here; use `try_new_abnormal`() if the
+    /// inputs may be extreme.
     ///
     /// # Panics
     ///
@@ -287,6 +485,110 @@ impl<C: Calendar, S: Standard> DateTime<C, S> {
         }
     }
 
+    /// Create a new `DateTime` from the given parts, detecting overflow.
+    ///
+    /// This normalizes the input just like `new_abnormal`(), but uses checked
+    /// arithmetic throughout.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if the normalized date would fall
+    /// outside of the range of years that a `DateTime` can represent, or if an
+    /// intermediate value would overflow.
+    #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn try_new_abnormal(
+        year: i32,
+        month: i64,
+        day: i64,
+        hour: i64,
+        minute: i64,
+        second: i64,
+        attosecond: i64,
+    ) -> Result<Self, Error> {
+        use crate::divmod_i64;
+
+        let month0 = month.checked_sub(1).ok_or(Error::RangeError)?;
+        let day0 = day.checked_sub(1).ok_or(Error::RangeError)?;
+
+        // roll up attoseconds into seconds (handling negative values)
+        let (div, attosecond) = divmod_i64(attosecond, 1_000_000_000_000_000_000);
+        let second = second.checked_add(div).ok_or(Error::RangeError)?;
+
+        // roll up seconds into minutes
+        let (div, second) = divmod_i64(second, 60);
+        let minute = minute.checked_add(div).ok_or(Error::RangeError)?;
+
+        // roll up minutes into hours
+        let (div, minute) = divmod_i64(minute, 60);
+        let hour = hour.checked_add(div).ok_or(Error::RangeError)?;
+
+        // roll up hours into days
+        let (div, hour) = divmod_i64(hour, 24);
+        let day0 = day0.checked_add(div).ok_or(Error::RangeError)?;
+
+        // roll up months into years
+        let (div, month0) = divmod_i64(month0, 12);
+        let year = i64::from(year)
+            .checked_add(div)
+            .and_then(|y| i32::try_from(y).ok())
+            .ok_or(Error::RangeError)?;
+
+        // Compute the day number from the first of the month, so that a huge
+        // day count cannot overflow inside of C::day_number()
+        let dn = C::day_number(year, month0 as u8 + 1, 1)?
+            .checked_add(day0)
+            .ok_or(Error::RangeError)?;
+
+        // Now set the date from that day number
+        let (y, m, d) = C::from_day_number(dn)?;
+
+        Ok(unsafe {
+            Self::new_unchecked(
+                y,
+                m,
+                d,
+                hour as u8,
+                minute as u8,
+                second as u8,
+                attosecond as u64,
+            )
+        })
+    }
+
+    /// Put this `DateTime` into its canonical form
+    ///
+    /// `DateTime`s made by this crate are always canonical, but one assembled
+    /// from outside data (such as by deserializing) might have reserved bits set,
+    /// or fields out of their normal range. This clears the reserved bits, and
+    /// carries any out of range fields into the next larger unit, as
+    /// `new_abnormal`() does. Fields are left out of range only if carrying them
+    /// would overflow the year.
+    ///
+    /// Equality, hashing and ordering all ignore the reserved bits, so they are
+    /// consistent with each other whether or not this has been called.
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn canonicalize(&mut self) {
+        self.packed &= !RESERVED_BITS;
+        let (year, month, day) = self.date();
+        let (hour, minute, second, attosecond) = self.time();
+        if Self::new(year, month, day, hour, minute, second, attosecond).is_ok() {
+            return;
+        }
+        let carried = Self::try_new_abnormal(
+            year,
+            i64::from(month),
+            i64::from(day),
+            i64::from(hour),
+            i64::from(minute),
+            i64::from(second) + (attosecond / 1_000_000_000_000_000_000) as i64,
+            (attosecond % 1_000_000_000_000_000_000) as i64,
+        );
+        if let Ok(dt) = carried {
+            *self = dt;
+        }
+    }
+
     /// Create a `DateTime` from a day number (integer).
     ///
     /// January 1st of 1 A.D. (Common Era) is the epoch and has a day number of 0.
@@ -354,6 +656,79 @@ impl<C: Calendar, S: Standard> DateTime<C, S> {
         Ok(unsafe { Self::new_unchecked(year, month, day, hour, min, sec, atto) })
     }
 
+    /// Create a `DateTime` from a day number, the seconds into that day, and
+    /// attoseconds (exact).
+    ///
+    /// January 1st of 1 A.D. (Common Era) is the epoch and has a day number of 0.
+    ///
+    /// Unlike `from_day_number_and_fraction`(), no floating point is involved.
+    /// On a day which ends with an inserted leap second, `secs_into_day` may be
+    /// 86400, which is 23:59:60.
+    ///
+    /// # Errors
+    ///
+    /// Will return a `Error::RangeError` if `day_number` is out of range, if
+    /// `secs_into_day` is not within the length of that day, or if `attos` is
+    /// a whole second or more.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_day_number_and_attos(
+        day_number: i64,
+        secs_into_day: u32,
+        attos: u64,
+    ) -> Result<Self, Error> {
+        if attos > 999_999_999_999_999_999 {
+            return Err(Error::RangeError);
+        }
+        let start = Self::from_day_number(day_number)?;
+        if i64::from(secs_into_day) >= 86400 + start.day_leap_seconds() {
+            return Err(Error::RangeError);
+        }
+
+        // Any leap second is in the last minute of the day
+        let (hour, min, sec) = if secs_into_day >= 86340 {
+            (23, 59, secs_into_day - 86340)
+        } else {
+            (
+                secs_into_day / 3600,
+                secs_into_day / 60 % 60,
+                secs_into_day % 60,
+            )
+        };
+        Ok(unsafe {
+            Self::new_unchecked(
+                start.year(),
+                start.month(),
+                start.day(),
+                hour as u8,
+                min as u8,
+                sec as u8,
+                attos,
+            )
+        })
+    }
+
+    /// Create a `DateTime` from a year and a fractional day of the year, where
+    /// 1.0 is midnight at the start of January 1st
+    ///
+    /// This is the form of orbital element epochs. It has the same precision as
+    /// `from_day_number_and_fraction`().
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if `day_of_year` is less than 1.0, or is
+    /// not before the end of the last day of the year.
+    pub fn from_year_and_fractional_day(year: i32, day_of_year: f64) -> Result<Self, Error> {
+        let days = if C::is_year_leap(year) { 366.0 } else { 365.0 };
+        // written so that NaN is out of range too
+        if !(day_of_year >= 1.0 && day_of_year < days + 1.0) {
+            return Err(Error::RangeError);
+        }
+        // day_of_year is positive, so truncating is flooring
+        let (whole, fraction) = crate::trunc_fract(day_of_year);
+        let day_number = C::day_number(year, 1, 1)? + whole - 1;
+        Self::from_day_number_and_fraction(day_number, fraction)
+    }
+
     /// Create a `DateTime` from a `Duration` from the calendar epoch
     /// (with the calendar epoch represented in time `Standard` `S`, such
     /// that no time Standard conversions are done here).
@@ -362,11 +737,303 @@ impl<C: Calendar, S: Standard> DateTime<C, S> {
         Self::new_abnormal(1, 1, 1, 0, 0, duration.secs, duration.attos)
     }
 
+    /// Create a `DateTime` from an `Instant`
+    ///
+    /// This is the same as `From<Instant>`, except that instants whose year is
+    /// outside of the range of an i32 give an error rather than a panic.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if the `Instant` is too far in the past or
+    /// future to be represented.
+    pub fn try_from_instant(i: Instant) -> Result<Self, Error> {
+        // Far beyond the range of a DateTime (about 68 million million seconds),
+        // but leaving room to convert between standards and epochs without
+        // overflowing
+        const LIMIT: u64 = 1 << 62;
+        if i.0.secs.unsigned_abs() > LIMIT {
+            return Err(Error::RangeError);
+        }
+
+        // Conversion between time standards
+        let dur: Duration = S::from_tt(i.0);
+
+        // NOTE: if we ever move the epoch that Durations are based on
+        //       away from TimeStandard, then replace `C::epoch()` below
+        //       with `C::epoch() - Epoch::TimeStandard.as_instant()`
+        let dur = dur - C::epoch().0;
+        Self::try_new_abnormal(1, 1, 1, 0, 0, dur.secs, dur.attos)
+    }
+
+    /// Create a `DateTime` from an `Instant`, clamping instants that are too far
+    /// in the past or future to the earliest or latest representable `DateTime`
+    #[must_use]
+    pub fn saturating_from_instant(i: Instant) -> Self {
+        Self::try_from_instant(i).unwrap_or_else(|_| {
+            if i.0.is_negative() {
+                Self::MIN
+            } else {
+                Self::MAX
+            }
+        })
+    }
+
+    /// The same moment in another time `Standard`, in the same `Calendar`
+    ///
+    /// This converts through an `Instant`, so it works between any pair of
+    /// time standards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the converted `DateTime` is outside of the representable
+    /// years, which can only happen within a minute or so of their limits.
+    #[must_use]
+    pub fn to_standard<S2: Standard>(&self) -> DateTime<C, S2> {
+        DateTime::from(Instant::from(*self))
+    }
+
+    /// The same day and time of day in another `Calendar`, in the same time
+    /// `Standard`
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if the day is out of range in the other
+    /// `Calendar`.
+    pub fn to_calendar<C2: Calendar>(&self) -> Result<DateTime<C2, S>, Error> {
+        let mut r =
+            DateTime::<C2, S>::from_day_number(self.day_number() + Self::day_shift::<C2>())?;
+        r.set_time(self.time())?;
+        Ok(r)
+    }
+
+    /// Whether this is the same day and time of day as `other`, which may be
+    /// in another `Calendar`
+    ///
+    /// ```
+    /// # use astrotime::{DateTime, Gregorian, Julian, Tt};
+    /// let julian = DateTime::<Julian, Tt>::new(1582, 10, 5, 0, 0, 0, 0).unwrap();
+    /// let gregorian = DateTime::<Gregorian, Tt>::new(1582, 10, 15, 0, 0, 0, 0).unwrap();
+    /// assert!(julian.equals_date(&gregorian));
+    /// ```
+    #[must_use]
+    pub fn equals_date<C2: Calendar>(&self, other: &DateTime<C2, S>) -> bool {
+        self.day_number() + Self::day_shift::<C2>() == other.day_number()
+            && self.time() == other.time()
+    }
+
+    // Add to a day number of this calendar to get the day number of the same
+    // day in C2, as day numbers count from each calendar's own epoch
+    fn day_shift<C2: Calendar>() -> i64 {
+        (C::epoch().0.secs - C2::epoch().0.secs) / 86400
+    }
+
+    /// Parse an ISO 8601 date and time, such as `2000-01-01T12:00:00Z`.
+    ///
+    /// The date is required and the time is optional. Years must have at least
+    /// four digits and may have a sign (e.g. `-0044-03-15`). The date may also
+    /// be an ordinal date, with a three digit day of the year (e.g. `2023-181`).
+    /// Fractional seconds may have up to 18 digits.
+    ///
+    /// A trailing `Z` is accepted. A numeric offset such as `+05:30` is taken
+    /// as the local time being ahead of the time `Standard` `S` by that offset,
+    /// and is removed.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::ParseError` if the input is malformed, or
+    /// `Error::RangeError` if any field is outside of the normal range.
+    pub fn parse_iso8601(s: &str) -> Result<Self, Error> {
+        Self::from_parsed(crate::iso8601::parse_datetime(s)?)
+    }
+
+    /// Parse an ISO 8601 date and time which may have a two digit year, such
+    /// as `70-01-01T00:00:00Z`
+    ///
+    /// This is the same as `parse_iso8601`(), except that an unsigned year of
+    /// exactly two digits is also accepted, for legacy data. Such a year is
+    /// ambiguous: it is taken to be the year from `pivot` up to 99 years after
+    /// it which ends in those two digits. For example, with a `pivot` of 1969,
+    /// `69` is 1969, `70` is 1970 and `68` is 2068. Years of four or more
+    /// digits are not affected by the `pivot`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::ParseError` if the input is malformed, or
+    /// `Error::RangeError` if any field is outside of the normal range.
+    pub fn parse_iso8601_with_pivot(s: &str, pivot: i32) -> Result<Self, Error> {
+        Self::from_parsed(crate::iso8601::parse_datetime_with_pivot(s, Some(pivot))?)
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    fn from_parsed(p: crate::iso8601::Parsed) -> Result<Self, Error> {
+        let (month, day) = match p.day_of_year {
+            Some(day_of_year) => Self::month_and_day_of_year(p.year, day_of_year)?,
+            None => (p.month, p.day),
+        };
+        let dt = Self::new(p.year, month, day, p.hour, p.minute, p.second, p.attosecond)?;
+        if p.offset == 0 {
+            Ok(dt)
+        } else {
+            // Near the ends of the year range, removing the offset can overflow
+            Self::try_new_abnormal(
+                dt.year(),
+                i64::from(dt.month()),
+                i64::from(dt.day()),
+                i64::from(dt.hour()),
+                i64::from(dt.minute()),
+                i64::from(dt.second()) - p.offset,
+                dt.attosecond() as i64,
+            )
+        }
+    }
+
+    /// Parse an ISO 8601 ordinal date and time, such as `2023-181T12:00:00Z`
+    ///
+    /// This is the same as `parse_iso8601`(), except that the date must be an
+    /// ordinal date, with a three digit day of the year which must exist in
+    /// that year.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::ParseError` if the input is malformed or is not an
+    /// ordinal date, or `Error::RangeError` if any field is outside of the
+    /// normal range.
+    pub fn parse_ordinal_iso8601(s: &str) -> Result<Self, Error> {
+        if crate::iso8601::parse_datetime(s)?.day_of_year.is_none() {
+            return Err(Error::ParseError(
+                "Expected an ordinal date (YYYY-DDD)".to_owned(),
+            ));
+        }
+        Self::parse_iso8601(s)
+    }
+
+    /// Format as an ISO 8601 ordinal date and time, such as
+    /// `2023-181T12:00:00.5`
+    ///
+    /// Years outside of 0 .. 9999 are written with a sign and at least four
+    /// digits. The fraction of a second is only written if it is not zero, and
+    /// without trailing zeroes. There is no time zone designator, as the time
+    /// is in the time `Standard` `S`. This can be parsed back with
+    /// `parse_ordinal_iso8601`().
+    #[must_use]
+    pub fn to_ordinal_iso8601(&self) -> String {
+        let year = self.year();
+        let sign = if year < 0 {
+            "-"
+        } else if year > 9999 {
+            "+"
+        } else {
+            ""
+        };
+        let day_of_year = self.ordinal();
+        let mut s = format!(
+            "{sign}{:04}-{day_of_year:03}T{:02}:{:02}:{:02}",
+            year.unsigned_abs(),
+            self.hour(),
+            self.minute(),
+            self.second()
+        );
+        s.push_str(&iso8601_fraction(self.attosecond()));
+        s
+    }
+
+    /// Parse a date and time laid out according to a strftime-style `pattern`,
+    /// such as `"%d/%m/%Y %H:%M"`.
+    ///
+    /// The following specifiers are understood:
+    ///
+    /// * `%Y`: the year, optionally signed. It must have exactly four digits if
+    ///   another specifier immediately follows it.
+    /// * `%m`, `%d`, `%H`, `%M`, `%S`: month, day, hour, minute and second, with
+    ///   one or two digits
+    /// * `%j`: the day of the year, with one to three digits
+    /// * `%f`: fractional seconds, with one to eighteen digits
+    /// * `%%`: a literal `%`
+    ///
+    /// Every other character of the pattern must match the input exactly. The
+    /// year is required; a missing month or day defaults to 1 and a missing
+    /// time part defaults to zero. Second 60 is only accepted where the leap
+    /// second table has one, as with `checked_new`().
+    ///
+    /// ```
+    /// # use astrotime::{DateTime, Gregorian, Utc};
+    /// let dt = DateTime::<Gregorian, Utc>::parse_from("%d/%m/%Y %H:%M", "15/03/2024 09:30")
+    ///     .unwrap();
+    /// assert_eq!(dt, DateTime::new(2024, 3, 15, 9, 30, 0, 0).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::ParseError` if the input does not match the pattern
+    /// or the pattern is malformed, or `Error::RangeError` if any field is
+    /// outside of the normal range, or if the second does not exist on that
+    /// day.
+    pub fn parse_from(pattern: &str, input: &str) -> Result<Self, Error> {
+        let f = crate::strftime::parse(pattern, input)?;
+        let Some(year) = f.year else {
+            return Err(Error::ParseError("Pattern has no year (%Y)".to_owned()));
+        };
+
+        let (month, day) = if let Some(day_of_year) = f.day_of_year {
+            let (month, day) = Self::month_and_day_of_year(year, day_of_year)?;
+            if f.month.is_some_and(|m| m != month) || f.day.is_some_and(|d| d != day) {
+                return Err(Error::RangeError);
+            }
+            (month, day)
+        } else {
+            (f.month.unwrap_or(1), f.day.unwrap_or(1))
+        };
+
+        let dt = Self::new(
+            year,
+            month,
+            day,
+            f.hour.unwrap_or(0),
+            f.minute.unwrap_or(0),
+            f.second.unwrap_or(0),
+            f.attosecond.unwrap_or(0),
+        )?;
+        if !dt.second_exists() {
+            return Err(Error::RangeError);
+        }
+        Ok(dt)
+    }
+
+    // Whether the second exists on this day according to the leap second
+    // table. Any leap second is in the last minute of the day, where second 60
+    // only exists after an inserted leap second, and second 59 does not exist
+    // before a removed one.
+    fn second_exists(&self) -> bool {
+        let last_second = if self.hour() == 23 && self.minute() == 59 {
+            59 + self.day_leap_seconds()
+        } else {
+            59
+        };
+        i64::from(self.second()) <= last_second
+    }
+
+    // Convert a 1-based day of the year into a month and day
+    fn month_and_day_of_year(year: i32, day_of_year: u16) -> Result<(u8, u8), Error> {
+        let mut remaining = day_of_year;
+        if remaining < 1 {
+            return Err(Error::RangeError);
+        }
+        for month in 1..=12 {
+            let days = u16::from(C::month_days(month, year));
+            if remaining <= days {
+                #[allow(clippy::cast_possible_truncation)]
+                return Ok((month, remaining as u8));
+            }
+            remaining -= days;
+        }
+        Err(Error::RangeError)
+    }
+
     /// The year part
     #[allow(clippy::cast_possible_truncation)]
     #[must_use]
     #[inline]
-    pub fn year(&self) -> i32 {
+    pub const fn year(&self) -> i32 {
         unpack(self.packed, YEAR_BITS, YEAR_OFFSET) as i32
     }
 
@@ -374,7 +1041,7 @@ impl<C: Calendar, S: Standard> DateTime<C, S> {
     #[allow(clippy::cast_possible_truncation)]
     #[must_use]
     #[inline]
-    pub fn year_bc(&self) -> i32 {
+    pub const fn year_bc(&self) -> i32 {
         1 - self.year()
     }
 
@@ -382,7 +1049,7 @@ impl<C: Calendar, S: Standard> DateTime<C, S> {
     #[allow(clippy::cast_possible_truncation)]
     #[must_use]
     #[inline]
-    pub fn month(&self) -> u8 {
+    pub const fn month(&self) -> u8 {
         unpack(self.packed, MONTH0_BITS, MONTH0_OFFSET) as u8 + 1
     }
 
@@ -390,7 +1057,7 @@ impl<C: Calendar, S: Standard> DateTime<C, S> {
     #[allow(clippy::cast_possible_truncation)]
     #[must_use]
     #[inline]
-    pub fn month0(&self) -> u8 {
+    pub const fn month0(&self) -> u8 {
         unpack(self.packed, MONTH0_BITS, MONTH0_OFFSET) as u8
     }
 
@@ -398,7 +1065,7 @@ impl<C: Calendar, S: Standard> DateTime<C, S> {
     #[allow(clippy::cast_possible_truncation)]
     #[must_use]
     #[inline]
-    pub fn day(&self) -> u8 {
+    pub const fn day(&self) -> u8 {
         unpack(self.packed, DAY0_BITS, DAY0_OFFSET) as u8 + 1
     }
 
@@ -406,7 +1073,7 @@ impl<C: Calendar, S: Standard> DateTime<C, S> {
     #[allow(clippy::cast_possible_truncation)]
     #[must_use]
     #[inline]
-    pub fn day0(&self) -> u8 {
+    pub const fn day0(&self) -> u8 {
         unpack(self.packed, DAY0_BITS, DAY0_OFFSET) as u8
     }
 
@@ -414,7 +1081,7 @@ impl<C: Calendar, S: Standard> DateTime<C, S> {
     #[allow(clippy::cast_possible_truncation)]
     #[must_use]
     #[inline]
-    pub fn hour(&self) -> u8 {
+    pub const fn hour(&self) -> u8 {
         unpack(self.packed, HOUR_BITS, HOUR_OFFSET) as u8
     }
 
@@ -422,7 +1089,7 @@ impl<C: Calendar, S: Standard> DateTime<C, S> {
     #[allow(clippy::cast_possible_truncation)]
     #[must_use]
     #[inline]
-    pub fn minute(&self) -> u8 {
+    pub const fn minute(&self) -> u8 {
         unpack(self.packed, MINUTE_BITS, MINUTE_OFFSET) as u8
     }
 
@@ -430,62 +1097,245 @@ impl<C: Calendar, S: Standard> DateTime<C, S> {
     #[allow(clippy::cast_possible_truncation)]
     #[must_use]
     #[inline]
-    pub fn second(&self) -> u8 {
+    pub const fn second(&self) -> u8 {
         unpack(self.packed, SECOND_BITS, SECOND_OFFSET) as u8
     }
 
     /// The attosecond part. Ranges from `0` .. `999_999_999_999_999_999`
     #[must_use]
     #[inline]
-    pub fn attosecond(&self) -> u64 {
+    pub const fn attosecond(&self) -> u64 {
         self.attos
     }
 
+    /// The fraction of the second in whole milliseconds, from `0` .. `999`
+    #[must_use]
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn millisecond(&self) -> u32 {
+        crate::rescale_attos(self.attos, 3) as u32
+    }
+
+    /// The fraction of the second in whole microseconds, from `0` .. `999_999`
+    #[must_use]
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn microsecond(&self) -> u32 {
+        crate::rescale_attos(self.attos, 6) as u32
+    }
+
+    /// The fraction of the second in whole nanoseconds, from `0` ..
+    /// `999_999_999`
+    #[must_use]
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn nanosecond(&self) -> u32 {
+        crate::rescale_attos(self.attos, 9) as u32
+    }
+
     /// The date part
     ///
     /// Returns (year, month, day)
     #[must_use]
     #[inline]
-    pub fn date(&self) -> (i32, u8, u8) {
+    pub const fn date(&self) -> (i32, u8, u8) {
         (self.year(), self.month(), self.day())
     }
 
+    /// The date part with a historical year, which has no year zero
+    ///
+    /// Returns (era, year, month, day). ISO years from 1 onwards are the same
+    /// years `Era::Ad`, and ISO year _y_ up to 0 is year 1-_y_ `Era::Bc`. See
+    /// `new_historical`().
+    #[must_use]
+    #[inline]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub const fn to_historical(&self) -> (Era, u32, u8, u8) {
+        let year = self.year() as i64;
+        let (era, year) = if year > 0 {
+            (Era::Ad, year)
+        } else {
+            (Era::Bc, 1 - year)
+        };
+        (era, year as u32, self.month(), self.day())
+    }
+
     /// The time part
     ///
     /// Returns (hour, minute, second, attosecond)
     #[must_use]
     #[inline]
-    pub fn time(&self) -> (u8, u8, u8, u64) {
+    pub const fn time(&self) -> (u8, u8, u8, u64) {
         (self.hour(), self.minute(), self.second(), self.attosecond())
     }
 
-    /// Set the year, leaving other fields unchanged
+    /// This `DateTime` with the attosecond zeroed
+    #[must_use]
     #[inline]
-    #[allow(clippy::cast_sign_loss)]
-    pub fn set_year(&mut self, year: i32) {
-        // "year as u64" treats the sign bit as a bit in the MSB, which is what we want,
-        // because we must preserve negative years in our packing.
-        pack(&mut self.packed, YEAR_BITS, YEAR_OFFSET, year as u64);
+    pub const fn truncated_to_second(&self) -> Self {
+        Self {
+            packed: self.packed,
+            attos: 0,
+            _cal: PhantomData,
+            _std: PhantomData,
+        }
     }
 
-    /// Set the year with a BC year, leaving other fields unchanged
+    /// This `DateTime` with the second and attosecond zeroed
+    #[must_use]
     #[inline]
-    #[allow(clippy::cast_sign_loss)]
-    pub fn set_year_bc(&mut self, year_bc: i32) {
-        let year = 1 - year_bc;
-        // "year as u64" treats the sign bit as a bit in the MSB, which is what we want,
-        // because we must preserve negative years in our packing.
-        pack(&mut self.packed, YEAR_BITS, YEAR_OFFSET, year as u64);
+    pub const fn truncated_to_minute(&self) -> Self {
+        let mut dt = self.truncated_to_second();
+        pack(&mut dt.packed, SECOND_BITS, SECOND_OFFSET, 0);
+        dt
     }
 
-    /// Set the month, leaving other fields unchanged
-    ///
-    /// # Errors
-    ///
-    /// Will return `Error::RangeError` if `month` is <1 or >12.
-    #[allow(clippy::manual_range_contains)]
-    pub fn set_month(&mut self, month: u8) -> Result<(), Error> {
-        if month < 1 || month > 12 {
+    /// This `DateTime` with the minute, second and attosecond zeroed
+    #[must_use]
+    #[inline]
+    pub const fn truncated_to_hour(&self) -> Self {
+        let mut dt = self.truncated_to_minute();
+        pack(&mut dt.packed, MINUTE_BITS, MINUTE_OFFSET, 0);
+        dt
+    }
+
+    /// This `DateTime` with the time of day zeroed, i.e. midnight at the start
+    /// of the same day
+    #[must_use]
+    #[inline]
+    pub const fn truncated_to_day(&self) -> Self {
+        let mut dt = self.truncated_to_hour();
+        pack(&mut dt.packed, HOUR_BITS, HOUR_OFFSET, 0);
+        dt
+    }
+
+    /// Midnight at the start of the same day
+    ///
+    /// This is the same as `truncated_to_day`().
+    #[must_use]
+    #[inline]
+    pub const fn start_of_day(&self) -> Self {
+        self.truncated_to_day()
+    }
+
+    /// The last attosecond of the same day
+    ///
+    /// This is normally 23:59:59.999999999999999999, but is 23:59:60.999999999999999999
+    /// when the day ends in an inserted leap second (and would be
+    /// 23:59:58.999999999999999999 if it ended in a removed one).
+    #[must_use]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn end_of_day(&self) -> Self {
+        let leaps = self.day_leap_seconds();
+        let mut dt = self.start_of_day();
+        pack(&mut dt.packed, HOUR_BITS, HOUR_OFFSET, 23);
+        pack(&mut dt.packed, MINUTE_BITS, MINUTE_OFFSET, 59);
+        pack(
+            &mut dt.packed,
+            SECOND_BITS,
+            SECOND_OFFSET,
+            (59 + leaps) as u64,
+        );
+        dt.attos = 999_999_999_999_999_999;
+        dt
+    }
+
+    /// Whether the day of this `DateTime` ends with a leap second
+    ///
+    /// This is only ever true for `Utc`, on the days in the leap second table.
+    /// A removed leap second also counts, although there has never been one.
+    /// Standards which smear leap seconds, such as `UtcSmeared`, never have one.
+    #[must_use]
+    pub fn has_leap_second(&self) -> bool {
+        self.day_leap_seconds() != 0
+    }
+
+    /// 1 if the day of this `DateTime` ends with an inserted leap second, -1 if
+    /// it ends with a removed one, and otherwise 0
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn leap_second_sign(&self) -> i8 {
+        self.day_leap_seconds().signum() as i8
+    }
+
+    // The number of seconds by which the day of this DateTime is longer than
+    // 86400, according to the leap second table. This is not the length of the
+    // day in SI seconds, which differs in standards that smear leap seconds.
+    fn day_leap_seconds(&self) -> i64 {
+        S::leap_seconds_in_day(Duration::new(self.day_number() * 86400, 0) + C::epoch().0)
+    }
+
+    /// Midnight at the start of the first day of the same month
+    #[must_use]
+    #[inline]
+    pub const fn start_of_month(&self) -> Self {
+        let mut dt = self.start_of_day();
+        pack(&mut dt.packed, DAY0_BITS, DAY0_OFFSET, 0);
+        dt
+    }
+
+    /// The last attosecond of the last day of the same month
+    ///
+    /// See `end_of_day`() regarding leap seconds.
+    #[must_use]
+    pub fn end_of_month(&self) -> Self {
+        let mut dt = self.start_of_day();
+        let last_day = C::month_days(self.month(), self.year());
+        pack(
+            &mut dt.packed,
+            DAY0_BITS,
+            DAY0_OFFSET,
+            u64::from(last_day - 1),
+        );
+        dt.end_of_day()
+    }
+
+    /// Midnight at the start of January 1st of the same year
+    #[must_use]
+    #[inline]
+    pub const fn start_of_year(&self) -> Self {
+        let mut dt = self.start_of_month();
+        pack(&mut dt.packed, MONTH0_BITS, MONTH0_OFFSET, 0);
+        dt
+    }
+
+    /// The last attosecond of December 31st of the same year
+    ///
+    /// See `end_of_day`() regarding leap seconds.
+    #[must_use]
+    pub fn end_of_year(&self) -> Self {
+        let mut dt = self.start_of_year();
+        pack(&mut dt.packed, MONTH0_BITS, MONTH0_OFFSET, 11);
+        dt.end_of_month()
+    }
+
+    /// Set the year, leaving other fields unchanged
+    #[inline]
+    #[allow(clippy::cast_sign_loss)]
+    pub const fn set_year(&mut self, year: i32) {
+        // "year as u32" keeps the two's complement bits of the year, which is what
+        // we want, because we must preserve negative years in our packing.
+        pack(&mut self.packed, YEAR_BITS, YEAR_OFFSET, year as u32 as u64);
+    }
+
+    /// Set the year with a BC year, leaving other fields unchanged
+    #[inline]
+    #[allow(clippy::cast_sign_loss)]
+    pub const fn set_year_bc(&mut self, year_bc: i32) {
+        let year = 1 - year_bc;
+        // "year as u32" keeps the two's complement bits of the year, which is what
+        // we want, because we must preserve negative years in our packing.
+        pack(&mut self.packed, YEAR_BITS, YEAR_OFFSET, year as u32 as u64);
+    }
+
+    /// Set the month, leaving other fields unchanged
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if `month` is <1 or >12.
+    #[allow(clippy::manual_range_contains)]
+    pub fn set_month(&mut self, month: u8) -> Result<(), Error> {
+        if month < 1 || month > 12 {
             return Err(Error::RangeError);
         }
         if self.day() > C::month_days(month, self.year()) {
@@ -571,7 +1421,7 @@ impl<C: Calendar, S: Standard> DateTime<C, S> {
     ///
     /// Will return `Error::RangeError` if `attosecond` are out of the proscribed range
     /// (more than 1 seconds worth of attoseconds)
-    pub fn set_attosecond(&mut self, attosecond: u64) -> Result<(), Error> {
+    pub const fn set_attosecond(&mut self, attosecond: u64) -> Result<(), Error> {
         if attosecond > 1_000_000_000_000_000_000 {
             return Err(Error::RangeError);
         }
@@ -579,6 +1429,54 @@ impl<C: Calendar, S: Standard> DateTime<C, S> {
         Ok(())
     }
 
+    /// Set the fraction of the second in milliseconds, from `0` .. `999`,
+    /// leaving other fields unchanged
+    ///
+    /// Any finer part of the fraction is cleared.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if `millisecond` is out of range
+    pub const fn set_millisecond(&mut self, millisecond: u32) -> Result<(), Error> {
+        if millisecond > 999 {
+            return Err(Error::RangeError);
+        }
+        self.attos = crate::attos_from_rescaled(millisecond as u64, 3);
+        Ok(())
+    }
+
+    /// Set the fraction of the second in microseconds, from `0` .. `999_999`,
+    /// leaving other fields unchanged
+    ///
+    /// Any finer part of the fraction is cleared.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if `microsecond` is out of range
+    pub const fn set_microsecond(&mut self, microsecond: u32) -> Result<(), Error> {
+        if microsecond > 999_999 {
+            return Err(Error::RangeError);
+        }
+        self.attos = crate::attos_from_rescaled(microsecond as u64, 6);
+        Ok(())
+    }
+
+    /// Set the fraction of the second in nanoseconds, from `0` ..
+    /// `999_999_999`, leaving other fields unchanged
+    ///
+    /// Any finer part of the fraction is cleared.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if `nanosecond` is out of range
+    pub const fn set_nanosecond(&mut self, nanosecond: u32) -> Result<(), Error> {
+        if nanosecond > 999_999_999 {
+            return Err(Error::RangeError);
+        }
+        self.attos = crate::attos_from_rescaled(nanosecond as u64, 9);
+        Ok(())
+    }
+
     /// Set the date part (year, month, day)
     ///
     /// # Errors
@@ -604,556 +1502,3413 @@ impl<C: Calendar, S: Standard> DateTime<C, S> {
         Ok(())
     }
 
-    /// Day number (integer).
-    ///
-    /// January 1st of 1 A.D. (Common Era) is the epoch and has a day number of 0.
+    /// Set the year, returning the previous year
+    #[inline]
+    pub const fn replace_year(&mut self, year: i32) -> i32 {
+        let old = self.year();
+        self.set_year(year);
+        old
+    }
+
+    /// Set the month as with `set_month`(), returning the previous month
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Will only panic on a bug that caused internal values to get out of range.
-    #[must_use]
-    pub fn day_number(&self) -> i64 {
-        C::day_number(self.year(), self.month(), i64::from(self.day())).unwrap()
+    /// Will return `Error::RangeError` as `set_month`() does, leaving this
+    /// unchanged.
+    pub fn replace_month(&mut self, month: u8) -> Result<u8, Error> {
+        let old = self.month();
+        self.set_month(month)?;
+        Ok(old)
     }
 
-    /// Day fraction, fractional part of the day since midnight
+    /// Set the day as with `set_day`(), returning the previous day
     ///
-    /// This isn't attosecond accurate because a day contains more attoseconds than
-    /// can fit in a f64 (which has 52 bits of precision).  However, it should be
-    /// accurate to 10,000 attoseconds.
-    #[allow(clippy::cast_precision_loss)]
-    #[must_use]
-    pub fn day_fraction(&self) -> f64 {
-        // In order to preserve as much precision as we can, we count
-        // in units of 10^-14 seconds (10,000 attoseconds).
-        // A 24-hour duration of these won't overflow a u64. Anything
-        // smaller would.
-        const FACTOR: u64 = 100_000_000_000_000;
-
-        (u64::from(self.hour()) * 3600 * FACTOR
-            + u64::from(self.minute()) * 60 * FACTOR
-            + u64::from(self.second()) * FACTOR
-            + (self.attosecond() / 10000) as u64) as f64
-            / 8_640_000_000_000_000_000.
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` as `set_day`() does, leaving this
+    /// unchanged.
+    pub fn replace_day(&mut self, day: u8) -> Result<u8, Error> {
+        let old = self.day();
+        self.set_day(day)?;
+        Ok(old)
     }
 
-    /// Duration from the calendar epoch (with the calendar epoch represented
-    /// in the time `Standard` `S`, such that no time Standard conversions are
-    /// done here).
+    /// Set the hour as with `set_hour`(), returning the previous hour
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// Only panics if we have an internal data consistency issue
-    #[must_use]
-    pub fn duration_from_epoch(&self) -> Duration {
-        let dn = self.day_number();
-        let seconds = dn * 86400
-            + i64::from(self.hour()) * 3600
-            + i64::from(self.minute()) * 60
-            + i64::from(self.second());
+    /// Will return `Error::RangeError` as `set_hour`() does, leaving this
+    /// unchanged.
+    pub fn replace_hour(&mut self, hour: u8) -> Result<u8, Error> {
+        let old = self.hour();
+        self.set_hour(hour)?;
+        Ok(old)
+    }
 
-        Duration::new(seconds, i64::try_from(self.attosecond()).unwrap())
+    /// Set the minute as with `set_minute`(), returning the previous minute
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` as `set_minute`() does, leaving this
+    /// unchanged.
+    pub fn replace_minute(&mut self, minute: u8) -> Result<u8, Error> {
+        let old = self.minute();
+        self.set_minute(minute)?;
+        Ok(old)
     }
-}
 
-impl<C: Calendar, S: Standard> fmt::Debug for DateTime<C, S> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:018} {} {}",
-            self.year(),
-            self.month(),
-            self.day(),
-            self.hour(),
-            self.minute(),
-            self.second(),
-            self.attosecond(),
-            C::name(),
-            S::abbrev()
-        )
+    /// Set the second as with `set_second`(), returning the previous second
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` as `set_second`() does, leaving this
+    /// unchanged.
+    pub fn replace_second(&mut self, second: u8) -> Result<u8, Error> {
+        let old = self.second();
+        self.set_second(second)?;
+        Ok(old)
     }
-}
 
-impl<C: Calendar, S: Standard> fmt::Display for DateTime<C, S> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:018} {} {}",
-            self.year(),
-            self.month(),
-            self.day(),
-            self.hour(),
-            self.minute(),
-            self.second(),
-            self.attosecond(),
-            C::name(),
-            S::abbrev()
-        )
+    /// Set the attosecond as with `set_attosecond`(), returning the previous
+    /// attosecond
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` as `set_attosecond`() does, leaving this
+    /// unchanged.
+    pub fn replace_attosecond(&mut self, attosecond: u64) -> Result<u64, Error> {
+        let old = self.attosecond();
+        self.set_attosecond(attosecond)?;
+        Ok(old)
     }
-}
 
-impl<C: Calendar, S: Standard> Add<Duration> for DateTime<C, S> {
-    type Output = Self;
+    /// This `DateTime` with the year changed
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if the day does not exist in that year
+    /// (February 29th in a non-leap year).
+    pub fn with_year(mut self, year: i32) -> Result<Self, Error> {
+        if self.day() > C::month_days(self.month(), year) {
+            return Err(Error::RangeError);
+        }
+        self.set_year(year);
+        Ok(self)
+    }
 
-    #[allow(clippy::cast_possible_wrap)]
-    fn add(self, rhs: Duration) -> Self {
-        Self::new_abnormal(
-            self.year(),
-            i64::from(self.month()),
-            i64::from(self.day()),
-            i64::from(self.hour()),
-            i64::from(self.minute()),
-            i64::from(self.second()) + rhs.seconds_part(),
-            self.attosecond() as i64 + rhs.attos_part() as i64,
-        )
+    /// This `DateTime` with the year changed to a BC year
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if the day does not exist in that year
+    /// (February 29th in a non-leap year).
+    pub fn with_year_bc(self, year_bc: i32) -> Result<Self, Error> {
+        self.with_year(1 - year_bc)
     }
-}
 
-impl<C: Calendar, S: Standard> Sub<Duration> for DateTime<C, S> {
-    type Output = Self;
+    /// This `DateTime` with the month changed
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if `month` is <1 or >12, or if the day
+    /// does not exist in that month.
+    pub fn with_month(mut self, month: u8) -> Result<Self, Error> {
+        self.set_month(month)?;
+        Ok(self)
+    }
 
-    #[allow(clippy::cast_possible_wrap)]
-    fn sub(self, rhs: Duration) -> Self {
-        Self::new_abnormal(
-            self.year(),
-            i64::from(self.month()),
-            i64::from(self.day()),
-            i64::from(self.hour()),
-            i64::from(self.minute()),
-            i64::from(self.second()) - rhs.seconds_part(),
-            self.attosecond() as i64 - rhs.attos_part() as i64,
-        )
+    /// This `DateTime` with the day changed
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if `day` is outside of the range of days
+    /// for the month.
+    pub fn with_day(mut self, day: u8) -> Result<Self, Error> {
+        self.set_day(day)?;
+        Ok(self)
     }
-}
 
-impl<C: Calendar, S: Standard> Sub for DateTime<C, S> {
-    type Output = Duration;
+    /// This `DateTime` with the hour changed
+    ///
+    /// These `with_` methods can be chained:
+    ///
+    /// ```
+    /// # use astrotime::{DateTime, Gregorian, Utc};
+    /// let dt = DateTime::<Gregorian, Utc>::new(2000, 1, 1, 12, 34, 56, 0)?;
+    /// let midnight = dt.with_hour(0)?.with_minute(0)?.with_second(0)?;
+    /// assert_eq!(midnight, DateTime::<Gregorian, Utc>::new(2000, 1, 1, 0, 0, 0, 0)?);
+    /// # Ok::<(), astrotime::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if `hour` is greater than 23.
+    pub fn with_hour(mut self, hour: u8) -> Result<Self, Error> {
+        self.set_hour(hour)?;
+        Ok(self)
+    }
 
-    #[allow(clippy::cast_possible_wrap)]
-    fn sub(self, other: Self) -> Duration {
-        let secs = (self.day_number() - other.day_number()) * 86400
-            + (i64::from(self.hour()) - i64::from(other.hour())) * 3600
-            + (i64::from(self.minute()) - i64::from(other.minute())) * 60
-            + (i64::from(self.second()) - i64::from(other.second()));
-        let attos = self.attosecond() as i64 - other.attosecond() as i64;
-        Duration::new(secs, attos) // it will normalize
+    /// This `DateTime` with the minute changed
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if `minute` is greater than 59.
+    pub fn with_minute(mut self, minute: u8) -> Result<Self, Error> {
+        self.set_minute(minute)?;
+        Ok(self)
     }
-}
 
-impl<C: Calendar, S: Standard> PartialEq<Self> for DateTime<C, S> {
-    fn eq(&self, other: &Self) -> bool {
-        self.packed == other.packed && self.attos == other.attos
+    /// This `DateTime` with the second changed
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if `second` is greater than 60.
+    pub fn with_second(mut self, second: u8) -> Result<Self, Error> {
+        self.set_second(second)?;
+        Ok(self)
     }
-}
 
-impl<C: Calendar, S: Standard> Eq for DateTime<C, S> {}
+    /// This `DateTime` with the attosecond changed
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if `attosecond` is out of range.
+    pub fn with_attosecond(mut self, attosecond: u64) -> Result<Self, Error> {
+        self.set_attosecond(attosecond)?;
+        Ok(self)
+    }
 
-impl<C: Calendar, S: Standard> Ord for DateTime<C, S> {
-    fn cmp(&self, other: &Self) -> Ordering {
-        if self.year() != other.year() {
-            return self.year().cmp(&other.year());
-        }
-        if self.month() != other.month() {
-            return self.month().cmp(&other.month());
+    /// This `DateTime` with the date part (year, month, day) changed
+    ///
+    /// Validity of the day is checked against the new year and month together.
+    ///
+    /// ```
+    /// # use astrotime::{DateTime, Julian, Tt};
+    /// let dt = DateTime::<Julian, Tt>::new(1999, 1, 31, 6, 0, 0, 0)?;
+    /// let leap_day = dt.with_date((1900, 2, 29))?;
+    /// assert_eq!(leap_day.time(), (6, 0, 0, 0));
+    /// assert!(dt.with_date((1901, 2, 29)).is_err());
+    /// # Ok::<(), astrotime::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if any input values are out of the proscribed range
+    #[allow(clippy::manual_range_contains)]
+    pub fn with_date(mut self, date: (i32, u8, u8)) -> Result<Self, Error> {
+        let (year, month, day) = date;
+        if month < 1 || month > 12 || day < 1 || day > C::month_days(month, year) {
+            return Err(Error::RangeError);
+        }
+        self.set_year(year);
+        pack(
+            &mut self.packed,
+            MONTH0_BITS,
+            MONTH0_OFFSET,
+            u64::from(month - 1),
+        );
+        pack(&mut self.packed, DAY0_BITS, DAY0_OFFSET, u64::from(day - 1));
+        Ok(self)
+    }
+
+    /// This `DateTime` with the time part (hour, minute, second, attosecond) changed
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if any input values are out of the proscribed range
+    pub fn with_time(mut self, time: (u8, u8, u8, u64)) -> Result<Self, Error> {
+        self.set_time(time)?;
+        Ok(self)
+    }
+
+    /// This `DateTime` with the date part (year, month, day) of `other`, keeping
+    /// this time of day
+    ///
+    /// This cannot fail, since the date of `other` is valid in the same
+    /// `Calendar`. (Changing the year, month and day one at a time can fail
+    /// part way, such as moving the 31st of January to February.)
+    #[must_use]
+    pub const fn with_date_from(&self, other: &Self) -> Self {
+        unsafe {
+            Self::new_unchecked(
+                other.year(),
+                other.month(),
+                other.day(),
+                self.hour(),
+                self.minute(),
+                self.second(),
+                self.attosecond(),
+            )
+        }
+    }
+
+    /// This `DateTime` with the time part (hour, minute, second, attosecond) of
+    /// `other`, keeping this date
+    #[must_use]
+    pub const fn with_time_from(&self, other: &Self) -> Self {
+        other.with_date_from(self)
+    }
+
+    /// The number of days in the month of this `DateTime`
+    #[must_use]
+    pub fn days_in_month(&self) -> u8 {
+        C::month_days(self.month(), self.year())
+    }
+
+    /// The number of days in the year of this `DateTime` (365 or 366)
+    #[must_use]
+    pub fn days_in_year(&self) -> u16 {
+        if self.is_leap_year() {
+            366
+        } else {
+            365
+        }
+    }
+
+    /// Whether the year of this `DateTime` is a leap year in its `Calendar`
+    #[must_use]
+    pub fn is_leap_year(&self) -> bool {
+        C::is_year_leap(self.year())
+    }
+
+    /// The day of the year, from 1 on January 1st up to 365 or 366
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn ordinal(&self) -> u16 {
+        (self.day_number() - self.start_of_year().day_number() + 1) as u16
+    }
+
+    /// Day number (integer).
+    ///
+    /// January 1st of 1 A.D. (Common Era) is the epoch and has a day number of 0.
+    ///
+    /// # Panics
+    ///
+    /// Will only panic on a bug that caused internal values to get out of range.
+    #[must_use]
+    pub fn day_number(&self) -> i64 {
+        C::day_number(self.year(), self.month(), i64::from(self.day())).unwrap()
+    }
+
+    /// The number of calendar days from this `DateTime` to `other`
+    ///
+    /// This is the difference of their day numbers, so the time of day is
+    /// ignored. It is negative if `other` is on an earlier day.
+    #[must_use]
+    pub fn days_between(&self, other: &Self) -> i64 {
+        other.day_number() - self.day_number()
+    }
+
+    /// The number of whole calendar months from this `DateTime` to `other`
+    ///
+    /// This is computed from the calendar fields rather than from elapsed time.
+    /// A month is only complete once the same day of the month and time of day
+    /// is reached, so from January 31st to February 28th is 0 months, and to
+    /// March 1st is 1 month. It is negative if `other` is earlier.
+    #[must_use]
+    pub fn months_between(&self, other: &Self) -> i64 {
+        let months = (i64::from(other.year()) - i64::from(self.year())) * 12
+            + i64::from(other.month())
+            - i64::from(self.month());
+
+        // Compare the remaining fields to see if the last month is incomplete
+        let rest = |dt: &Self| (dt.day(), dt.time());
+        match months.cmp(&0) {
+            Ordering::Greater if rest(other) < rest(self) => months - 1,
+            Ordering::Less if rest(other) > rest(self) => months + 1,
+            _ => months,
+        }
+    }
+
+    /// The number of whole calendar years from this `DateTime` to `other`
+    ///
+    /// As with `months_between`(), a year is only complete once the same month,
+    /// day and time of day is reached. So someone born on February 29th turns one
+    /// on March 1st of the following year.
+    #[must_use]
+    pub fn years_between(&self, other: &Self) -> i64 {
+        self.months_between(other) / 12
+    }
+
+    /// The calendar `Period` from this `DateTime` to `other`
+    ///
+    /// This counts whole years and months from the calendar fields, as with
+    /// `months_between`(), then whole days, then the time of day. Where the
+    /// starting day does not exist in the last month (such as the 31st, going
+    /// into a month of 30 days), it is taken as the last day of that month, so
+    /// from January 31st to March 1st is 1 month and 1 day. A day borrowed for
+    /// the time of day is as long as that day is, including any leap second.
+    ///
+    /// If `other` is earlier, this is the period from `other` to this, marked
+    /// as negative.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn period_until(&self, other: &Self) -> Period {
+        if other < self {
+            let mut period = other.period_until(self);
+            period.negative = true;
+            return period;
+        }
+
+        let time_of_day = |dt: &Self| {
+            let (h, m, s, a) = dt.time();
+            (i128::from(h) * 3600 + i128::from(m) * 60 + i128::from(s)) * 1_000_000_000_000_000_000
+                + i128::from(a)
+        };
+        let (y1, m1, d1) = (i64::from(self.year()), self.month(), self.day());
+        let (mut y2, mut m2, mut d2) = (i64::from(other.year()), other.month(), other.day());
+
+        // Borrow a day for the time of day if need be. Since other is later,
+        // its date must be too, so this stays within range.
+        let mut attos = time_of_day(other) - time_of_day(self);
+        if attos < 0 {
+            if d2 > 1 {
+                d2 -= 1;
+            } else {
+                if m2 > 1 {
+                    m2 -= 1;
+                } else {
+                    m2 = 12;
+                    y2 -= 1;
+                }
+                d2 = C::month_days(m2, y2 as i32);
+            }
+            let borrowed = unsafe { Self::new_unchecked(y2 as i32, m2, d2, 0, 0, 0, 0) };
+            attos += i128::from(86400 + borrowed.day_leap_seconds()) * 1_000_000_000_000_000_000;
+        }
+
+        // Whole months, then the days left over from the start day in the
+        // month before the end (or the last day of that month)
+        let mut months = (y2 - y1) * 12 + i64::from(m2) - i64::from(m1);
+        let days = if d2 >= d1 {
+            d2 - d1
+        } else {
+            months -= 1;
+            let (py, pm) = if m2 > 1 { (y2, m2 - 1) } else { (y2 - 1, 12) };
+            let month_days = C::month_days(pm, py as i32);
+            month_days - d1.min(month_days) + d2
+        };
+
+        // Any leap second is in the last minute of the day
+        let secs = (attos / 1_000_000_000_000_000_000) as u32;
+        let (hours, minutes, seconds) = if secs >= 86340 {
+            (23, 59, secs - 86340)
+        } else {
+            (secs / 3600, secs / 60 % 60, secs % 60)
+        };
+
+        Period {
+            negative: false,
+            years: (months / 12) as u32,
+            months: (months % 12) as u8,
+            days,
+            hours: hours as u8,
+            minutes: minutes as u8,
+            seconds: seconds as u8,
+            attoseconds: (attos % 1_000_000_000_000_000_000) as u64,
+        }
+    }
+
+    /// The month
+    #[must_use]
+    pub const fn month_enum(&self) -> Month {
+        Month::ALL[self.month() as usize - 1]
+    }
+
+    /// The day of the week as an ISO 8601 day number, from Monday (1) through
+    /// Sunday (7)
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn weekday(&self) -> u8 {
+        // Gregorian day number 0 (January 1st, 1 CE) was a Monday. Julian day
+        // numbers for the same day are 2 larger.
+        let mut dn = self.day_number();
+        if !C::is_gregorian() {
+            dn -= JULIAN_TO_GREGORIAN_EPOCH_DAYS;
+        }
+        dn.rem_euclid(7) as u8 + 1
+    }
+
+    /// The day of the week
+    #[must_use]
+    pub fn weekday_enum(&self) -> Weekday {
+        Weekday::ALL[usize::from(self.weekday() - 1)]
+    }
+
+    /// The same time of day on the following calendar day
+    ///
+    /// See `plus_days`() for how a leap second time of day is handled.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if the result would be out of range.
+    pub fn tomorrow(&self) -> Result<Self, Error> {
+        self.plus_days(1)
+    }
+
+    /// The same time of day on the preceding calendar day
+    ///
+    /// See `plus_days`() for how a leap second time of day is handled.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if the result would be out of range.
+    pub fn yesterday(&self) -> Result<Self, Error> {
+        self.plus_days(-1)
+    }
+
+    /// The same time of day on the nearest following day that is a `weekday`
+    ///
+    /// This is always in the future, so from a Monday the next Monday is a week
+    /// later.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if the result would be out of range.
+    pub fn next_weekday(&self, weekday: Weekday) -> Result<Self, Error> {
+        let ahead = (weekday.number() + 6 - self.weekday()) % 7 + 1;
+        self.plus_days(i64::from(ahead))
+    }
+
+    /// The same time of day on the nearest preceding day that is a `weekday`
+    ///
+    /// This is always in the past, so from a Monday the previous Monday is a
+    /// week earlier.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if the result would be out of range.
+    pub fn previous_weekday(&self, weekday: Weekday) -> Result<Self, Error> {
+        let behind = (self.weekday() + 6 - weekday.number()) % 7 + 1;
+        self.plus_days(-i64::from(behind))
+    }
+
+    /// Midnight at the start of the week, which begins on `first_day`
+    ///
+    /// ISO 8601 weeks begin on Monday, but in some places weeks begin on
+    /// Sunday.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the week starts before the earliest representable date.
+    #[must_use]
+    pub fn start_of_week(&self, first_day: Weekday) -> Self {
+        let behind = (self.weekday() + 7 - first_day.number()) % 7;
+        self.start_of_day()
+            .plus_days(-i64::from(behind))
+            .expect("Week starts out of range")
+    }
+
+    /// The last attosecond of the week, which begins on `first_day`
+    ///
+    /// See `end_of_day`() regarding leap seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the week ends after the latest representable date.
+    #[must_use]
+    pub fn end_of_week(&self, first_day: Weekday) -> Self {
+        let ahead = (first_day.number() + 6 - self.weekday()) % 7;
+        self.start_of_day()
+            .plus_days(i64::from(ahead))
+            .expect("Week ends out of range")
+            .end_of_day()
+    }
+
+    /// The same time of day, `days` calendar days later (or earlier if negative)
+    ///
+    /// Days are counted on the calendar, not as multiples of 86400 seconds, so
+    /// this is unaffected by any leap seconds in between. If the time of day is a
+    /// leap second (second 60) and the resulting day has no leap second, the
+    /// result is taken through an `Instant` and so lands on the first second of
+    /// the following day.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if the result would be out of range.
+    pub fn plus_days(&self, days: i64) -> Result<Self, Error> {
+        let day_number = self
+            .day_number()
+            .checked_add(days)
+            .ok_or(Error::RangeError)?;
+        let mut dt = Self::from_day_number(day_number)?;
+        dt.set_time(self.time())?;
+        if dt.second() == 60 {
+            // A `DateTime` converted from an `Instant` never has second 60, so
+            // this only changes `dt` if its leap second does not exist
+            let instant = Instant::from(dt);
+            let normalized: Self = From::from(instant);
+            if Instant::from(normalized) == instant {
+                dt = normalized;
+            }
+        }
+        Ok(dt)
+    }
+
+    /// Day fraction, fractional part of the day since midnight
+    ///
+    /// This isn't attosecond accurate because a day contains more attoseconds than
+    /// can fit in a f64 (which has 52 bits of precision).  However, it should be
+    /// accurate to 10,000 attoseconds.
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn day_fraction(&self) -> f64 {
+        self.time_of_day_units() as f64 / (86400 * TIME_OF_DAY_FACTOR) as f64
+    }
+
+    /// The day of the year with the time of day as a fraction, where 1.0 is
+    /// midnight at the start of January 1st
+    ///
+    /// This is `ordinal`() plus `day_fraction`(), the form of orbital element
+    /// epochs. See `from_year_and_fractional_day`().
+    #[must_use]
+    pub fn day_of_year_fraction(&self) -> f64 {
+        f64::from(self.ordinal()) + self.day_fraction()
+    }
+
+    /// The time of day in hours since midnight, from 0.0 up to 24.0
+    ///
+    /// This has the same precision as `day_fraction`().
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn as_hours_f64(&self) -> f64 {
+        self.time_of_day_units() as f64 / (3600 * TIME_OF_DAY_FACTOR) as f64
+    }
+
+    /// The time of day as an angle in degrees, from 0.0 up to 360.0, with
+    /// midnight at 0 degrees and noon at 180 degrees
+    ///
+    /// This has the same precision as `day_fraction`().
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn as_degrees_f64(&self) -> f64 {
+        // One degree is 240 seconds
+        self.time_of_day_units() as f64 / (240 * TIME_OF_DAY_FACTOR) as f64
+    }
+
+    /// The time of day as an angle in radians, from 0.0 up to 2π, with
+    /// midnight at 0 and noon at π
+    ///
+    /// This has the same precision as `day_fraction`().
+    #[must_use]
+    pub fn as_radians_f64(&self) -> f64 {
+        self.day_fraction() * core::f64::consts::TAU
+    }
+
+    // The time of day in units of `TIME_OF_DAY_FACTOR`
+    fn time_of_day_units(&self) -> u64 {
+        u64::from(self.hour()) * 3600 * TIME_OF_DAY_FACTOR
+            + u64::from(self.minute()) * 60 * TIME_OF_DAY_FACTOR
+            + u64::from(self.second()) * TIME_OF_DAY_FACTOR
+            + (self.attosecond() / 10000)
+    }
+
+    /// Duration from the calendar epoch (with the calendar epoch represented
+    /// in the time `Standard` `S`, such that no time Standard conversions are
+    /// done here).
+    ///
+    /// # Panics
+    ///
+    /// Only panics if we have an internal data consistency issue
+    #[must_use]
+    pub fn duration_from_epoch(&self) -> Duration {
+        let dn = self.day_number();
+        let seconds = dn * 86400
+            + i64::from(self.hour()) * 3600
+            + i64::from(self.minute()) * 60
+            + i64::from(self.second());
+
+        Duration::new(seconds, i64::try_from(self.attosecond()).unwrap())
+    }
+
+    /// Round down to a multiple of `unit` measured from the calendar epoch
+    ///
+    /// Units that evenly divide a day (such as a second, a minute or an hour)
+    /// round to the usual boundaries within each day. Other units are counted
+    /// continuously from the calendar epoch, across midnights.
+    ///
+    /// A leap second (`:60`) is divided as any other second by units of a
+    /// second or less. Longer units round it down to the last multiple before
+    /// it, and up to the first multiple from the following midnight.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `unit` is not positive.
+    #[must_use]
+    pub fn floor_to(&self, unit: Duration) -> Self {
+        self.unit_bounds(unit).0
+    }
+
+    /// Round up to a multiple of `unit` measured from the calendar epoch
+    ///
+    /// See `floor_to`() regarding which multiples are used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `unit` is not positive.
+    #[must_use]
+    pub fn ceil_to(&self, unit: Duration) -> Self {
+        let (lower, upper, _, exact) = self.unit_bounds(unit);
+        if exact {
+            lower
+        } else {
+            upper
+        }
+    }
+
+    /// Round to the nearest multiple of `unit` measured from the calendar epoch
+    ///
+    /// See `floor_to`() regarding which multiples are used. The nearest one is
+    /// decided by the actual time elapsed, so a leap second in between is
+    /// respected. A `DateTime` exactly halfway between two multiples is rounded
+    /// to the even multiple.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `unit` is not positive.
+    #[must_use]
+    pub fn round_to(&self, unit: Duration) -> Self {
+        let (lower, upper, units, exact) = self.unit_bounds(unit);
+        if exact {
+            return lower;
+        }
+
+        let at = Instant::from(*self);
+        let below = at - Instant::from(lower);
+        let above = Instant::from(upper) - at;
+        match below.cmp(&above) {
+            Ordering::Less => lower,
+            Ordering::Equal if units % 2 == 0 => lower,
+            _ => upper,
+        }
+    }
+
+    /// Format with the year in era notation, such as `44 BCE 03-15 12:00:00.0... Julian TT`
+    ///
+    /// Years from 1 onwards are shown as CE (Common Era), and years up to 0 are
+    /// shown as BCE (Before Common Era) as with `year_bc`(), so year 0 is 1 BCE and
+    /// year -43 is 44 BCE. The rest is formatted as `Display` does.
+    #[must_use]
+    pub fn format_era(&self) -> String {
+        let (year, era) = if self.year() > 0 {
+            (i64::from(self.year()), "CE")
+        } else {
+            // as year_bc(), but without overflowing on the earliest year
+            (1 - i64::from(self.year()), "BCE")
+        };
+        format!(
+            "{} {} {:02}-{:02} {:02}:{:02}:{:02}.{:018} {} {}",
+            year,
+            era,
+            self.month(),
+            self.day(),
+            self.hour(),
+            self.minute(),
+            self.second(),
+            self.attosecond(),
+            C::name(),
+            S::abbrev()
+        )
+    }
+
+    /// Compare with a `DateTime` in another `Calendar` or time `Standard` by
+    /// the moment in time that each represents
+    ///
+    /// Both are converted to an `Instant`, so offsets between time standards
+    /// and leap seconds are taken into account.
+    #[must_use]
+    pub fn cmp_instant<C2: Calendar, S2: Standard>(&self, other: &DateTime<C2, S2>) -> Ordering {
+        Instant::from(*self).cmp(&Instant::from(*other))
+    }
+
+    /// Whether this is earlier than `other`
+    #[must_use]
+    pub fn is_before(&self, other: &Self) -> bool {
+        self < other
+    }
+
+    /// Whether this is later than `other`
+    #[must_use]
+    pub fn is_after(&self, other: &Self) -> bool {
+        self > other
+    }
+
+    /// Whether this is from `start` up to (but not including) `end`
+    ///
+    /// This is never true if `end` is not after `start`.
+    #[must_use]
+    pub fn is_between(&self, start: &Self, end: &Self) -> bool {
+        start <= self && self < end
+    }
+
+    /// Whether this and `other` are no further apart than `tolerance`
+    ///
+    /// This is for comparing the results of conversions which go through an
+    /// `f64`, such as `from_day_number_and_fraction`(), where `==` is too
+    /// strict. Both are converted to an `Instant`, so leap seconds are taken
+    /// into account.
+    #[must_use]
+    pub fn approx_eq(&self, other: &Self, tolerance: Duration) -> bool {
+        Instant::from(*self).approx_eq(&Instant::from(*other), tolerance)
+    }
+
+    /// The earlier of this and `other`
+    #[must_use]
+    pub fn min(self, other: Self) -> Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// The later of this and `other`
+    #[must_use]
+    pub fn max(self, other: Self) -> Self {
+        if other > self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// This `DateTime` restricted to the range `min` ..= `max`
+    ///
+    /// In debug builds this asserts that `min <= max`.
+    #[must_use]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        debug_assert!(min <= max, "clamp: min must not be after max");
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
+    // The multiples of `unit` from the calendar epoch at or below, and above,
+    // this `DateTime`, along with the number of units to the lower one and
+    // whether this `DateTime` is exactly on it
+    fn unit_bounds(&self, unit: Duration) -> (Self, Self, i128, bool) {
+        let (units, remainder) = self.duration_from_epoch().split_units(unit);
+        if self.second() == 60 {
+            return self.leap_second_unit_bounds(unit, units, remainder);
+        }
+        let lower = Duration::from_attos_i128(units * unit.as_attos_i128());
+        (
+            Self::from_duration_from_epoch(lower),
+            Self::from_duration_from_epoch(lower + unit),
+            units,
+            remainder == 0,
+        )
+    }
+
+    // unit_bounds() during a leap second, given the split of its duration from
+    // the epoch. That duration counts `:60` as the following midnight, so the
+    // multiples found from it may be after this `DateTime`.
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    fn leap_second_unit_bounds(
+        &self,
+        unit: Duration,
+        units: i128,
+        remainder: i128,
+    ) -> (Self, Self, i128, bool) {
+        let unit_attos = unit.as_attos_i128();
+        let midnight = self.truncated_to_second().duration_from_epoch();
+
+        // Units of a second or less have multiples within the leap second
+        if 1_000_000_000_000_000_000 % unit_attos == 0 {
+            let mut lower = *self;
+            lower.attos = (i128::from(self.attos) - remainder) as u64;
+            let mut upper = lower;
+            upper.attos += unit_attos as u64;
+            if upper.attos > 999_999_999_999_999_999 {
+                upper = Self::from_duration_from_epoch(midnight);
+            }
+            return (lower, upper, units, remainder == 0);
+        }
+
+        // Otherwise the multiples are either side of the leap second
+        let (units, _) = (midnight - Duration::new(0, 1)).split_units(unit);
+        let lower = Duration::from_attos_i128(units * unit_attos);
+        (
+            Self::from_duration_from_epoch(lower),
+            Self::from_duration_from_epoch(lower + unit),
+            units,
+            false,
+        )
+    }
+}
+
+impl<C: Calendar> DateTime<C, Utc> {
+    /// Create a new `DateTime` from the given parts, checking leap seconds
+    /// against the leap second table
+    ///
+    /// Unlike `new`(), second 60 is only accepted at 23:59 on a day which ends
+    /// in an inserted leap second. On a day which ends in a removed leap
+    /// second, 23:59:59 does not exist, and is refused too.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if any input is outside of the normal
+    /// range (as with `new`()), or if the second does not exist on that day.
+    pub fn checked_new(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        attosecond: u64,
+    ) -> Result<Self, Error> {
+        let dt = Self::new(year, month, day, hour, minute, second, attosecond)?;
+        if !dt.second_exists() {
+            return Err(Error::RangeError);
+        }
+        Ok(dt)
+    }
+
+    /// The number of seconds in the day of this `DateTime`.
+    ///
+    /// This is normally 86400, but is 86401 on a day which ends with an inserted
+    /// leap second (and would be 86399 on a day which ends with a removed leap
+    /// second).
+    #[must_use]
+    pub fn seconds_in_day(&self) -> u32 {
+        let start_of_day = self.day_number() * 86400;
+        crate::standard::utc_seconds_in_day(Duration::new(start_of_day, 0) + C::epoch().0)
+    }
+
+    /// This date and time in local civil time at a fixed `offset` ahead of UTC,
+    /// such as `+05:30`
+    ///
+    /// See `FixedOffsetDateTime`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if `offset` is not a whole number of
+    /// minutes, or is a day or more either way.
+    pub fn with_fixed_offset(&self, offset: Duration) -> Result<FixedOffsetDateTime<C>, Error> {
+        if offset.attos_part() != 0
+            || offset.seconds_part() % 60 != 0
+            || offset.seconds_part().abs() >= 86400
+        {
+            return Err(Error::RangeError);
+        }
+        let local = if self.second() == 60 {
+            let mut local = self.truncated_to_minute() + Duration::new(59, 0) + offset;
+            local.set_second(60)?;
+            local.set_attosecond(self.attosecond())?;
+            local
+        } else {
+            *self + offset
+        };
+        Ok(FixedOffsetDateTime::new(*self, local, offset))
+    }
+
+    /// Convert to an `Instant`, applying leap seconds according to `policy`
+    ///
+    /// With `LeapPolicy::Table` this is the same as `Instant::from`().
+    #[must_use]
+    pub fn to_instant_with_policy(&self, policy: LeapPolicy) -> Instant {
+        let dur = self.duration_from_epoch() + C::epoch().0;
+        Instant(Utc::to_tt_with_policy(dur, policy))
+    }
+
+    /// Convert from an `Instant`, applying leap seconds according to `policy`
+    ///
+    /// With `LeapPolicy::Table` this is the same as `DateTime::from`().
+    #[must_use]
+    pub fn from_instant_with_policy(instant: Instant, policy: LeapPolicy) -> Self {
+        let dur = Utc::from_tt_with_policy(instant.0, policy);
+        Self::from_duration_from_epoch(dur - C::epoch().0)
+    }
+}
+
+impl DateTime<Gregorian, Utc> {
+    /// The current date and time, from the system clock
+    ///
+    /// This is a convenience for `Instant::now`() followed by a conversion, so
+    /// the same leap second correction applies.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if the system clock is set so far from the
+    /// UNIX epoch that it cannot be represented.
+    #[cfg(all(
+        feature = "std",
+        not(all(target_arch = "wasm32", target_os = "unknown"))
+    ))]
+    pub fn now() -> Result<Self, Error> {
+        Ok(From::from(Instant::now()?))
+    }
+
+    /// Describe this date and time relative to `reference`, such as
+    /// `3 hours ago` or `in 2 days`
+    ///
+    /// See `Instant::relative_to`().
+    #[must_use]
+    pub fn relative_to(&self, reference: Instant) -> String {
+        Instant::from(*self).relative_to(reference)
+    }
+
+    /// Describe this date and time relative to the current time, such as
+    /// `3 hours ago`
+    ///
+    /// See `Instant::relative_to_now`().
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if the system clock is set so far from the
+    /// UNIX epoch that it cannot be represented.
+    #[cfg(all(
+        feature = "std",
+        not(all(target_arch = "wasm32", target_os = "unknown"))
+    ))]
+    pub fn relative_to_now(&self) -> Result<String, Error> {
+        Instant::from(*self).relative_to_now()
+    }
+
+    /// Format as a two line element set epoch, such as `08264.51782528`
+    ///
+    /// This is a two digit year followed by the day of the year and its
+    /// fraction (see `day_of_year_fraction`()), truncated to eight decimal
+    /// places. Two digit years from 57 are in the 1900s, and the rest are in
+    /// the 2000s.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if the year is not from 1957 to 2056.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn to_tle_epoch(&self) -> Result<String, Error> {
+        if !(1957..=2056).contains(&self.year()) {
+            return Err(Error::RangeError);
+        }
+        // A leap second would otherwise round up into the next day
+        let fraction = ((self.day_fraction() * 1e8) as u32).min(99_999_999);
+        Ok(format!(
+            "{:02}{:03}.{:08}",
+            self.year() % 100,
+            self.ordinal(),
+            fraction
+        ))
+    }
+
+    /// Parse a two line element set epoch, such as `08264.51782528`
+    ///
+    /// See `to_tle_epoch`() for the format.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::ParseError` if the input is malformed, or
+    /// `Error::RangeError` if the day of the year is out of range.
+    pub fn from_tle_epoch(s: &str) -> Result<Self, Error> {
+        let s = s.trim();
+        let bad = || Error::ParseError(format!("Invalid TLE epoch: {s}"));
+        if s.len() < 3
+            || !s.is_char_boundary(2)
+            || !s[..2].bytes().all(|b| b.is_ascii_digit())
+            || !s[2..].bytes().all(|b| b.is_ascii_digit() || b == b'.')
+        {
+            return Err(bad());
+        }
+        let yy: i32 = s[..2].parse().map_err(|_| bad())?;
+        let year = if yy >= 57 { 1900 + yy } else { 2000 + yy };
+        let day_of_year: f64 = s[2..].parse().map_err(|_| bad())?;
+        Self::from_year_and_fractional_day(year, day_of_year)
+    }
+}
+
+impl<S: Standard> DateTime<Gregorian, S> {
+    /// The number of ISO 8601 weeks in the ISO week numbering `year`, 52 or 53
+    ///
+    /// ISO weeks start on Monday, and week 1 is the week containing the first
+    /// Thursday of the year. A year has 53 weeks if it starts or ends on a
+    /// Thursday.
+    ///
+    /// # Panics
+    ///
+    /// Will only panic on a bug that caused internal values to get out of range.
+    #[must_use]
+    pub fn weeks_in_year(year: i32) -> u8 {
+        let weekday = |day| Gregorian::day_number(year, 1, day).unwrap().rem_euclid(7);
+        // Day numbers that are multiples of 7 are Mondays
+        let thursday = 3;
+        let december_31 = if Gregorian::is_year_leap(year) {
+            366
+        } else {
+            365
+        };
+        if weekday(1) == thursday || weekday(december_31) == thursday {
+            53
+        } else {
+            52
+        }
+    }
+
+    /// The ISO 8601 week date, as (ISO week numbering year, week, weekday)
+    ///
+    /// The ISO week numbering year differs from the calendar year for a few
+    /// days at the start or end of some years. For example, January 1st 2021 is
+    /// in week 53 of 2020, and December 29th 2008 is in week 1 of 2009.
+    ///
+    /// # Panics
+    ///
+    /// Panics in the first few days of the earliest year or the last few days
+    /// of the latest year, if the ISO week numbering year is out of range.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn iso_week(&self) -> (i32, u8, Weekday) {
+        let weekday = self.weekday_enum();
+        // The week belongs to the year that its Thursday is in
+        let thursday = self.day_number() - i64::from(weekday.number()) + 4;
+        let (year, _, _) =
+            Gregorian::from_day_number(thursday).expect("ISO week year out of range");
+        let week = (thursday - Gregorian::day_number(year, 1, 1).unwrap()) / 7 + 1;
+        (year, week as u8, weekday)
+    }
+
+    /// Create a `DateTime` at midnight on the given ISO 8601 week date
+    ///
+    /// See `iso_week`() and `weeks_in_year`().
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if `week` is not from 1 up to the
+    /// `weeks_in_year`() of `year`, or if the date is out of range.
+    pub fn from_iso_week(year: i32, week: u8, weekday: Weekday) -> Result<Self, Error> {
+        if week < 1 || week > Self::weeks_in_year(year) {
+            return Err(Error::RangeError);
+        }
+        // Week 1 is the week containing January 4th
+        let january_4 = Gregorian::day_number(year, 1, 4)?;
+        let week_1 = january_4 - january_4.rem_euclid(7);
+        Self::from_day_number(week_1 + (i64::from(week) - 1) * 7 + i64::from(weekday.number()) - 1)
+    }
+}
+
+impl<C: Calendar, S: Standard> fmt::Debug for DateTime<C, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:018} {} {}",
+            self.year(),
+            self.month(),
+            self.day(),
+            self.hour(),
+            self.minute(),
+            self.second(),
+            self.attosecond(),
+            C::name(),
+            S::abbrev()
+        )
+    }
+}
+
+/// Formats as `2000-01-01 12:00:00.000000000000000000 Gregorian TT`
+///
+/// The alternate form (`{:#}`) leaves off trailing zeroes of the fraction of a
+/// second, and the decimal point too if it is zero, as in
+/// `2000-01-01 12:00:00 Gregorian TT` or `1965-03-07 14:29:42.5 Gregorian UTC`.
+impl<C: Calendar, S: Standard> fmt::Display for DateTime<C, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+            self.year(),
+            self.month(),
+            self.day(),
+            self.hour(),
+            self.minute(),
+            self.second(),
+        )?;
+        let mut attos = self.attosecond();
+        if !f.alternate() {
+            write!(f, ".{attos:018}")?;
+        } else if attos != 0 {
+            let mut digits = 18;
+            while attos.is_multiple_of(10) {
+                attos /= 10;
+                digits -= 1;
+            }
+            write!(f, ".{attos:0digits$}")?;
+        }
+        write!(f, " {} {}", C::name(), S::abbrev())
+    }
+}
+
+impl<C: Calendar, S: Standard> FromStr for DateTime<C, S> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_iso8601(s)
+    }
+}
+
+impl<C: Calendar, S: Standard> Add<Duration> for DateTime<C, S> {
+    type Output = Self;
+
+    #[allow(clippy::cast_possible_wrap)]
+    fn add(self, rhs: Duration) -> Self {
+        Self::new_abnormal(
+            self.year(),
+            i64::from(self.month()),
+            i64::from(self.day()),
+            i64::from(self.hour()),
+            i64::from(self.minute()),
+            i64::from(self.second()) + rhs.seconds_part(),
+            self.attosecond() as i64 + rhs.attos_part(),
+        )
+    }
+}
+
+impl<C: Calendar, S: Standard> Sub<Duration> for DateTime<C, S> {
+    type Output = Self;
+
+    #[allow(clippy::cast_possible_wrap)]
+    fn sub(self, rhs: Duration) -> Self {
+        Self::new_abnormal(
+            self.year(),
+            i64::from(self.month()),
+            i64::from(self.day()),
+            i64::from(self.hour()),
+            i64::from(self.minute()),
+            i64::from(self.second()) - rhs.seconds_part(),
+            self.attosecond() as i64 - rhs.attos_part(),
+        )
+    }
+}
+
+/// The time elapsed from `other` to `self`
+///
+/// This counts any leap seconds in between, such as for `Utc`.
+impl<C: Calendar, S: Standard> Sub for DateTime<C, S> {
+    type Output = Duration;
+
+    #[allow(clippy::cast_possible_wrap)]
+    fn sub(self, other: Self) -> Duration {
+        if !S::is_continuous() {
+            return Instant::from(self) - Instant::from(other);
+        }
+
+        let secs = (self.day_number() - other.day_number()) * 86400
+            + (i64::from(self.hour()) - i64::from(other.hour())) * 3600
+            + (i64::from(self.minute()) - i64::from(other.minute())) * 60
+            + (i64::from(self.second()) - i64::from(other.second()));
+        let attos = self.attosecond() as i64 - other.attosecond() as i64;
+        Duration::new(secs, attos) // it will normalize
+    }
+}
+
+/// Whether two `DateTime`s, possibly in different `Calendar`s and time
+/// `Standard`s, represent the same moment in time
+///
+/// See `DateTime::cmp_instant`().
+#[must_use]
+pub fn same_instant<C1: Calendar, S1: Standard, C2: Calendar, S2: Standard>(
+    a: &DateTime<C1, S1>,
+    b: &DateTime<C2, S2>,
+) -> bool {
+    a.cmp_instant(b) == Ordering::Equal
+}
+
+// Clone and Copy are not derived, as that would require `C` and `S` to be `Copy`
+impl<C: Calendar, S: Standard> Clone for DateTime<C, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C: Calendar, S: Standard> Copy for DateTime<C, S> {}
+
+/// The default `DateTime` is the start of the calendar epoch,
+/// `0001-01-01 00:00:00` in the `DateTime`'s own `Calendar` and `Standard`.
+impl<C: Calendar, S: Standard> Default for DateTime<C, S> {
+    fn default() -> Self {
+        unsafe { Self::new_unchecked(1, 1, 1, 0, 0, 0, 0) }
+    }
+}
+
+// Equality, ordering and hashing are all defined on the chronological key, which
+// ignores the reserved bits, so that they are consistent with each other.
+impl<C: Calendar, S: Standard> PartialEq<Self> for DateTime<C, S> {
+    fn eq(&self, other: &Self) -> bool {
+        chronological_key(self.packed) == chronological_key(other.packed)
+            && self.attos == other.attos
+    }
+}
+
+impl<C: Calendar, S: Standard> Eq for DateTime<C, S> {}
+
+impl<C: Calendar, S: Standard> Ord for DateTime<C, S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        chronological_key(self.packed)
+            .cmp(&chronological_key(other.packed))
+            .then(self.attos.cmp(&other.attos))
+    }
+}
+
+impl<C: Calendar, S: Standard> PartialOrd<Self> for DateTime<C, S> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Calendar, S: Standard> Hash for DateTime<C, S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        chronological_key(self.packed).hash(state);
+        self.attos.hash(state);
+    }
+}
+
+unsafe impl<C: Calendar, S: Standard> Send for DateTime<C, S> {}
+
+impl<S: Standard> TryFrom<DateTime<Gregorian, S>> for DateTime<Julian, S> {
+    type Error = Error;
+    fn try_from(input: DateTime<Gregorian, S>) -> Result<Self, Self::Error> {
+        input.to_calendar()
+    }
+}
+
+impl<S: Standard> TryFrom<DateTime<Julian, S>> for DateTime<Gregorian, S> {
+    type Error = Error;
+    fn try_from(input: DateTime<Julian, S>) -> Result<Self, Self::Error> {
+        input.to_calendar()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unreadable_literal)]
+mod test {
+    use super::{same_instant, DateTime};
+    use crate::calendar::{Gregorian, Julian};
+    use crate::duration::Duration;
+    use crate::epoch::Epoch;
+    use crate::era::Era;
+    use crate::error::Error;
+    use crate::instant::Instant;
+    use crate::month::Month;
+    use crate::period::Period;
+    use crate::standard::{Tai, Tcg, Tt, Utc, UtcSmeared};
+    use crate::weekday::Weekday;
+    use core::marker::PhantomData;
+    use std::cmp::Ordering;
+
+    #[test]
+    fn test_range_errors() {
+        crate::setup_logging();
+
+        assert!(DateTime::<Gregorian, Tt>::new(2000, 0, 31, 0, 0, 0, 0).is_err());
+        assert!(DateTime::<Gregorian, Tt>::new(2000, 13, 31, 0, 0, 0, 0).is_err());
+        assert!(DateTime::<Gregorian, Tt>::new(2000, 6, 0, 0, 0, 0, 0).is_err());
+        assert!(DateTime::<Gregorian, Tt>::new(2000, 6, 31, 0, 0, 0, 0).is_err());
+        assert!(DateTime::<Gregorian, Tt>::new(2000, 7, 32, 0, 0, 0, 0).is_err());
+        assert!(DateTime::<Gregorian, Tt>::new(2003, 2, 29, 0, 0, 0, 0).is_err());
+        assert!(DateTime::<Gregorian, Tt>::new(2004, 2, 29, 24, 0, 0, 0).is_err());
+        assert!(DateTime::<Gregorian, Tt>::new(2004, 2, 29, 0, 60, 0, 0).is_err());
+        assert!(DateTime::<Gregorian, Tt>::new(2004, 2, 29, 0, 0, 61, 0).is_err());
+        assert!(
+            DateTime::<Gregorian, Tt>::new(2004, 2, 29, 0, 0, 0, 1_000_000_000_000_000_000)
+                .is_err()
+        );
+
+        let _ = DateTime::<Gregorian, Tt>::new_abnormal(0, 1, 31, 0, 0, 0, 0);
+        let _ = DateTime::<Gregorian, Tt>::new_abnormal(2000, 0, 31, 0, 0, 0, 0);
+        let _ = DateTime::<Gregorian, Tt>::new_abnormal(2000, 13, 31, 0, 0, 0, 0);
+        let _ = DateTime::<Gregorian, Tt>::new_abnormal(2000, 6, 0, 0, 0, 0, 0);
+        let _ = DateTime::<Gregorian, Tt>::new_abnormal(2000, 6, 31, 0, 0, 0, 0);
+        let _ = DateTime::<Gregorian, Tt>::new_abnormal(2000, 7, 32, 0, 0, 0, 0);
+        let _ = DateTime::<Gregorian, Tt>::new_abnormal(2003, 2, 29, 0, 0, 0, 0);
+        let _ = DateTime::<Gregorian, Tt>::new_abnormal(2004, 2, 29, 24, 0, 0, 0);
+        let _ = DateTime::<Gregorian, Tt>::new_abnormal(2004, 2, 29, 0, 60, 0, 0);
+        let _ = DateTime::<Gregorian, Tt>::new_abnormal(2004, 2, 29, 0, 0, 61, 0);
+        let _ = DateTime::<Gregorian, Tt>::new_abnormal(
+            2004,
+            2,
+            29,
+            0,
+            0,
+            0,
+            1_000_000_000_000_000_000,
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        crate::setup_logging();
+
+        // This is right out of leap second file for 1 Jan 1972
+        let dt = DateTime::<Gregorian, Tt>::new_abnormal(1900, 1, 1, 0, 0, 2272060800, 0);
+        assert_eq!(dt.year(), 1972);
+        assert_eq!(dt.month(), 1);
+        assert_eq!(dt.day(), 1);
+        assert_eq!(dt.hour(), 0);
+        assert_eq!(dt.minute(), 0);
+        assert_eq!(dt.second(), 0);
+        assert_eq!(dt.attosecond(), 0);
+
+        // 3rd leap second
+        // NOTE FIXME ELSEWHERE: t1900 must not include leap seconds, or else
+        // this would be off by 2 as it does not account for the 2 leap seconds
+        // added prior to it.
+        let dt = DateTime::<Gregorian, Tt>::new_abnormal(1900, 1, 1, 0, 0, 2303683200, 0);
+        assert_eq!(dt.year(), 1973);
+        assert_eq!(dt.month(), 1);
+        assert_eq!(dt.day(), 1);
+        assert_eq!(dt.hour(), 0);
+        assert_eq!(dt.minute(), 0);
+        assert_eq!(dt.second(), 0);
+        assert_eq!(dt.attosecond(), 0);
+
+        // Test hour roll over that crosses a month during the end of
+        // February during a leap year
+        let dt = DateTime::<Gregorian, Tt>::new_abnormal(1972, 2, 29, 25, 0, 0, 0);
+        assert_eq!(dt.month(), 3); // mar
+        assert_eq!(dt.day(), 1); // 1st
+        assert_eq!(dt.hour(), 1);
+
+        // Test some negative values
+        let dt = DateTime::<Gregorian, Tt>::new_abnormal(
+            2000,
+            1 - 11,
+            1 + (365 - 31),
+            -12,
+            60 * 12,
+            0,
+            0,
+        );
+        // We subtract 11 months, but add back the (365-11) days
+        // We subtract 12 hours, but add back the (60*12) minutes
+        assert_eq!(dt.year(), 2000);
+        assert_eq!(dt.month(), 1);
+        assert_eq!(dt.day(), 1);
+        assert_eq!(dt.hour(), 0);
+        assert_eq!(dt.minute(), 0);
+        assert_eq!(dt.second(), 0);
+        assert_eq!(dt.attosecond(), 0);
+
+        // Test further negative values
+        let dt =
+            DateTime::<Gregorian, Tt>::new_abnormal(2000, 1 - 60, 1 + (365 * 4 + 366), 0, 0, 0, 0);
+        // We subtract 60 months, but add back the (365 + 365 + 365 + 366 + 365) days
+        // We subtract 12 hours, but add back the (60*12) minutes
+        assert_eq!(dt.year(), 2000);
+        assert_eq!(dt.month(), 1);
+        assert_eq!(dt.day(), 1);
+
+        // Test year rollover
+        let dt = DateTime::<Gregorian, Tt>::new_abnormal(1970, 12, 31, 25, 0, 0, 0);
+        assert_eq!(dt.year(), 1971);
+        assert_eq!(dt.month(), 1);
+        assert_eq!(dt.day(), 1);
+        assert_eq!(dt.hour(), 1);
+    }
+
+    #[test]
+    fn test_day_number() {
+        crate::setup_logging();
+
+        let dt = DateTime::<Gregorian, Tt>::new(1, 1, 1, 0, 0, 0, 0).unwrap(); // year 1
+        assert_eq!(dt.day_number(), 0);
+
+        let dt2 = DateTime::<Gregorian, Tt>::from_day_number(dt.day_number()).unwrap();
+        assert_eq!(dt, dt2);
+
+        let dt = DateTime::<Gregorian, Tt>::new(2000, 1, 1, 0, 0, 0, 0).unwrap();
+        assert_eq!(dt.day_number(), 730119);
+
+        let dt2 = DateTime::<Gregorian, Tt>::from_day_number(dt.day_number()).unwrap();
+        assert_eq!(dt, dt2);
+
+        assert_eq!(dt2.day_number(), dt.day_number());
+    }
+
+    #[test]
+    fn test_day_fraction() {
+        use float_cmp::ApproxEq;
+
+        crate::setup_logging();
+
+        let g1 = DateTime::<Gregorian, Tt>::new(2000, 1, 1, 12, 0, 0, 0).unwrap();
+        assert!(g1.day_fraction().approx_eq(0.5, (0.0, 1)));
+        let g2 = DateTime::<Gregorian, Tt>::new(2000, 1, 1, 18, 0, 0, 0).unwrap();
+        assert!(g2.day_fraction().approx_eq(0.75, (0.0, 1)));
+        let g3 = DateTime::<Gregorian, Tt>::new(2000, 1, 1, 0, 0, 1, 0).unwrap();
+        assert!(g3.day_fraction().approx_eq(1. / 86400., (0.0, 1)));
+
+        let g4 =
+            DateTime::<Gregorian, Tt>::from_day_number_and_fraction(g1.day_number(), 0.75).unwrap();
+        assert_eq!(g4, g2);
+
+        let g4 =
+            DateTime::<Gregorian, Tt>::from_day_number_and_fraction(g1.day_number(), 19. / 97.)
+                .unwrap();
+        assert!(g4.day_fraction().approx_eq(19. / 97., (0.0, 1)));
+    }
+
+    #[test]
+    fn test_day_number_and_attos() {
+        crate::setup_logging();
+
+        let dt = DateTime::<Gregorian, Tt>::new(2000, 1, 1, 13, 46, 40, 1).unwrap();
+        let exact = DateTime::<Gregorian, Tt>::from_day_number_and_attos(dt.day_number(), 49600, 1)
+            .unwrap();
+        assert_eq!(exact, dt);
+        let float = DateTime::<Gregorian, Tt>::from_day_number_and_fraction(
+            dt.day_number(),
+            dt.day_fraction(),
+        )
+        .unwrap();
+        assert_ne!(float, dt);
+
+        let last = DateTime::<Gregorian, Tt>::from_day_number_and_attos(
+            dt.day_number(),
+            86399,
+            999_999_999_999_999_999,
+        )
+        .unwrap();
+        assert_eq!(last, dt.end_of_day());
+        assert!(
+            DateTime::<Gregorian, Tt>::from_day_number_and_attos(dt.day_number(), 86400, 0)
+                .is_err()
+        );
+        assert!(DateTime::<Gregorian, Tt>::from_day_number_and_attos(
+            dt.day_number(),
+            0,
+            1_000_000_000_000_000_000
+        )
+        .is_err());
+
+        // 86400 is only in range on a leap second day
+        let leap_day = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 0, 0, 0, 0).unwrap();
+        assert_eq!(
+            DateTime::<Gregorian, Utc>::from_day_number_and_attos(leap_day.day_number(), 86400, 5)
+                .unwrap(),
+            DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 5).unwrap()
+        );
+        assert!(DateTime::<Gregorian, Utc>::from_day_number_and_attos(
+            leap_day.day_number() - 1,
+            86400,
+            5
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_time_of_day_angles() {
+        use float_cmp::ApproxEq;
+        use std::f64::consts::PI;
+
+        crate::setup_logging();
+
+        let noon = DateTime::<Gregorian, Tt>::new(2000, 1, 1, 12, 0, 0, 0).unwrap();
+        assert!(noon.as_hours_f64().approx_eq(12.0, (0.0, 1)));
+        assert!(noon.as_degrees_f64().approx_eq(180.0, (0.0, 1)));
+        assert!(noon.as_radians_f64().approx_eq(PI, (0.0, 1)));
+
+        let midnight = noon.start_of_day();
+        assert!(midnight.as_hours_f64().approx_eq(0.0, (0.0, 1)));
+        assert!(midnight.as_radians_f64().approx_eq(0.0, (0.0, 1)));
+
+        let dt = DateTime::<Gregorian, Tt>::new(2000, 1, 1, 6, 30, 36, 0).unwrap();
+        assert!(dt.as_hours_f64().approx_eq(6.51, (0.0, 1)));
+        assert!(dt.as_degrees_f64().approx_eq(97.65, (0.0, 1)));
+
+        // Sub-second precision is kept
+        let dt = DateTime::<Gregorian, Tt>::new(2000, 1, 1, 0, 0, 0, 36_000_000_000_000).unwrap();
+        assert!(dt.as_hours_f64().approx_eq(1e-8, (0.0, 1)));
+    }
+
+    #[test]
+    fn test_extractors() {
+        crate::setup_logging();
+
+        let g = DateTime::<Gregorian, Tt>::new(1965, 3, 7, 14, 29, 42, 500_000_000_000_000_000)
+            .unwrap();
+        assert_eq!(g.year(), 1965);
+        assert_eq!(g.month(), 3);
+        assert_eq!(g.month0(), 2);
+        assert_eq!(g.day(), 7);
+        assert_eq!(g.day0(), 6);
+        assert_eq!(g.hour(), 14);
+        assert_eq!(g.minute(), 29);
+        assert_eq!(g.second(), 42);
+        assert_eq!(g.attosecond(), 500_000_000_000_000_000);
+    }
+
+    #[test]
+    fn test_sub_second_units() {
+        crate::setup_logging();
+
+        let mut dt = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 0).unwrap();
+        dt.set_millisecond(500).unwrap();
+        assert_eq!(dt.attosecond(), 500_000_000_000_000_000);
+        assert_eq!(dt.millisecond(), 500);
+        assert_eq!(dt.microsecond(), 500_000);
+        assert_eq!(dt.nanosecond(), 500_000_000);
+        assert_eq!(dt.second(), 60);
+
+        // Finer parts are truncated when read, and cleared when set
+        dt.set_attosecond(123_456_789_987_654_321).unwrap();
+        assert_eq!(dt.millisecond(), 123);
+        assert_eq!(dt.microsecond(), 123_456);
+        assert_eq!(dt.nanosecond(), 123_456_789);
+        dt.set_microsecond(999_999).unwrap();
+        assert_eq!(dt.attosecond(), 999_999_000_000_000_000);
+        dt.set_nanosecond(1).unwrap();
+        assert_eq!(dt.attosecond(), 1_000_000_000);
+
+        assert!(dt.set_millisecond(1000).is_err());
+        assert!(dt.set_microsecond(1_000_000).is_err());
+        assert!(dt.set_nanosecond(1_000_000_000).is_err());
+        assert_eq!(dt.attosecond(), 1_000_000_000);
+    }
+
+    #[test]
+    fn test_setters() {
+        crate::setup_logging();
+
+        let mut g = DateTime::<Gregorian, Tt>::new(1965, 3, 7, 14, 29, 42, 500_000_000_000_000_000)
+            .unwrap();
+
+        g.set_year(1921);
+        assert_eq!(g.year(), 1921);
+
+        g.set_month(1).unwrap();
+        assert_eq!(g.month(), 1);
+
+        g.set_day(17).unwrap();
+        assert_eq!(g.day(), 17);
+
+        g.set_hour(3).unwrap();
+        assert_eq!(g.hour(), 3);
+
+        g.set_minute(55).unwrap();
+        assert_eq!(g.minute(), 55);
+
+        g.set_second(51).unwrap();
+        assert_eq!(g.second(), 51);
+
+        g.set_attosecond(123_456_789_012_345_678).unwrap();
+        assert_eq!(g.attosecond(), 123_456_789_012_345_678);
+
+        let h = DateTime::<Gregorian, Tt>::new(1921, 1, 17, 3, 55, 51, 123_456_789_012_345_678)
+            .unwrap();
+
+        assert_eq!(g, h);
+
+        let mut g = DateTime::<Gregorian, Tt>::new(1997, 3, 30, 17, 24, 6, 2_340_897).unwrap();
+        assert!(g.set_month(2).is_err());
+        assert_eq!(g.month(), 3);
+        assert!(g.set_day(28).is_ok());
+        assert!(g.set_month(2).is_ok());
+        assert_eq!(g.month(), 2);
+        assert_eq!(g.day(), 28);
+    }
+
+    #[test]
+    fn test_replacers() {
+        crate::setup_logging();
+
+        let mut g = DateTime::<Gregorian, Tt>::new(1965, 3, 7, 14, 29, 42, 500_000_000_000_000_000)
+            .unwrap();
+
+        assert_eq!(g.replace_year(1921), 1965);
+        assert_eq!(g.replace_month(1).unwrap(), 3);
+        assert_eq!(g.replace_day(17).unwrap(), 7);
+        assert_eq!(g.replace_hour(3).unwrap(), 14);
+        assert_eq!(g.replace_minute(55).unwrap(), 29);
+        assert_eq!(g.replace_second(51).unwrap(), 42);
+        assert_eq!(
+            g.replace_attosecond(123_456_789_012_345_678).unwrap(),
+            500_000_000_000_000_000
+        );
+        assert_eq!(
+            g,
+            DateTime::<Gregorian, Tt>::new(1921, 1, 17, 3, 55, 51, 123_456_789_012_345_678)
+                .unwrap()
+        );
+
+        // Errors leave the field unchanged
+        let before = g;
+        assert!(matches!(g.replace_month(13), Err(Error::RangeError)));
+        assert!(matches!(g.replace_day(32), Err(Error::RangeError)));
+        assert!(matches!(g.replace_hour(24), Err(Error::RangeError)));
+        assert!(matches!(g.replace_minute(60), Err(Error::RangeError)));
+        assert!(matches!(g.replace_second(61), Err(Error::RangeError)));
+        assert!(matches!(
+            g.replace_attosecond(2_000_000_000_000_000_000),
+            Err(Error::RangeError)
+        ));
+        assert_eq!(g, before);
+
+        // Undo by replacing with the previous value
+        let old = g.replace_day(31).unwrap();
+        assert!(g.replace_month(2).is_err());
+        g.replace_day(old).unwrap();
+        assert_eq!(g, before);
+    }
+
+    #[test]
+    fn test_comparison() {
+        crate::setup_logging();
+
+        let g = DateTime::<Gregorian, Tt>::new(1965, 3, 7, 14, 29, 42, 500_000_000_000_000_000)
+            .unwrap();
+        let h = DateTime::<Gregorian, Tt>::new(1966, 1, 17, 3, 55, 51, 123_456_789_012_345_678)
+            .unwrap();
+        let i = DateTime::<Gregorian, Tt>::new(1966, 3, 7, 14, 29, 42, 500_000_000_000_000_000)
+            .unwrap();
+        let j = DateTime::<Gregorian, Tt>::new(1966, 3, 7, 14, 29, 42, 500_000_000_000_000_000)
+            .unwrap();
+        assert!(g < h);
+        assert!(h < i);
+        assert!(i == j);
+    }
+
+    #[test]
+    fn test_math() {
+        crate::setup_logging();
+
+        let g = DateTime::<Gregorian, Tt>::new(1996, 3, 2, 0, 0, 0, 50).unwrap();
+        let week_less_150ns = Duration::new(86400 * 7, 150);
+        let earlier = g - week_less_150ns;
+        assert_eq!(earlier.year(), 1996);
+        assert_eq!(earlier.month(), 2);
+        assert_eq!(earlier.day(), 23);
+        assert_eq!(earlier.hour(), 23);
+        assert_eq!(earlier.minute(), 59);
+        assert_eq!(earlier.second(), 59);
+        assert_eq!(earlier.attosecond(), 1_000_000_000_000_000_000 - 100);
+
+        let g1 = DateTime::<Gregorian, Tt>::new(2000, 1, 1, 0, 0, 0, 0).unwrap();
+        let g2 = DateTime::<Gregorian, Tt>::new(2001, 2, 2, 1, 3, 5, 11).unwrap();
+        let diff = g2 - g1;
+        assert_eq!(
+            diff.seconds_part(),
+            366 * 86400 + 31 * 86400 + 86400 + 3600 + 3 * 60 + 5
+        );
+        assert_eq!(diff.attos_part(), 11);
+    }
+
+    #[test]
+    fn test_sub_across_leap_second() {
+        crate::setup_logging();
+
+        let utc = |h, mi, s, attos| {
+            DateTime::<Gregorian, Utc>::new(2016, 12, 31, h, mi, s, attos).unwrap()
+        };
+        let after = DateTime::<Gregorian, Utc>::new(2017, 1, 1, 0, 0, 0, 0).unwrap();
+        let before = utc(23, 59, 59, 0);
+        let leap = utc(23, 59, 60, 0);
+        assert_eq!(after - before, Duration::new(2, 0));
+        assert_eq!(before - after, Duration::new(-2, 0));
+        assert_eq!(leap - before, Duration::new(1, 0));
+        assert_eq!(after - leap, Duration::new(1, 0));
+        assert_eq!(
+            after - utc(23, 59, 60, 250_000_000_000_000_000),
+            Duration::new(0, 750_000_000_000_000_000)
+        );
+        assert_eq!(after - utc(0, 0, 0, 0), Duration::new(86401, 0));
+
+        // Continuous standards are unaffected
+        let tai_before = DateTime::<Gregorian, Tai>::new(2016, 12, 31, 23, 59, 59, 0).unwrap();
+        let tai_after = DateTime::<Gregorian, Tai>::new(2017, 1, 1, 0, 0, 0, 0).unwrap();
+        assert_eq!(tai_after - tai_before, Duration::new(1, 0));
+    }
+
+    #[test]
+    fn test_display_alternate() {
+        crate::setup_logging();
+
+        let dt = DateTime::<Gregorian, Tt>::new(2000, 1, 1, 12, 0, 0, 0).unwrap();
+        assert_eq!(
+            format!("{dt}"),
+            "2000-01-01 12:00:00.000000000000000000 Gregorian TT"
+        );
+        assert_eq!(format!("{dt:#}"), "2000-01-01 12:00:00 Gregorian TT");
+
+        let dt = DateTime::<Gregorian, Utc>::new(1965, 3, 7, 14, 29, 42, 500_000_000_000_000_000)
+            .unwrap();
+        assert_eq!(format!("{dt:#}"), "1965-03-07 14:29:42.5 Gregorian UTC");
+
+        let dt = DateTime::<Julian, Tai>::new(-44, 3, 15, 0, 0, 0, 1).unwrap();
+        assert_eq!(
+            format!("{dt:#}"),
+            "-044-03-15 00:00:00.000000000000000001 Julian TAI"
+        );
+        let dt = dt.with_attosecond(999_999_999_999_999_999).unwrap();
+        assert_eq!(format!("{dt:#}"), format!("{dt}"));
+        let dt = dt.with_attosecond(10_000_000).unwrap();
+        assert_eq!(
+            format!("{dt:#}"),
+            "-044-03-15 00:00:00.00000000001 Julian TAI"
+        );
+    }
+
+    #[test]
+    fn test_print_extremes() {
+        crate::setup_logging();
+
+        let min = DateTime::<Gregorian, Tt>::new(i32::MIN, 1, 1, 0, 0, 0, 0).unwrap();
+        info!("Min gregorian: {min}");
+        let max =
+            DateTime::<Gregorian, Tt>::new(i32::MAX, 12, 31, 23, 59, 59, 999_999_999_999_999_999)
+                .unwrap();
+        info!("Max gregorian: {max}");
+    }
+
+    #[test]
+    fn test_bc_day_numbers() {
+        crate::setup_logging();
+
+        let mar1 = DateTime::<Gregorian, Tt>::new(0, 3, 1, 0, 0, 0, 0).unwrap();
+        let feb29 = DateTime::<Gregorian, Tt>::new(0, 2, 29, 0, 0, 0, 0).unwrap();
+        let feb28 = DateTime::<Gregorian, Tt>::new(0, 2, 28, 0, 0, 0, 0).unwrap();
+        assert_eq!(mar1.day_number(), -306);
+        assert_eq!(feb29.day_number(), -307);
+        assert_eq!(feb28.day_number(), -308);
+
+        let mar1x = DateTime::<Gregorian, Tt>::from_day_number(-306).unwrap();
+        let feb29x = DateTime::<Gregorian, Tt>::from_day_number(-307).unwrap();
+        let feb28x = DateTime::<Gregorian, Tt>::from_day_number(-308).unwrap();
+        assert_eq!(mar1, mar1x);
+        assert_eq!(feb29, feb29x);
+        assert_eq!(feb28, feb28x);
+    }
+
+    #[test]
+    fn test_convert_calendar() {
+        crate::setup_logging();
+
+        let j = DateTime::<Julian, Tt>::new(1582, 10, 5, 0, 0, 0, 0).unwrap();
+        let g = DateTime::<Gregorian, Tt>::new(1582, 10, 15, 0, 0, 0, 0).unwrap();
+        let j2: DateTime<Julian, Tt> = TryFrom::try_from(g).unwrap();
+        assert_eq!(j, j2);
+        let g2: DateTime<Gregorian, Tt> = TryFrom::try_from(j).unwrap();
+        assert_eq!(g, g2);
+
+        let j = DateTime::<Julian, Tt>::new(1582, 10, 4, 0, 0, 0, 0).unwrap();
+        let g = DateTime::<Gregorian, Tt>::new(1582, 10, 14, 0, 0, 0, 0).unwrap();
+        let j2: DateTime<Julian, Tt> = TryFrom::try_from(g).unwrap();
+        assert_eq!(j, j2);
+        let g2: DateTime<Gregorian, Tt> = TryFrom::try_from(j).unwrap();
+        assert_eq!(g, g2);
+
+        let j = DateTime::<Julian, Tt>::new(-4713, 1, 1, 0, 0, 0, 0).unwrap();
+        let g = DateTime::<Gregorian, Tt>::new(-4714, 11, 24, 0, 0, 0, 0).unwrap();
+        let j2: DateTime<Julian, Tt> = TryFrom::try_from(g).unwrap();
+        assert_eq!(j, j2);
+        let g2: DateTime<Gregorian, Tt> = TryFrom::try_from(j).unwrap();
+        assert_eq!(g, g2);
+
+        let j = DateTime::<Julian, Tt>::new(1, 1, 3, 0, 0, 0, 0).unwrap();
+        let g = DateTime::<Gregorian, Tt>::new(1, 1, 1, 0, 0, 0, 0).unwrap();
+        let j2: DateTime<Julian, Tt> = TryFrom::try_from(g).unwrap();
+        assert_eq!(j, j2);
+        let g2: DateTime<Gregorian, Tt> = TryFrom::try_from(j).unwrap();
+        assert_eq!(g, g2);
+
+        let j = DateTime::<Julian, Tt>::new(1, 1, 1, 0, 0, 0, 0).unwrap();
+        let g = DateTime::<Gregorian, Tt>::new(0, 12, 30, 0, 0, 0, 0).unwrap();
+        let j2: DateTime<Julian, Tt> = TryFrom::try_from(g).unwrap();
+        assert_eq!(j, j2);
+        let g2: DateTime<Gregorian, Tt> = TryFrom::try_from(j).unwrap();
+        assert_eq!(g, g2);
+    }
+
+    #[test]
+    fn test_convert_calendar_extreme_years() {
+        crate::setup_logging();
+
+        // Far from 1582 the calendars disagree by thousands of days, but the
+        // instant and the time of day must be preserved exactly.
+        let g = DateTime::<Gregorian, Tt>::new(2000000, 1, 1, 13, 14, 15, 16).unwrap();
+        let j = DateTime::<Julian, Tt>::new(1999958, 12, 9, 13, 14, 15, 16).unwrap();
+        let j2: DateTime<Julian, Tt> = TryFrom::try_from(g).unwrap();
+        assert_eq!(j, j2);
+        let g2: DateTime<Gregorian, Tt> = TryFrom::try_from(j).unwrap();
+        assert_eq!(g, g2);
+        assert_eq!(Instant::from(g), Instant::from(j2));
+
+        let g = DateTime::<Gregorian, Tt>::new(-2000000, 1, 1, 23, 59, 59, 999).unwrap();
+        let j = DateTime::<Julian, Tt>::new(-1999959, 1, 27, 23, 59, 59, 999).unwrap();
+        let j2: DateTime<Julian, Tt> = TryFrom::try_from(g).unwrap();
+        assert_eq!(j, j2);
+        let g2: DateTime<Gregorian, Tt> = TryFrom::try_from(j).unwrap();
+        assert_eq!(g, g2);
+        assert_eq!(Instant::from(g), Instant::from(j2));
+
+        // A leap second survives the conversion
+        let g = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 5).unwrap();
+        let j: DateTime<Julian, Utc> = TryFrom::try_from(g).unwrap();
+        assert_eq!(j.time(), (23, 59, 60, 5));
+        assert_eq!((j.month(), j.day()), (12, 18));
+    }
+
+    #[test]
+    fn test_to_standard() {
+        crate::setup_logging();
+
+        let tt =
+            DateTime::<Gregorian, Tt>::new(2017, 1, 1, 0, 1, 9, 184_000_000_000_000_000).unwrap();
+        let tai: DateTime<Gregorian, Tai> = tt.to_standard();
+        assert_eq!(tai, DateTime::new(2017, 1, 1, 0, 0, 37, 0).unwrap());
+        let utc: DateTime<Gregorian, Utc> = tai.to_standard();
+        assert_eq!(utc, DateTime::new(2017, 1, 1, 0, 0, 0, 0).unwrap());
+        assert_eq!(utc.to_standard::<Tai>(), tai);
+        assert_eq!(utc.to_standard::<Tt>(), tt);
+        assert_eq!(tt.to_standard::<Tt>(), tt);
+
+        // During a leap second
+        let leap = DateTime::<Julian, Utc>::new(2016, 12, 18, 23, 59, 60, 5).unwrap();
+        let tai = leap.to_standard::<Tai>();
+        assert_eq!(tai, DateTime::new(2016, 12, 19, 0, 0, 36, 5).unwrap());
+        assert_eq!(
+            (tai + Duration::new(1, 0)).to_standard::<Utc>(),
+            DateTime::new(2016, 12, 19, 0, 0, 0, 5).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_equals_date() {
+        crate::setup_logging();
+
+        // The first day of the Gregorian calendar
+        let julian = DateTime::<Julian, Utc>::new(1582, 10, 5, 6, 30, 0, 1).unwrap();
+        let gregorian = DateTime::<Gregorian, Utc>::new(1582, 10, 15, 6, 30, 0, 1).unwrap();
+        assert!(julian.equals_date(&gregorian));
+        assert!(gregorian.equals_date(&julian));
+        assert!(gregorian.equals_date(&gregorian));
+        assert_eq!(Instant::from(julian), Instant::from(gregorian));
+
+        // The same fields on different calendars are different days
+        let julian_oct_15 = DateTime::<Julian, Utc>::new(1582, 10, 15, 6, 30, 0, 1).unwrap();
+        assert!(!julian_oct_15.equals_date(&gregorian));
+
+        // The time of day counts too
+        let later = gregorian.with_attosecond(2).unwrap();
+        assert!(!julian.equals_date(&later));
+    }
+
+    #[test]
+    fn test_to_calendar() {
+        crate::setup_logging();
+
+        let g = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 7).unwrap();
+        let j: DateTime<Julian, Utc> = g.to_calendar().unwrap();
+        assert_eq!(j, DateTime::new(2016, 12, 18, 23, 59, 60, 7).unwrap());
+        assert_eq!(j.to_calendar::<Gregorian>().unwrap(), g);
+        assert_eq!(g.to_calendar::<Gregorian>().unwrap(), g);
+
+        // The Julian calendar runs ahead of the Gregorian in the far past and
+        // behind it in the far future, so only Gregorian dates can be converted
+        // at both ends of the range
+        for (y, m, d) in [(i32::MIN, 1, 1), (i32::MAX, 12, 31)] {
+            let g = DateTime::<Gregorian, Tt>::new(y, m, d, 12, 0, 0, 1).unwrap();
+            let j = g.to_calendar::<Julian>().unwrap();
+            assert!(same_instant(&g, &j));
+            assert_eq!(j.to_calendar::<Gregorian>().unwrap(), g);
+
+            let j = DateTime::<Julian, Tt>::new(y, m, d, 12, 0, 0, 1).unwrap();
+            assert!(matches!(
+                j.to_calendar::<Gregorian>(),
+                Err(Error::RangeError)
+            ));
         }
-        if self.day() != other.day() {
-            return self.day().cmp(&other.day());
+    }
+
+    #[test]
+    fn test_epoch_duration() {
+        crate::setup_logging();
+
+        let g = DateTime::<Gregorian, Tt>::new(1582, 10, 14, 0, 0, 0, 0).unwrap();
+        let h = DateTime::<Gregorian, Tt>::from_duration_from_epoch(g.duration_from_epoch());
+        assert_eq!(g, h);
+
+        let g = DateTime::<Julian, Tt>::new(1582, 10, 14, 11, 0, 5, 130).unwrap();
+        let h = DateTime::<Julian, Tt>::from_duration_from_epoch(g.duration_from_epoch());
+        assert_eq!(g, h);
+    }
+
+    #[test]
+    fn test_seconds_in_day() {
+        crate::setup_logging();
+
+        let normal = DateTime::<Gregorian, Utc>::new(2016, 12, 30, 15, 0, 0, 0).unwrap();
+        assert_eq!(normal.seconds_in_day(), 86400);
+
+        let leap_day = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 0, 0, 0, 0).unwrap();
+        assert_eq!(leap_day.seconds_in_day(), 86401);
+
+        let leap_second = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 0).unwrap();
+        assert_eq!(leap_second.seconds_in_day(), 86401);
+
+        let next_day = DateTime::<Gregorian, Utc>::new(2017, 1, 1, 0, 0, 0, 0).unwrap();
+        assert_eq!(next_day.seconds_in_day(), 86400);
+
+        let first_leap_day = DateTime::<Gregorian, Utc>::new(1972, 6, 30, 12, 0, 0, 0).unwrap();
+        assert_eq!(first_leap_day.seconds_in_day(), 86401);
+    }
+
+    #[test]
+    fn test_parse_iso8601_with_pivot() {
+        type Dt = DateTime<Gregorian, Utc>;
+
+        crate::setup_logging();
+
+        let year = |s| Dt::parse_iso8601_with_pivot(s, 1969).unwrap().year();
+        assert_eq!(year("69-07-20"), 1969);
+        assert_eq!(year("70-01-01T00:00:00Z"), 1970);
+        assert_eq!(year("99-12-31"), 1999);
+        assert_eq!(year("00-01-01"), 2000);
+        assert_eq!(year("68-12-31T23:59:59"), 2068);
+
+        // Years straddling a pivot from the other side
+        let year = |s| Dt::parse_iso8601_with_pivot(s, 2030).unwrap().year();
+        assert_eq!(year("29-01-01"), 2129);
+        assert_eq!(year("30-01-01"), 2030);
+        assert_eq!(year("31-01-01"), 2031);
+
+        // Longer years are not changed
+        let year = |s| Dt::parse_iso8601_with_pivot(s, 1969).unwrap().year();
+        assert_eq!(year("0068-01-01"), 68);
+        assert_eq!(year("1868-01-01"), 1868);
+        assert_eq!(year("-0068-01-01"), -68);
+
+        // Two digit years are otherwise rejected
+        assert!(Dt::parse_iso8601("70-01-01").is_err());
+        assert!(Dt::parse_iso8601_with_pivot("-70-01-01", 1969).is_err());
+        assert!(Dt::parse_iso8601_with_pivot("700-01-01", 1969).is_err());
+        assert!(Dt::parse_iso8601_with_pivot("7-01-01", 1969).is_err());
+    }
+
+    #[test]
+    fn test_ordinal_iso8601() {
+        crate::setup_logging();
+
+        let dt = DateTime::<Gregorian, Utc>::parse_ordinal_iso8601("2020-366").unwrap();
+        assert_eq!(dt, DateTime::new(2020, 12, 31, 0, 0, 0, 0).unwrap());
+        assert!(matches!(
+            DateTime::<Gregorian, Utc>::parse_ordinal_iso8601("2021-366"),
+            Err(Error::RangeError)
+        ));
+        assert!(DateTime::<Gregorian, Utc>::parse_ordinal_iso8601("2021-000").is_err());
+        assert!(matches!(
+            DateTime::<Gregorian, Utc>::parse_ordinal_iso8601("2021-06-30"),
+            Err(Error::ParseError(_))
+        ));
+        // 1900 is a leap year only in the Julian calendar
+        assert!(DateTime::<Julian, Tt>::parse_ordinal_iso8601("1900-366").is_ok());
+        assert!(DateTime::<Gregorian, Tt>::parse_ordinal_iso8601("1900-366").is_err());
+
+        let dt: DateTime<Gregorian, Utc> = "2023-181T12:00:00Z".parse().unwrap();
+        assert_eq!(dt, DateTime::new(2023, 6, 30, 12, 0, 0, 0).unwrap());
+        assert_eq!(dt.to_ordinal_iso8601(), "2023-181T12:00:00");
+
+        let leap =
+            DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 500_000_000_000_000_000)
+                .unwrap();
+        assert_eq!(leap.to_ordinal_iso8601(), "2016-366T23:59:60.5");
+        let ides = DateTime::<Gregorian, Utc>::new(-44, 3, 15, 12, 0, 0, 0).unwrap();
+        assert_eq!(ides.to_ordinal_iso8601(), "-0044-075T12:00:00");
+
+        for dt in [
+            leap,
+            ides + Duration::new(0, 1),
+            DateTime::new(2000, 1, 1, 0, 0, 0, 0).unwrap(),
+            DateTime::new(12345, 2, 1, 0, 0, 0, 0).unwrap(),
+        ] {
+            let s = dt.to_ordinal_iso8601();
+            assert_eq!(DateTime::parse_ordinal_iso8601(&s).unwrap(), dt);
         }
-        if self.hour() != other.hour() {
-            return self.hour().cmp(&other.hour());
+    }
+
+    #[test]
+    fn test_from_str() {
+        crate::setup_logging();
+
+        let dt: DateTime<Gregorian, Utc> = "2000-01-01T00:00:00Z".parse().unwrap();
+        assert_eq!(
+            dt,
+            DateTime::<Gregorian, Utc>::new(2000, 1, 1, 0, 0, 0, 0).unwrap()
+        );
+
+        let dt: DateTime<Gregorian, Utc> = "2016-12-31T23:59:60.5Z".parse().unwrap();
+        assert_eq!(
+            dt,
+            DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 500_000_000_000_000_000)
+                .unwrap()
+        );
+
+        let dt: DateTime<Gregorian, Utc> = "2000-01-01T05:30:00+05:30".parse().unwrap();
+        assert_eq!(
+            dt,
+            DateTime::<Gregorian, Utc>::new(2000, 1, 1, 0, 0, 0, 0).unwrap()
+        );
+
+        let dt: DateTime<Julian, Tt> = "-4713-01-01T12:00:00".parse().unwrap();
+        assert_eq!(
+            dt,
+            DateTime::<Julian, Tt>::new(-4713, 1, 1, 12, 0, 0, 0).unwrap()
+        );
+
+        assert!("2000-01-01 junk"
+            .parse::<DateTime<Gregorian, Utc>>()
+            .is_err());
+        assert!("2001-02-29T00:00:00Z"
+            .parse::<DateTime<Gregorian, Utc>>()
+            .is_err());
+        assert!("2000-01-01T24:00:00Z"
+            .parse::<DateTime<Gregorian, Utc>>()
+            .is_err());
+
+        // Removing the offset must not overflow the range of years
+        for s in [
+            "-2147483648-01-01T00:00:00+01:00",
+            "+2147483647-12-31T23:30:00-01:00",
+        ] {
+            assert!(matches!(
+                DateTime::<Gregorian, Tt>::parse_iso8601(s),
+                Err(Error::RangeError)
+            ));
+            assert!(matches!(
+                s.parse::<DateTime<Gregorian, Tt>>(),
+                Err(Error::RangeError)
+            ));
         }
-        if self.minute() != other.minute() {
-            return self.minute().cmp(&other.minute());
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_possible_wrap)]
+    fn test_comparison_matches_fields() {
+        crate::setup_logging();
+
+        // xorshift64, so the test is deterministic
+        let mut state: u64 = 0x2545_F491_4F6C_DD1D;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        // Draw fields from narrow ranges half of the time so that the higher
+        // fields are often equal and the lower fields get compared
+        let mut random = |narrow: bool| {
+            let year = if narrow {
+                (next() % 3) as i32 - 1
+            } else {
+                next() as i32
+            };
+            let month = (next() % if narrow { 2 } else { 12 }) as u8 + 1;
+            let day = (next() % if narrow { 2 } else { 28 }) as u8 + 1;
+            let hour = (next() % if narrow { 2 } else { 24 }) as u8;
+            let minute = (next() % if narrow { 2 } else { 60 }) as u8;
+            let second = (next() % if narrow { 2 } else { 61 }) as u8;
+            let atto = next() % if narrow { 2 } else { 1_000_000_000_000_000_000 };
+            DateTime::<Gregorian, Tt>::new(year, month, day, hour, minute, second, atto).unwrap()
+        };
+
+        let by_fields = |a: &DateTime<Gregorian, Tt>, b: &DateTime<Gregorian, Tt>| -> Ordering {
+            a.year()
+                .cmp(&b.year())
+                .then(a.month().cmp(&b.month()))
+                .then(a.day().cmp(&b.day()))
+                .then(a.hour().cmp(&b.hour()))
+                .then(a.minute().cmp(&b.minute()))
+                .then(a.second().cmp(&b.second()))
+                .then(a.attosecond().cmp(&b.attosecond()))
+        };
+
+        for n in 0..1_000_000 {
+            let narrow = n % 2 == 0;
+            let a = random(narrow);
+            let b = random(narrow);
+            assert_eq!(a.cmp(&b), by_fields(&a, &b), "{a} vs {b}");
+            assert_eq!(a.cmp(&a), Ordering::Equal);
         }
-        if self.second() != other.second() {
-            return self.second().cmp(&other.second());
+
+        // Extremes of the year
+        let min = DateTime::<Gregorian, Tt>::new(i32::MIN, 1, 1, 0, 0, 0, 0).unwrap();
+        let neg = DateTime::<Gregorian, Tt>::new(-1, 12, 31, 23, 59, 60, 0).unwrap();
+        let zero = DateTime::<Gregorian, Tt>::new(0, 1, 1, 0, 0, 0, 0).unwrap();
+        let max = DateTime::<Gregorian, Tt>::new(i32::MAX, 12, 31, 23, 59, 59, 0).unwrap();
+        assert!(min < neg);
+        assert!(neg < zero);
+        assert!(zero < max);
+    }
+
+    #[test]
+    fn test_rounding() {
+        crate::setup_logging();
+
+        let minute = Duration::new(60, 0);
+        let tenth = Duration::new(0, 100_000_000_000_000_000);
+
+        let dt = DateTime::<Gregorian, Tt>::new(2000, 1, 1, 12, 34, 56, 789_000_000_000_000_000)
+            .unwrap();
+        let expected = DateTime::<Gregorian, Tt>::new(2000, 1, 1, 12, 34, 0, 0).unwrap();
+        assert_eq!(dt.floor_to(minute), expected);
+        assert_eq!(dt.ceil_to(minute), expected + minute);
+        assert_eq!(dt.round_to(minute), expected + minute);
+        let expected =
+            DateTime::<Gregorian, Tt>::new(2000, 1, 1, 12, 34, 56, 700_000_000_000_000_000)
+                .unwrap();
+        assert_eq!(dt.floor_to(tenth), expected);
+        assert_eq!(dt.round_to(tenth), expected + tenth);
+
+        // Exact values are unchanged
+        let dt = DateTime::<Gregorian, Tt>::new(2000, 1, 1, 12, 34, 0, 0).unwrap();
+        assert_eq!(dt.floor_to(minute), dt);
+        assert_eq!(dt.ceil_to(minute), dt);
+        assert_eq!(dt.round_to(minute), dt);
+
+        // Halfway rounds to the even multiple
+        let dt = DateTime::<Gregorian, Tt>::new(2000, 1, 1, 0, 0, 30, 0).unwrap();
+        assert_eq!(
+            dt.round_to(minute),
+            DateTime::<Gregorian, Tt>::new(2000, 1, 1, 0, 0, 0, 0).unwrap()
+        );
+        let dt = DateTime::<Gregorian, Tt>::new(2000, 1, 1, 0, 1, 30, 0).unwrap();
+        assert_eq!(
+            dt.round_to(minute),
+            DateTime::<Gregorian, Tt>::new(2000, 1, 1, 0, 2, 0, 0).unwrap()
+        );
+
+        // Units that don't evenly divide a day carry across midnight
+        let seven = Duration::new(7, 0);
+        let dt = DateTime::<Gregorian, Tt>::new(2000, 1, 2, 0, 0, 0, 0).unwrap();
+        let floor = dt.floor_to(seven);
+        assert!(floor < dt);
+        assert!(dt - floor < seven);
+        assert_eq!(dt.ceil_to(seven) - floor, seven);
+        assert_eq!(floor.duration_from_epoch().seconds_part() % 7, 0);
+    }
+
+    #[test]
+    fn test_rounding_across_leap_second() {
+        crate::setup_logging();
+
+        let minute = Duration::new(60, 0);
+        let before = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 0, 0).unwrap();
+        let after = DateTime::<Gregorian, Utc>::new(2017, 1, 1, 0, 0, 0, 0).unwrap();
+
+        // The last minute of 2016 was 61 seconds long
+        let dt = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 30, 300_000_000_000_000_000)
+            .unwrap();
+        assert_eq!(dt.round_to(minute), before);
+        let dt = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 30, 700_000_000_000_000_000)
+            .unwrap();
+        assert_eq!(dt.round_to(minute), after);
+        assert_eq!(dt.floor_to(minute), before);
+        assert_eq!(dt.ceil_to(minute), after);
+
+        // Halfway through the 61 second minute, 23:59 is odd so we round up
+        let dt = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 30, 500_000_000_000_000_000)
+            .unwrap();
+        assert_eq!(dt.round_to(minute), after);
+
+        // Whereas a day earlier, with a 60 second minute, this rounds up
+        let dt = DateTime::<Gregorian, Utc>::new(2016, 12, 30, 23, 59, 30, 300_000_000_000_000_000)
+            .unwrap();
+        assert_eq!(
+            dt.round_to(minute),
+            DateTime::<Gregorian, Utc>::new(2016, 12, 31, 0, 0, 0, 0).unwrap()
+        );
+
+        // Within the leap second itself, floors are never after it
+        let second = Duration::new(1, 0);
+        let tenth = Duration::new(0, 100_000_000_000_000_000);
+        let leap = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 0).unwrap();
+        assert_eq!(leap.floor_to(second), leap);
+        assert_eq!(leap.ceil_to(second), leap);
+        assert_eq!(leap.round_to(second), leap);
+        assert_eq!(leap.floor_to(minute), before);
+        assert_eq!(leap.ceil_to(minute), after);
+        assert_eq!(leap.round_to(minute), after);
+
+        let dt = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 500_000_000_000_000_000)
+            .unwrap();
+        assert_eq!(dt.floor_to(second), leap);
+        assert_eq!(dt.ceil_to(second), after);
+        assert_eq!(dt.floor_to(tenth), dt);
+        assert_eq!(dt.floor_to(minute), before);
+        assert_eq!(dt.ceil_to(minute), after);
+        assert_eq!(dt.round_to(minute), after);
+        let dt = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 950_000_000_000_000_000)
+            .unwrap();
+        assert_eq!(
+            dt.floor_to(tenth),
+            DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 900_000_000_000_000_000)
+                .unwrap()
+        );
+        assert_eq!(dt.ceil_to(tenth), after);
+        assert_eq!(dt.round_to(tenth), after);
+
+        // Units which don't divide the day are bounded either side of it
+        let seven = Duration::new(7, 0);
+        assert!(dt.floor_to(seven) < leap);
+        assert!(dt.ceil_to(seven) >= after);
+        assert_eq!(dt.ceil_to(seven) - dt.floor_to(seven), seven + second);
+    }
+
+    #[test]
+    fn test_truncation() {
+        crate::setup_logging();
+
+        let dt = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 500_000_000_000_000_000)
+            .unwrap();
+        assert_eq!(
+            dt.truncated_to_second(),
+            DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 0).unwrap()
+        );
+        assert_eq!(
+            dt.truncated_to_minute(),
+            DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 0, 0).unwrap()
+        );
+        assert_eq!(
+            dt.truncated_to_hour(),
+            DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 0, 0, 0).unwrap()
+        );
+
+        let day = dt.truncated_to_day();
+        assert_eq!(day.date(), (2016, 12, 31));
+        assert_eq!(day.time(), (0, 0, 0, 0));
+
+        let dt = DateTime::<Julian, Tt>::new_bc(4713, 1, 1, 12, 34, 56, 789_000_000_000_000_000)
+            .unwrap();
+        let day = dt.truncated_to_day();
+        assert_eq!(day.date(), dt.date());
+        assert_eq!(day.time(), (0, 0, 0, 0));
+    }
+
+    #[test]
+    fn test_has_leap_second() {
+        crate::setup_logging();
+
+        let dt = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 0, 0, 0, 0).unwrap();
+        assert!(dt.has_leap_second());
+        assert_eq!(dt.leap_second_sign(), 1);
+        let dt = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 0).unwrap();
+        assert!(dt.has_leap_second());
+        let dt = DateTime::<Julian, Utc>::new(2016, 12, 18, 12, 0, 0, 0).unwrap();
+        assert!(dt.has_leap_second());
+
+        let dt = DateTime::<Gregorian, Utc>::new(2016, 12, 30, 12, 0, 0, 0).unwrap();
+        assert!(!dt.has_leap_second());
+        assert_eq!(dt.leap_second_sign(), 0);
+        let dt = DateTime::<Gregorian, Utc>::new(2017, 1, 1, 0, 0, 0, 0).unwrap();
+        assert!(!dt.has_leap_second());
+
+        // Continuous standards never have leap seconds
+        let dt = DateTime::<Gregorian, Tai>::new(2016, 12, 31, 0, 0, 0, 0).unwrap();
+        assert!(!dt.has_leap_second());
+        assert_eq!(dt.leap_second_sign(), 0);
+
+        // Nor do smeared ones, although their days around a leap second are
+        // longer than 86400 SI seconds
+        for day in [30, 31] {
+            let dt = DateTime::<Gregorian, UtcSmeared>::new(2016, 12, day, 0, 0, 0, 0).unwrap();
+            assert!(!dt.has_leap_second());
         }
-        self.attosecond().cmp(&other.attosecond())
+        let dt = DateTime::<Gregorian, UtcSmeared>::new(2017, 1, 1, 0, 0, 0, 0).unwrap();
+        assert!(!dt.has_leap_second());
+        assert_eq!(dt.leap_second_sign(), 0);
     }
-}
 
-impl<C: Calendar, S: Standard> PartialOrd<Self> for DateTime<C, S> {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+    #[test]
+    fn test_start_and_end_of_periods() {
+        crate::setup_logging();
+
+        let last: u64 = 999_999_999_999_999_999;
+
+        // A normal day
+        let dt = DateTime::<Gregorian, Utc>::new(2023, 2, 14, 13, 45, 6, 7).unwrap();
+        assert_eq!(dt.start_of_day().time(), (0, 0, 0, 0));
+        assert_eq!(dt.start_of_day().date(), (2023, 2, 14));
+        assert_eq!(dt.end_of_day().time(), (23, 59, 59, last));
+        assert_eq!(dt.end_of_day().date(), (2023, 2, 14));
+        assert_eq!(dt.start_of_month().date(), (2023, 2, 1));
+        assert_eq!(dt.end_of_month().date(), (2023, 2, 28));
+        assert_eq!(dt.end_of_month().time(), (23, 59, 59, last));
+        assert_eq!(dt.start_of_year().date(), (2023, 1, 1));
+        assert_eq!(dt.start_of_year().time(), (0, 0, 0, 0));
+
+        // A day ending in a leap second
+        let dt = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 8, 0, 0, 0).unwrap();
+        assert_eq!(dt.end_of_day().time(), (23, 59, 60, last));
+        assert_eq!(dt.end_of_month().time(), (23, 59, 60, last));
+        assert_eq!(dt.end_of_year().time(), (23, 59, 60, last));
+        let dt = DateTime::<Gregorian, Utc>::new(2015, 6, 3, 0, 0, 0, 0).unwrap();
+        assert_eq!(dt.end_of_day().time(), (23, 59, 59, last));
+        assert_eq!(dt.end_of_month().date(), (2015, 6, 30));
+        assert_eq!(dt.end_of_month().time(), (23, 59, 60, last));
+        assert_eq!(dt.end_of_year().time(), (23, 59, 59, last));
+
+        // The same day is not special in a continuous standard
+        let dt = DateTime::<Gregorian, Tt>::new(2016, 12, 31, 8, 0, 0, 0).unwrap();
+        assert_eq!(dt.end_of_day().time(), (23, 59, 59, last));
+
+        // Nor when the leap second is smeared
+        for day in [(2016, 12, 31), (2017, 1, 1)] {
+            let dt =
+                DateTime::<Gregorian, UtcSmeared>::new(day.0, day.1, day.2, 8, 0, 0, 0).unwrap();
+            assert_eq!(dt.end_of_day().time(), (23, 59, 59, last));
+            assert_eq!(dt.end_of_day().date(), day);
+        }
+
+        // Leap years in the Julian calendar
+        let dt = DateTime::<Julian, Tt>::new(1900, 2, 3, 0, 0, 0, 0).unwrap();
+        assert_eq!(dt.end_of_month().date(), (1900, 2, 29));
+        assert_eq!(dt.end_of_year().date(), (1900, 12, 31));
     }
-}
 
-impl<C: Calendar, S: Standard> Hash for DateTime<C, S> {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        self.packed.hash(state);
-        self.attos.hash(state);
+    #[test]
+    fn test_month_enum() {
+        crate::setup_logging();
+
+        let dt = DateTime::<Gregorian, Utc>::new(2026, 2, 1, 12, 0, 0, 0).unwrap();
+        assert_eq!(dt.month_enum(), Month::February);
+        assert_eq!(dt.month_enum().days_in(dt.year(), true), dt.days_in_month());
+
+        let dt = DateTime::<Julian, Tt>::new(1900, 12, 1, 0, 0, 0, 0).unwrap();
+        assert_eq!(dt.month_enum(), Month::December);
+        let dt = dt.with_month(Month::February.into()).unwrap();
+        assert_eq!(dt.days_in_month(), 29);
     }
-}
 
-unsafe impl<C: Calendar, S: Standard> Send for DateTime<C, S> {}
+    #[test]
+    fn test_try_from_instant_extremes() {
+        crate::setup_logging();
 
-impl<S: Standard> TryFrom<DateTime<Gregorian, S>> for DateTime<Julian, S> {
-    type Error = Error;
-    fn try_from(input: DateTime<Gregorian, S>) -> Result<Self, Self::Error> {
-        let dn = input.day_number() + 2;
-        let mut r = Self::from_day_number(dn)?;
-        r.set_time(input.time())?;
-        Ok(r)
+        let min = DateTime::<Gregorian, Tt>::new(i32::MIN, 1, 1, 0, 0, 0, 0).unwrap();
+        let max =
+            DateTime::<Gregorian, Tt>::new(i32::MAX, 12, 31, 23, 59, 59, 999_999_999_999_999_999)
+                .unwrap();
+        let tiny = Duration::new(0, 1);
+
+        // The extremes themselves round trip
+        assert_eq!(DateTime::try_from_instant(Instant::from(min)).unwrap(), min);
+        assert_eq!(DateTime::try_from_instant(Instant::from(max)).unwrap(), max);
+
+        // Just beyond them
+        let before = Instant::from(min) - tiny;
+        let after = Instant::from(max) + tiny;
+        assert!(matches!(
+            DateTime::<Gregorian, Tt>::try_from_instant(before),
+            Err(Error::RangeError)
+        ));
+        assert!(matches!(
+            DateTime::<Gregorian, Tt>::try_from_instant(after),
+            Err(Error::RangeError)
+        ));
+        assert_eq!(DateTime::saturating_from_instant(before), min);
+        assert_eq!(DateTime::saturating_from_instant(after), max);
+
+        // Years far beyond an i32, in every standard and calendar
+        for secs in [
+            100_000_000_000_000_000,
+            -100_000_000_000_000_000,
+            i64::MAX,
+            i64::MIN,
+        ] {
+            let i = Instant(Duration::new(secs, 0));
+            assert!(DateTime::<Gregorian, Utc>::try_from_instant(i).is_err());
+            assert!(DateTime::<Julian, Tai>::try_from_instant(i).is_err());
+            let saturated = DateTime::<Julian, Utc>::saturating_from_instant(i);
+            assert_eq!(saturated.year(), if secs < 0 { i32::MIN } else { i32::MAX });
+        }
+
+        // Within range it agrees with From<Instant>
+        let i =
+            Instant::from(DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 59, 5).unwrap());
+        assert_eq!(
+            DateTime::<Gregorian, Utc>::try_from_instant(i).unwrap(),
+            DateTime::<Gregorian, Utc>::from(i)
+        );
     }
-}
 
-impl<S: Standard> TryFrom<DateTime<Julian, S>> for DateTime<Gregorian, S> {
-    type Error = Error;
-    fn try_from(input: DateTime<Julian, S>) -> Result<Self, Self::Error> {
-        let dn = input.day_number() - 2;
-        let mut r = Self::from_day_number(dn)?;
-        r.set_time(input.time())?;
-        Ok(r)
+    #[test]
+    fn test_canonicalize() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        crate::setup_logging();
+
+        let hash = |dt: &DateTime<Gregorian, Tt>| {
+            let mut hasher = DefaultHasher::new();
+            dt.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        let clean = DateTime::<Gregorian, Tt>::new(2024, 2, 29, 23, 59, 59, 7).unwrap();
+        let mut dirty = clean;
+        dirty.packed |= super::RESERVED_BITS;
+        assert_ne!(dirty.packed, clean.packed);
+        assert_eq!(dirty, clean);
+        assert_eq!(hash(&dirty), hash(&clean));
+        assert_eq!(dirty.cmp(&clean), Ordering::Equal);
+
+        dirty.canonicalize();
+        assert_eq!(dirty.packed, clean.packed);
+        assert_eq!(dirty.to_packed(), clean.to_packed());
+
+        // Out of range fields are carried
+        let mut dirty = clean;
+        dirty.attos = 1_500_000_000_000_000_000;
+        dirty.canonicalize();
+        assert_eq!(
+            dirty,
+            DateTime::new(2024, 3, 1, 0, 0, 0, 500_000_000_000_000_000).unwrap()
+        );
+
+        // Leap seconds are left alone
+        let mut leap = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 0).unwrap();
+        leap.canonicalize();
+        assert_eq!(leap.second(), 60);
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::DateTime;
-    use crate::calendar::{Gregorian, Julian};
-    use crate::duration::Duration;
-    use crate::standard::Tt;
+    #[test]
+    fn test_start_and_end_of_week() {
+        crate::setup_logging();
+
+        let last = 999_999_999_999_999_999;
+
+        // Wednesday March 1st, 2023
+        let dt = DateTime::<Gregorian, Tt>::new(2023, 3, 1, 15, 30, 0, 7).unwrap();
+        assert_eq!(
+            dt.start_of_week(Weekday::Monday),
+            DateTime::new(2023, 2, 27, 0, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            dt.end_of_week(Weekday::Monday),
+            DateTime::new(2023, 3, 5, 23, 59, 59, last).unwrap()
+        );
+        assert_eq!(
+            dt.start_of_week(Weekday::Sunday),
+            DateTime::new(2023, 2, 26, 0, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            dt.end_of_week(Weekday::Sunday),
+            DateTime::new(2023, 3, 4, 23, 59, 59, last).unwrap()
+        );
+
+        // On the first and last days of the week
+        let monday = DateTime::<Gregorian, Tt>::new(2023, 2, 27, 12, 0, 0, 0).unwrap();
+        assert_eq!(monday.start_of_week(Weekday::Monday), monday.start_of_day());
+        assert_eq!(monday.end_of_week(Weekday::Tuesday), monday.end_of_day());
+        for first_day in Weekday::ALL {
+            assert_eq!(dt.start_of_week(first_day).weekday_enum(), first_day);
+            assert_eq!(dt.end_of_week(first_day).weekday_enum(), first_day.pred());
+            assert!(dt.start_of_week(first_day) <= dt && dt <= dt.end_of_week(first_day));
+        }
+
+        // A week ending in a leap second
+        let utc = DateTime::<Gregorian, Utc>::new(2016, 12, 28, 0, 0, 0, 0).unwrap();
+        assert_eq!(
+            utc.end_of_week(Weekday::Sunday),
+            DateTime::new(2016, 12, 31, 23, 59, 60, last).unwrap()
+        );
+        assert_eq!(
+            utc.end_of_week(Weekday::Sunday) - utc.start_of_week(Weekday::Sunday),
+            Duration::new(7 * 86400, 999_999_999_999_999_999)
+        );
+    }
 
     #[test]
-    fn test_range_errors() {
+    fn test_relative_days() {
         crate::setup_logging();
 
-        assert!(DateTime::<Gregorian, Tt>::new(2000, 0, 31, 0, 0, 0, 0).is_err());
-        assert!(DateTime::<Gregorian, Tt>::new(2000, 13, 31, 0, 0, 0, 0).is_err());
-        assert!(DateTime::<Gregorian, Tt>::new(2000, 6, 0, 0, 0, 0, 0).is_err());
-        assert!(DateTime::<Gregorian, Tt>::new(2000, 6, 31, 0, 0, 0, 0).is_err());
-        assert!(DateTime::<Gregorian, Tt>::new(2000, 7, 32, 0, 0, 0, 0).is_err());
-        assert!(DateTime::<Gregorian, Tt>::new(2003, 2, 29, 0, 0, 0, 0).is_err());
-        assert!(DateTime::<Gregorian, Tt>::new(2004, 2, 29, 24, 0, 0, 0).is_err());
-        assert!(DateTime::<Gregorian, Tt>::new(2004, 2, 29, 0, 60, 0, 0).is_err());
-        assert!(DateTime::<Gregorian, Tt>::new(2004, 2, 29, 0, 0, 61, 0).is_err());
-        assert!(
-            DateTime::<Gregorian, Tt>::new(2004, 2, 29, 0, 0, 0, 1_000_000_000_000_000_000)
-                .is_err()
+        // 2024-01-01 was a Monday
+        let monday = DateTime::<Gregorian, Tt>::new(2024, 1, 1, 9, 30, 0, 7).unwrap();
+        assert_eq!(monday.weekday_enum(), Weekday::Monday);
+        let next = monday.next_weekday(Weekday::Monday).unwrap();
+        assert_eq!(next, DateTime::new(2024, 1, 8, 9, 30, 0, 7).unwrap());
+        let prev = monday.previous_weekday(Weekday::Monday).unwrap();
+        assert_eq!(prev, DateTime::new(2023, 12, 25, 9, 30, 0, 7).unwrap());
+        let sunday = monday.next_weekday(Weekday::Sunday).unwrap();
+        assert_eq!(sunday, DateTime::new(2024, 1, 7, 9, 30, 0, 7).unwrap());
+        let tuesday = monday.previous_weekday(Weekday::Tuesday).unwrap();
+        assert_eq!(tuesday, DateTime::new(2023, 12, 26, 9, 30, 0, 7).unwrap());
+        assert_eq!(monday.tomorrow().unwrap().yesterday().unwrap(), monday);
+
+        // Across a day with a leap second, the time of day is preserved even
+        // though the day was 86401 seconds long
+        let noon = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 12, 0, 0, 0).unwrap();
+        let tomorrow = noon.tomorrow().unwrap();
+        assert_eq!(tomorrow, DateTime::new(2017, 1, 1, 12, 0, 0, 0).unwrap());
+        assert_eq!(
+            Instant::from(tomorrow) - Instant::from(noon),
+            Duration::new(86401, 0)
         );
+        assert_eq!(tomorrow.yesterday().unwrap(), noon);
 
-        let _ = DateTime::<Gregorian, Tt>::new_abnormal(0, 1, 31, 0, 0, 0, 0);
-        let _ = DateTime::<Gregorian, Tt>::new_abnormal(2000, 0, 31, 0, 0, 0, 0);
-        let _ = DateTime::<Gregorian, Tt>::new_abnormal(2000, 13, 31, 0, 0, 0, 0);
-        let _ = DateTime::<Gregorian, Tt>::new_abnormal(2000, 6, 0, 0, 0, 0, 0);
-        let _ = DateTime::<Gregorian, Tt>::new_abnormal(2000, 6, 31, 0, 0, 0, 0);
-        let _ = DateTime::<Gregorian, Tt>::new_abnormal(2000, 7, 32, 0, 0, 0, 0);
-        let _ = DateTime::<Gregorian, Tt>::new_abnormal(2003, 2, 29, 0, 0, 0, 0);
-        let _ = DateTime::<Gregorian, Tt>::new_abnormal(2004, 2, 29, 24, 0, 0, 0);
-        let _ = DateTime::<Gregorian, Tt>::new_abnormal(2004, 2, 29, 0, 60, 0, 0);
-        let _ = DateTime::<Gregorian, Tt>::new_abnormal(2004, 2, 29, 0, 0, 61, 0);
-        let _ = DateTime::<Gregorian, Tt>::new_abnormal(
-            2004,
-            2,
-            29,
-            0,
-            0,
-            0,
-            1_000_000_000_000_000_000,
+        // A leap second only carries over to days that have one
+        let leap = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 5).unwrap();
+        assert_eq!(
+            leap.tomorrow().unwrap(),
+            DateTime::new(2017, 1, 2, 0, 0, 0, 5).unwrap()
+        );
+        let earlier = DateTime::<Gregorian, Utc>::new(2015, 6, 30, 0, 0, 0, 0).unwrap();
+        assert_eq!(
+            leap.plus_days(leap.days_between(&earlier)).unwrap(),
+            DateTime::new(2015, 6, 30, 23, 59, 60, 5).unwrap()
         );
+
+        let last = DateTime::<Gregorian, Tt>::new(i32::MAX, 12, 31, 0, 0, 0, 0).unwrap();
+        assert!(matches!(last.tomorrow(), Err(Error::RangeError)));
     }
 
     #[test]
-    fn test_normalize() {
+    fn test_period_until() {
+        let period = |years, months, days, hours, minutes, seconds| Period {
+            negative: false,
+            years,
+            months,
+            days,
+            hours,
+            minutes,
+            seconds,
+            attoseconds: 0,
+        };
+
         crate::setup_logging();
 
-        // This is right out of leap second file for 1 Jan 1972
-        let dt = DateTime::<Gregorian, Tt>::new_abnormal(1900, 1, 1, 0, 0, 2272060800, 0);
-        assert_eq!(dt.year(), 1972);
-        assert_eq!(dt.month(), 1);
-        assert_eq!(dt.day(), 1);
-        assert_eq!(dt.hour(), 0);
-        assert_eq!(dt.minute(), 0);
-        assert_eq!(dt.second(), 0);
-        assert_eq!(dt.attosecond(), 0);
+        let d = |y, m, d| DateTime::<Gregorian, Tt>::new(y, m, d, 0, 0, 0, 0).unwrap();
 
-        // 3rd leap second
-        // NOTE FIXME ELSEWHERE: t1900 must not include leap seconds, or else
-        // this would be off by 2 as it does not account for the 2 leap seconds
-        // added prior to it.
-        let dt = DateTime::<Gregorian, Tt>::new_abnormal(1900, 1, 1, 0, 0, 2303683200, 0);
-        assert_eq!(dt.year(), 1973);
-        assert_eq!(dt.month(), 1);
-        assert_eq!(dt.day(), 1);
-        assert_eq!(dt.hour(), 0);
-        assert_eq!(dt.minute(), 0);
-        assert_eq!(dt.second(), 0);
-        assert_eq!(dt.attosecond(), 0);
+        // Month boundaries, where the start day may not exist
+        assert_eq!(
+            d(2023, 1, 31).period_until(&d(2023, 2, 28)),
+            period(0, 0, 28, 0, 0, 0)
+        );
+        assert_eq!(
+            d(2023, 1, 31).period_until(&d(2023, 3, 1)),
+            period(0, 1, 1, 0, 0, 0)
+        );
+        assert_eq!(
+            d(2023, 1, 30).period_until(&d(2023, 3, 1)),
+            period(0, 1, 1, 0, 0, 0)
+        );
+        assert_eq!(
+            d(2023, 3, 31).period_until(&d(2023, 4, 30)),
+            period(0, 0, 30, 0, 0, 0)
+        );
+        assert_eq!(
+            d(2023, 1, 15).period_until(&d(2024, 3, 18)),
+            period(1, 2, 3, 0, 0, 0)
+        );
 
-        // Test hour roll over that crosses a month during the end of
-        // February during a leap year
-        let dt = DateTime::<Gregorian, Tt>::new_abnormal(1972, 2, 29, 25, 0, 0, 0);
-        assert_eq!(dt.month(), 3); // mar
-        assert_eq!(dt.day(), 1); // 1st
-        assert_eq!(dt.hour(), 1);
+        // Leap days
+        assert_eq!(
+            d(2024, 1, 31).period_until(&d(2024, 3, 1)),
+            period(0, 1, 1, 0, 0, 0)
+        );
+        assert_eq!(
+            d(2020, 2, 29).period_until(&d(2021, 2, 28)),
+            period(0, 11, 30, 0, 0, 0)
+        );
+        assert_eq!(
+            d(2020, 2, 29).period_until(&d(2021, 3, 1)),
+            period(1, 0, 1, 0, 0, 0)
+        );
+        assert_eq!(
+            d(2020, 2, 29).period_until(&d(2024, 2, 29)),
+            period(4, 0, 0, 0, 0, 0)
+        );
 
-        // Test some negative values
-        let dt = DateTime::<Gregorian, Tt>::new_abnormal(
-            2000,
-            1 - 11,
-            1 + (365 - 31),
-            -12,
-            60 * 12,
-            0,
-            0,
+        // Borrowing a day for the time of day, across a year boundary
+        let start = DateTime::<Gregorian, Tt>::new(2022, 12, 31, 18, 30, 0, 0).unwrap();
+        let end = DateTime::<Gregorian, Tt>::new(2023, 1, 1, 6, 15, 0, 5).unwrap();
+        let mut expected = period(0, 0, 0, 11, 45, 0);
+        expected.attoseconds = 5;
+        assert_eq!(start.period_until(&end), expected);
+        expected.negative = true;
+        assert_eq!(end.period_until(&start), expected);
+        assert!(start.period_until(&start).is_zero());
+
+        // A borrowed day with a leap second is a second longer
+        let start = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 12, 0, 0, 0).unwrap();
+        let end = DateTime::<Gregorian, Utc>::new(2017, 1, 1, 11, 0, 0, 0).unwrap();
+        assert_eq!(start.period_until(&end), period(0, 0, 0, 23, 0, 1));
+        let leap = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 0).unwrap();
+        assert_eq!(leap.period_until(&end), period(0, 0, 0, 11, 0, 1));
+        assert_eq!(
+            start.start_of_day().period_until(&leap),
+            period(0, 0, 0, 23, 59, 60)
         );
-        // We subtract 11 months, but add back the (365-11) days
-        // We subtract 12 hours, but add back the (60*12) minutes
-        assert_eq!(dt.year(), 2000);
-        assert_eq!(dt.month(), 1);
-        assert_eq!(dt.day(), 1);
-        assert_eq!(dt.hour(), 0);
-        assert_eq!(dt.minute(), 0);
-        assert_eq!(dt.second(), 0);
-        assert_eq!(dt.attosecond(), 0);
+    }
+
+    #[test]
+    fn test_calendar_units_between() {
+        crate::setup_logging();
+
+        let d = |y, m, d| DateTime::<Gregorian, Tt>::new(y, m, d, 0, 0, 0, 0).unwrap();
+
+        // Partial months
+        assert_eq!(d(2023, 1, 31).months_between(&d(2023, 2, 28)), 0);
+        assert_eq!(d(2023, 1, 31).months_between(&d(2023, 3, 1)), 1);
+        assert_eq!(d(2023, 1, 15).months_between(&d(2023, 2, 15)), 1);
+        assert_eq!(d(2023, 1, 15).months_between(&d(2023, 1, 31)), 0);
+        assert_eq!(d(2023, 2, 15).months_between(&d(2023, 1, 15)), -1);
+        assert_eq!(d(2023, 2, 15).months_between(&d(2023, 1, 16)), 0);
+        assert_eq!(d(2023, 3, 1).months_between(&d(2023, 1, 31)), -1);
+
+        // The time of day counts too
+        let noon = DateTime::<Gregorian, Tt>::new(2023, 2, 15, 12, 0, 0, 0).unwrap();
+        assert_eq!(d(2023, 1, 15).months_between(&noon), 1);
+        assert_eq!(noon.months_between(&d(2023, 3, 15)), 0);
+
+        // Anniversaries, including a leap day birthday
+        let born = d(2000, 2, 29);
+        assert_eq!(born.years_between(&d(2001, 2, 28)), 0);
+        assert_eq!(born.years_between(&d(2001, 3, 1)), 1);
+        assert_eq!(born.years_between(&d(2004, 2, 29)), 4);
+        assert_eq!(born.years_between(&d(2023, 12, 31)), 23);
+        assert_eq!(d(1990, 6, 15).years_between(&d(2024, 6, 14)), 33);
+        assert_eq!(d(1990, 6, 15).years_between(&d(2024, 6, 15)), 34);
+        assert_eq!(d(2024, 6, 15).years_between(&d(1990, 6, 15)), -34);
+        assert_eq!(d(-1, 6, 15).years_between(&d(1, 6, 15)), 2);
+
+        // Days ignore the time of day
+        assert_eq!(d(2000, 2, 28).days_between(&d(2000, 3, 1)), 2);
+        assert_eq!(d(2001, 3, 1).days_between(&d(2000, 3, 1)), -365);
+        assert_eq!(d(2023, 2, 15).days_between(&noon), 0);
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_possible_wrap)]
+    fn test_packed_round_trip() {
+        crate::setup_logging();
+
+        // A simple xorshift generator, so the test is repeatable
+        let mut state: u64 = 0x9E3779B97F4A7C15;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+
+        for _ in 0..10000 {
+            let dt = DateTime::<Gregorian, Utc>::new_abnormal(
+                next() as i32,
+                1,
+                (next() % 366) as i64 + 1,
+                0,
+                0,
+                (next() % 86400) as i64,
+                (next() % 1_000_000_000_000_000_000) as i64,
+            );
+            let (packed, attos) = dt.to_packed();
+            assert_eq!(DateTime::from_packed(packed, attos).unwrap(), dt);
+
+            // Corrupting any one field, or any reserved bit, is caught
+            let corruptions = [
+                (packed | 0xF, attos),                               // month 16
+                (packed | (31 << 15), attos),                        // hour 31
+                (packed | (63 << 20), attos),                        // minute 63
+                (packed | (63 << 26), attos),                        // second 63
+                (packed | (1 << (4 + next() % 6)), attos),           // reserved
+                (packed, 1_000_000_000_000_000_000 + next() % 1000), // attos
+            ];
+            for (packed, attos) in corruptions {
+                assert!(matches!(
+                    DateTime::<Gregorian, Utc>::from_packed(packed, attos),
+                    Err(Error::RangeError)
+                ));
+            }
+        }
+
+        // A day that does not exist in that month
+        let (packed, attos) = DateTime::<Gregorian, Tt>::new(2023, 2, 28, 0, 0, 0, 0)
+            .unwrap()
+            .to_packed();
+        assert!(DateTime::<Gregorian, Tt>::from_packed(packed + (1 << 10), attos).is_err());
+        assert!(DateTime::<Julian, Tt>::from_packed(packed, attos).is_ok());
+    }
+
+    #[test]
+    fn test_checked_new() {
+        type Dt = DateTime<Gregorian, Utc>;
+
+        crate::setup_logging();
+
+        // Leap seconds where they happened
+        for (y, m, d) in [(1972, 6, 30), (1998, 12, 31), (2016, 12, 31)] {
+            let dt = Dt::checked_new(y, m, d, 23, 59, 60, 5).unwrap();
+            assert_eq!(dt, Dt::new(y, m, d, 23, 59, 60, 5).unwrap());
+        }
+
+        // But nowhere else, although new() is lenient
+        for (y, m, d, h, min) in [
+            (2016, 12, 30, 23, 59),
+            (2017, 12, 31, 23, 59),
+            (2016, 12, 31, 23, 58),
+            (2016, 12, 31, 12, 59),
+        ] {
+            assert!(matches!(
+                Dt::checked_new(y, m, d, h, min, 60, 0),
+                Err(Error::RangeError)
+            ));
+            assert!(Dt::new(y, m, d, h, min, 60, 0).is_ok());
+        }
 
-        // Test further negative values
-        let dt =
-            DateTime::<Gregorian, Tt>::new_abnormal(2000, 1 - 60, 1 + (365 * 4 + 366), 0, 0, 0, 0);
-        // We subtract 60 months, but add back the (365 + 365 + 365 + 366 + 365) days
-        // We subtract 12 hours, but add back the (60*12) minutes
-        assert_eq!(dt.year(), 2000);
-        assert_eq!(dt.month(), 1);
-        assert_eq!(dt.day(), 1);
+        // Ordinary dates and times, and the usual range checks
+        assert_eq!(
+            Dt::checked_new(2016, 12, 31, 23, 59, 59, 999_999_999_999_999_999).unwrap(),
+            Dt::new(2016, 12, 31, 23, 59, 59, 999_999_999_999_999_999).unwrap()
+        );
+        assert!(Dt::checked_new(2024, 2, 29, 0, 0, 0, 0).is_ok());
+        assert!(Dt::checked_new(2023, 2, 29, 0, 0, 0, 0).is_err());
+        assert!(Dt::checked_new(2023, 1, 1, 24, 0, 0, 0).is_err());
+        assert!(Dt::checked_new(2023, 1, 1, 0, 0, 61, 0).is_err());
+        assert!(Dt::checked_new(2023, 1, 1, 0, 0, 0, 1_000_000_000_000_000_000).is_err());
+    }
 
-        // Test year rollover
-        let dt = DateTime::<Gregorian, Tt>::new_abnormal(1970, 12, 31, 25, 0, 0, 0);
-        assert_eq!(dt.year(), 1971);
-        assert_eq!(dt.month(), 1);
-        assert_eq!(dt.day(), 1);
-        assert_eq!(dt.hour(), 1);
+    #[test]
+    fn test_validate() {
+        crate::setup_logging();
+
+        let good = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 5).unwrap();
+        assert!(good.is_valid());
+        assert!(good.validate().is_ok());
+        assert!(DateTime::<Julian, Tt>::default().is_valid());
+
+        let corrupt = |packed: u64, attos: u64| DateTime::<Gregorian, Utc> {
+            packed,
+            attos,
+            _cal: PhantomData,
+            _std: PhantomData,
+        };
+        let (packed, attos) = good.to_packed();
+        let cases = [
+            (corrupt(packed | (1 << 4), attos), "reserved"),
+            (corrupt(packed | 0xF, attos), "month"),
+            (corrupt(packed - 11 + 1, attos), "day"), // February 31st
+            (corrupt(packed | (31 << 15), attos), "hour"),
+            (corrupt(packed & !(0x3F << 20), attos), "second"), // 23:00:60
+            (corrupt(packed | (63 << 26), attos), "second"),
+            (corrupt(packed, 1_000_000_000_000_000_000), "attosecond"),
+        ];
+        for (dt, field) in cases {
+            assert!(!dt.is_valid());
+            assert!(matches!(dt.validate(), Err(Error::FieldRangeError(f)) if f == field));
+        }
+
+        // Second 60 only where there was a leap second
+        let unchecked =
+            unsafe { DateTime::<Gregorian, Utc>::new_unchecked(2016, 12, 30, 23, 59, 60, 0) };
+        assert!(matches!(
+            unchecked.validate(),
+            Err(Error::FieldRangeError("second"))
+        ));
+        let unchecked =
+            unsafe { DateTime::<Gregorian, Tai>::new_unchecked(2016, 12, 31, 23, 59, 60, 0) };
+        assert!(!unchecked.is_valid());
+        let unchecked = unsafe { DateTime::<Julian, Tt>::new_unchecked(2023, 2, 29, 0, 0, 0, 0) };
+        assert!(matches!(
+            unchecked.validate(),
+            Err(Error::FieldRangeError("day"))
+        ));
     }
 
     #[test]
-    fn test_day_number() {
+    fn test_try_new_abnormal() {
         crate::setup_logging();
 
-        let dt = DateTime::<Gregorian, Tt>::new(1, 1, 1, 0, 0, 0, 0).unwrap(); // year 1
-        assert_eq!(dt.day_number(), 0);
+        // Agrees with new_abnormal() when nothing overflows
+        let cases = [
+            (2000, 14, 0, 25, -1, 61, -1),
+            (1999, -3, 400, 0, 0, -86401, 1_500_000_000_000_000_000),
+            (-44, 3, 15, 12, 0, 0, 0),
+        ];
+        for (y, mo, d, h, mi, s, a) in cases {
+            assert_eq!(
+                DateTime::<Gregorian, Tt>::try_new_abnormal(y, mo, d, h, mi, s, a).unwrap(),
+                DateTime::<Gregorian, Tt>::new_abnormal(y, mo, d, h, mi, s, a)
+            );
+        }
 
-        let dt2 = DateTime::<Gregorian, Tt>::from_day_number(dt.day_number()).unwrap();
-        assert_eq!(dt, dt2);
+        // Extreme day counts
+        assert!(matches!(
+            DateTime::<Gregorian, Tt>::try_new_abnormal(2000, 1, i64::MAX, 0, 0, 0, 0),
+            Err(Error::RangeError)
+        ));
+        assert!(matches!(
+            DateTime::<Julian, Tt>::try_new_abnormal(2000, 1, i64::MIN, 0, 0, 0, 0),
+            Err(Error::RangeError)
+        ));
+        assert!(matches!(
+            DateTime::<Gregorian, Tt>::try_new_abnormal(2000, 1, 1, i64::MAX, 0, 0, i64::MAX),
+            Err(Error::RangeError)
+        ));
+        assert!(matches!(
+            DateTime::<Gregorian, Tt>::try_new_abnormal(2000, 1, 1, 0, 0, i64::MAX, 0),
+            Err(Error::RangeError)
+        ));
+
+        // Years past the end of the range
+        assert!(matches!(
+            DateTime::<Gregorian, Tt>::try_new_abnormal(i32::MAX, 13, 1, 0, 0, 0, 0),
+            Err(Error::RangeError)
+        ));
+        assert!(matches!(
+            DateTime::<Gregorian, Tt>::try_new_abnormal(i32::MAX, 12, 32, 0, 0, 0, 0),
+            Err(Error::RangeError)
+        ));
+        assert!(matches!(
+            DateTime::<Gregorian, Tt>::try_new_abnormal(i32::MIN, 1, 0, 0, 0, 0, 0),
+            Err(Error::RangeError)
+        ));
+        assert_eq!(
+            DateTime::<Gregorian, Tt>::try_new_abnormal(i32::MAX, 12, 31, 23, 59, 59, 0)
+                .unwrap()
+                .date(),
+            (i32::MAX, 12, 31)
+        );
+    }
 
-        let dt = DateTime::<Gregorian, Tt>::new(2000, 1, 1, 0, 0, 0, 0).unwrap();
-        assert_eq!(dt.day_number(), 730119);
+    #[test]
+    fn test_default() {
+        crate::setup_logging();
 
-        let dt2 = DateTime::<Gregorian, Tt>::from_day_number(dt.day_number()).unwrap();
-        assert_eq!(dt, dt2);
+        let dt = DateTime::<Gregorian, Tt>::default();
+        assert_eq!(dt, DateTime::new(1, 1, 1, 0, 0, 0, 0).unwrap());
+        assert_eq!(dt.day_number(), 0);
+        assert_eq!(Instant::from(dt), Epoch::GregorianCalendar.as_instant());
 
-        assert_eq!(dt2.day_number(), dt.day_number())
+        let dt = DateTime::<Julian, Utc>::default();
+        assert_eq!(dt.date(), (1, 1, 1));
+        assert_eq!(dt.time(), (0, 0, 0, 0));
     }
 
     #[test]
-    fn test_day_fraction() {
+    fn test_cmp_instant() {
         crate::setup_logging();
 
-        use float_cmp::ApproxEq;
-        let g1 = DateTime::<Gregorian, Tt>::new(2000, 1, 1, 12, 0, 0, 0).unwrap();
-        assert!(g1.day_fraction().approx_eq(0.5, (0.0, 1)));
-        let g2 = DateTime::<Gregorian, Tt>::new(2000, 1, 1, 18, 0, 0, 0).unwrap();
-        assert!(g2.day_fraction().approx_eq(0.75, (0.0, 1)));
-        let g3 = DateTime::<Gregorian, Tt>::new(2000, 1, 1, 0, 0, 1, 0).unwrap();
-        assert!(g3.day_fraction().approx_eq(1. / 86400., (0.0, 1)));
+        // The middle of the leap second at the end of 2016, when TAI - UTC was
+        // still 36 seconds
+        let utc =
+            DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 500_000_000_000_000_000)
+                .unwrap();
+        let tai =
+            DateTime::<Gregorian, Tai>::new(2017, 1, 1, 0, 0, 36, 500_000_000_000_000_000).unwrap();
+        assert_eq!(utc.cmp_instant(&tai), Ordering::Equal);
+        assert!(same_instant(&utc, &tai));
+        assert!(same_instant(&tai, &utc));
+
+        // The second after the leap second is later
+        let after =
+            DateTime::<Gregorian, Utc>::new(2017, 1, 1, 0, 0, 0, 500_000_000_000_000_000).unwrap();
+        assert_eq!(after.cmp_instant(&tai), Ordering::Greater);
+        assert_eq!(tai.cmp_instant(&after), Ordering::Less);
+        assert!(!same_instant(&after, &tai));
+        let tai_after: DateTime<Gregorian, Tai> = From::from(Instant::from(after));
+        assert_eq!(tai_after.time(), (0, 0, 37, 500_000_000_000_000_000));
+
+        // Across calendars and standards
+        let tt =
+            DateTime::<Julian, Tt>::new(2016, 12, 19, 0, 1, 8, 684_000_000_000_000_000).unwrap();
+        assert!(same_instant(&utc, &tt));
+    }
 
-        let g4 =
-            DateTime::<Gregorian, Tt>::from_day_number_and_fraction(g1.day_number(), 0.75).unwrap();
-        assert_eq!(g4, g2);
+    #[test]
+    fn test_weekday() {
+        crate::setup_logging();
 
-        let g4 =
-            DateTime::<Gregorian, Tt>::from_day_number_and_fraction(g1.day_number(), 19. / 97.)
+        let dt = DateTime::<Gregorian, Utc>::new(2026, 2, 1, 12, 0, 0, 0).unwrap();
+        assert_eq!(dt.weekday(), 7);
+        assert_eq!(dt.weekday_enum(), Weekday::Sunday);
+
+        let dt = DateTime::<Gregorian, Tt>::new(1, 1, 1, 0, 0, 0, 0).unwrap();
+        assert_eq!(dt.weekday_enum(), Weekday::Monday);
+        let dt = DateTime::<Gregorian, Tt>::new(0, 12, 31, 0, 0, 0, 0).unwrap();
+        assert_eq!(dt.weekday_enum(), Weekday::Sunday);
+
+        // The Gregorian calendar was adopted the day after Thursday 4 October
+        // 1582 (Julian), which was Friday 15 October 1582 (Gregorian)
+        let dt = DateTime::<Julian, Tt>::new(1582, 10, 4, 0, 0, 0, 0).unwrap();
+        assert_eq!(dt.weekday_enum(), Weekday::Thursday);
+        let dt = DateTime::<Gregorian, Tt>::new(1582, 10, 15, 0, 0, 0, 0).unwrap();
+        assert_eq!(dt.weekday_enum(), Weekday::Friday);
+    }
+
+    #[test]
+    fn test_min_max_clamp() {
+        crate::setup_logging();
+
+        let before = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 59, 0).unwrap();
+        let leap = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 0).unwrap();
+        let late_leap =
+            DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 999_999_999_999_999_999)
                 .unwrap();
-        assert!(g4.day_fraction().approx_eq(19. / 97., (0.0, 1)));
+        let after = DateTime::<Gregorian, Utc>::new(2017, 1, 1, 0, 0, 0, 0).unwrap();
+
+        assert_eq!(leap.min(after), leap);
+        assert_eq!(after.min(leap), leap);
+        assert_eq!(leap.max(before), leap);
+        assert_eq!(before.max(leap), leap);
+        assert_eq!(late_leap.max(after), after);
+
+        assert_eq!(leap.clamp(before, after), leap);
+        assert_eq!(late_leap.clamp(before, leap), leap);
+        assert_eq!(before.clamp(leap, after), leap);
+        assert_eq!(after.clamp(before, late_leap), late_leap);
+        assert_eq!(leap.clamp(leap, leap), leap);
     }
 
     #[test]
-    fn test_extractors() {
+    fn test_with() {
         crate::setup_logging();
 
-        let g = DateTime::<Gregorian, Tt>::new(1965, 3, 7, 14, 29, 42, 500_000_000_000_000_000)
-            .unwrap();
-        assert_eq!(g.year(), 1965);
-        assert_eq!(g.month(), 3);
-        assert_eq!(g.month0(), 2);
-        assert_eq!(g.day(), 7);
-        assert_eq!(g.day0(), 6);
-        assert_eq!(g.hour(), 14);
-        assert_eq!(g.minute(), 29);
-        assert_eq!(g.second(), 42);
-        assert_eq!(g.attosecond(), 500_000_000_000_000_000);
+        let dt = DateTime::<Gregorian, Utc>::new(2004, 2, 29, 12, 34, 56, 789).unwrap();
+        assert_eq!(
+            dt.with_year(2008).unwrap(),
+            DateTime::<Gregorian, Utc>::new(2008, 2, 29, 12, 34, 56, 789).unwrap()
+        );
+        assert!(dt.with_year(2005).is_err());
+        assert!(dt.with_year(1900).is_err());
+        assert_eq!(dt.with_year_bc(1).unwrap().year(), 0);
+        assert!(dt.with_month(4).is_ok());
+        assert!(dt.with_month(13).is_err());
+        assert!(dt.with_day(30).is_err());
+        assert_eq!(
+            dt.with_day(1)
+                .unwrap()
+                .with_month(3)
+                .unwrap()
+                .with_day(31)
+                .unwrap(),
+            DateTime::<Gregorian, Utc>::new(2004, 3, 31, 12, 34, 56, 789).unwrap()
+        );
+        assert!(dt.with_hour(24).is_err());
+        assert!(dt.with_minute(60).is_err());
+        assert_eq!(dt.with_second(60).unwrap().second(), 60);
+        assert!(dt.with_second(61).is_err());
+        assert_eq!(dt.with_attosecond(0).unwrap().attosecond(), 0);
+
+        // The day is checked against the new month and year together
+        let dt = DateTime::<Gregorian, Utc>::new(2003, 1, 31, 0, 0, 0, 0).unwrap();
+        assert_eq!(dt.with_date((2004, 2, 29)).unwrap().date(), (2004, 2, 29));
+        assert!(dt.with_date((2003, 2, 29)).is_err());
+        assert!(dt.with_date((2003, 0, 1)).is_err());
+        assert!(dt.with_date((2003, 1, 0)).is_err());
+        assert_eq!(
+            dt.with_time((23, 59, 60, 5)).unwrap().time(),
+            (23, 59, 60, 5)
+        );
+
+        // Failures leave the original untouched
+        assert_eq!(dt.date(), (2003, 1, 31));
     }
 
     #[test]
-    fn test_setters() {
+    fn test_with_date_and_time_from() {
         crate::setup_logging();
 
-        let mut g = DateTime::<Gregorian, Tt>::new(1965, 3, 7, 14, 29, 42, 500_000_000_000_000_000)
-            .unwrap();
+        let leap_day = DateTime::<Gregorian, Utc>::new(2024, 2, 29, 0, 0, 0, 0).unwrap();
+        let evening = DateTime::<Gregorian, Utc>::new(2023, 1, 31, 18, 30, 15, 25).unwrap();
+        let expected = DateTime::new(2024, 2, 29, 18, 30, 15, 25).unwrap();
+        assert_eq!(evening.with_date_from(&leap_day), expected);
+        assert_eq!(leap_day.with_time_from(&evening), expected);
+        assert_eq!(
+            evening.with_time_from(&leap_day),
+            DateTime::new(2023, 1, 31, 0, 0, 0, 0).unwrap()
+        );
 
-        g.set_year(1921);
-        assert_eq!(g.year(), 1921);
+        // One field at a time, the 31st does not fit in February
+        assert!(matches!(evening.with_month(2), Err(Error::RangeError)));
 
-        g.set_month(1).unwrap();
-        assert_eq!(g.month(), 1);
+        // Same time tomorrow
+        let tomorrow = evening.tomorrow().unwrap();
+        assert_eq!(tomorrow.with_time_from(&evening), tomorrow);
+        assert_eq!(evening.with_date_from(&tomorrow), tomorrow);
+    }
 
-        g.set_day(17).unwrap();
-        assert_eq!(g.day(), 17);
+    #[test]
+    fn test_days_in_month_and_year() {
+        crate::setup_logging();
 
-        g.set_hour(3).unwrap();
-        assert_eq!(g.hour(), 3);
+        // (year, gregorian leap, julian leap)
+        for (year, gregorian, julian) in [
+            (2000, true, true),
+            (1900, false, true),
+            (2004, true, true),
+            (2001, false, false),
+            (-4, true, true),
+            (-100, false, true),
+        ] {
+            let feb = DateTime::<Gregorian, Tt>::new(year, 2, 1, 0, 0, 0, 0).unwrap();
+            assert_eq!(feb.is_leap_year(), gregorian);
+            assert_eq!(feb.days_in_month(), if gregorian { 29 } else { 28 });
+            assert_eq!(feb.days_in_year(), if gregorian { 366 } else { 365 });
+
+            let feb = DateTime::<Julian, Tt>::new(year, 2, 1, 0, 0, 0, 0).unwrap();
+            assert_eq!(feb.is_leap_year(), julian);
+            assert_eq!(feb.days_in_month(), if julian { 29 } else { 28 });
+            assert_eq!(feb.days_in_year(), if julian { 366 } else { 365 });
+        }
 
-        g.set_minute(55).unwrap();
-        assert_eq!(g.minute(), 55);
+        let dt = DateTime::<Gregorian, Tt>::new(2001, 4, 30, 0, 0, 0, 0).unwrap();
+        assert_eq!(dt.days_in_month(), 30);
+        let dt = DateTime::<Julian, Tt>::new(2001, 12, 1, 0, 0, 0, 0).unwrap();
+        assert_eq!(dt.days_in_month(), 31);
+    }
 
-        g.set_second(51).unwrap();
-        assert_eq!(g.second(), 51);
+    #[test]
+    fn test_historical() {
+        crate::setup_logging();
 
-        g.set_attosecond(123_456_789_012_345_678).unwrap();
-        assert_eq!(g.attosecond(), 123_456_789_012_345_678);
+        let dt = DateTime::<Julian, Tt>::new_historical(Era::Bc, 1, 12, 31, 0, 0, 0, 0).unwrap();
+        assert_eq!(dt.date(), (0, 12, 31));
+        assert_eq!(dt.to_historical(), (Era::Bc, 1, 12, 31));
+        let next = dt + Duration::DAY;
+        assert_eq!(next.date(), (1, 1, 1));
+        assert_eq!(next.to_historical(), (Era::Ad, 1, 1, 1));
+        assert_eq!(
+            DateTime::<Julian, Tt>::new_historical(Era::Ad, 1, 1, 1, 0, 0, 0, 0).unwrap(),
+            next
+        );
 
-        let h = DateTime::<Gregorian, Tt>::new(1921, 1, 17, 3, 55, 51, 123_456_789_012_345_678)
-            .unwrap();
+        let ides = DateTime::<Julian, Tt>::new_historical(Era::Bc, 44, 3, 15, 12, 0, 0, 0).unwrap();
+        assert_eq!(ides.year(), -43);
+        assert_eq!(ides.year_bc(), 44);
+        assert_eq!(ides.to_historical(), (Era::Bc, 44, 3, 15));
+
+        // No year zero, and the extremes of the range
+        assert!(DateTime::<Gregorian, Tt>::new_historical(Era::Ad, 0, 1, 1, 0, 0, 0, 0).is_err());
+        assert!(DateTime::<Gregorian, Tt>::new_historical(Era::Bc, 0, 1, 1, 0, 0, 0, 0).is_err());
+        let last =
+            DateTime::<Gregorian, Tt>::new_historical(Era::Ad, 2_147_483_647, 1, 1, 0, 0, 0, 0);
+        assert_eq!(last.unwrap().year(), i32::MAX);
+        assert!(DateTime::<Gregorian, Tt>::new_historical(
+            Era::Ad,
+            2_147_483_648,
+            1,
+            1,
+            0,
+            0,
+            0,
+            0
+        )
+        .is_err());
+        let first =
+            DateTime::<Gregorian, Tt>::new_historical(Era::Bc, 2_147_483_649, 1, 1, 0, 0, 0, 0)
+                .unwrap();
+        assert_eq!(first.year(), i32::MIN);
+        assert_eq!(first.to_historical(), (Era::Bc, 2_147_483_649, 1, 1));
+        assert!(DateTime::<Gregorian, Tt>::new_historical(
+            Era::Bc,
+            2_147_483_650,
+            1,
+            1,
+            0,
+            0,
+            0,
+            0
+        )
+        .is_err());
+    }
 
-        assert_eq!(g, h);
+    #[test]
+    fn test_tle_epoch() {
+        use float_cmp::ApproxEq;
 
-        let mut g = DateTime::<Gregorian, Tt>::new(1997, 3, 30, 17, 24, 06, 2340897).unwrap();
-        assert!(g.set_month(2).is_err());
-        assert_eq!(g.month(), 3);
-        assert!(g.set_day(28).is_ok());
-        assert!(g.set_month(2).is_ok());
-        assert_eq!(g.month(), 2);
-        assert_eq!(g.day(), 28);
+        crate::setup_logging();
+
+        // The ISS, from a published element set
+        let dt = DateTime::<Gregorian, Utc>::from_tle_epoch("08264.51782528").unwrap();
+        assert_eq!(dt.date(), (2008, 9, 20));
+        let (hour, minute, second, attosecond) = dt.time();
+        assert_eq!((hour, minute, second), (12, 25, 40));
+        assert!(attosecond.abs_diff(104_192_000_000_000_000) < 1_000_000_000);
+        assert_eq!(dt.ordinal(), 264);
+        assert!(dt
+            .day_of_year_fraction()
+            .approx_eq(264.517_825_28, (1e-9, 0)));
+        assert_eq!(dt.to_tle_epoch().unwrap(), "08264.51782528");
+
+        // The pivot
+        let dt = DateTime::<Gregorian, Utc>::from_tle_epoch("57001.00000000").unwrap();
+        assert_eq!(dt.date(), (1957, 1, 1));
+        let dt = DateTime::<Gregorian, Utc>::from_tle_epoch("56366.5").unwrap();
+        assert_eq!(dt.date(), (2056, 12, 31));
+        assert_eq!(dt.hour(), 12);
+        assert_eq!(dt.to_tle_epoch().unwrap(), "56366.50000000");
+        let dt = DateTime::<Gregorian, Utc>::new(2057, 1, 1, 0, 0, 0, 0).unwrap();
+        assert!(dt.to_tle_epoch().is_err());
+
+        // A leap second stays in its own day
+        let dt = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 0).unwrap();
+        assert_eq!(dt.to_tle_epoch().unwrap(), "16366.99999999");
+
+        // Out of range and malformed
+        assert!(matches!(
+            DateTime::<Gregorian, Utc>::from_tle_epoch("23366.0"),
+            Err(Error::RangeError)
+        ));
+        assert!(matches!(
+            DateTime::<Gregorian, Utc>::from_tle_epoch("23000.5"),
+            Err(Error::RangeError)
+        ));
+        for bad in ["", "2", "x3001.0", "23-01.0", "23inf", "23001.0.0"] {
+            assert!(matches!(
+                DateTime::<Gregorian, Utc>::from_tle_epoch(bad),
+                Err(Error::ParseError(_))
+            ));
+        }
+
+        // Other calendars
+        let dt = DateTime::<Julian, Tt>::from_year_and_fractional_day(1900, 60.25).unwrap();
+        assert_eq!(dt.date(), (1900, 2, 29));
+        assert_eq!(dt.hour(), 6);
+        assert!(DateTime::<Gregorian, Tt>::from_year_and_fractional_day(1900, 366.0).is_err());
+        assert!(DateTime::<Gregorian, Tt>::from_year_and_fractional_day(1900, f64::NAN).is_err());
     }
 
     #[test]
-    fn test_comparison() {
+    fn test_comparisons() {
         crate::setup_logging();
 
-        let g = DateTime::<Gregorian, Tt>::new(1965, 3, 7, 14, 29, 42, 500_000_000_000_000_000)
-            .unwrap();
-        let h = DateTime::<Gregorian, Tt>::new(1966, 1, 17, 3, 55, 51, 123_456_789_012_345_678)
-            .unwrap();
-        let i = DateTime::<Gregorian, Tt>::new(1966, 3, 7, 14, 29, 42, 500_000_000_000_000_000)
-            .unwrap();
-        let j = DateTime::<Gregorian, Tt>::new(1966, 3, 7, 14, 29, 42, 500_000_000_000_000_000)
-            .unwrap();
-        assert!(g < h);
-        assert!(h < i);
-        assert!(i == j);
+        let start = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 59, 0).unwrap();
+        let leap = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 0).unwrap();
+        let end = DateTime::<Gregorian, Utc>::new(2017, 1, 1, 0, 0, 0, 0).unwrap();
+        assert!(start.is_before(&leap) && leap.is_before(&end));
+        assert!(end.is_after(&leap) && !leap.is_after(&leap));
+        assert!(start.is_between(&start, &end));
+        assert!(leap.is_between(&start, &end));
+        assert!(!end.is_between(&start, &end));
+        assert!(!start.is_between(&leap, &end));
+        assert!(!leap.is_between(&end, &start));
     }
 
     #[test]
-    fn test_math() {
+    fn test_approx_eq() {
         crate::setup_logging();
 
-        let g = DateTime::<Gregorian, Tt>::new(1996, 3, 2, 0, 0, 0, 50).unwrap();
-        let week_less_150ns = Duration::new(86400 * 7, 150);
-        let earlier = g - week_less_150ns;
-        assert_eq!(earlier.year(), 1996);
-        assert_eq!(earlier.month(), 2);
-        assert_eq!(earlier.day(), 23);
-        assert_eq!(earlier.hour(), 23);
-        assert_eq!(earlier.minute(), 59);
-        assert_eq!(earlier.second(), 59);
-        assert_eq!(earlier.attosecond(), 1_000_000_000_000_000_000 - 100);
+        let nanosecond = Duration::new(0, 1_000_000_000);
+        let dt = DateTime::<Gregorian, Tcg>::new(2024, 2, 29, 13, 14, 15, 123_456_789_012_345_678)
+            .unwrap();
+        let back = DateTime::<Gregorian, Tcg>::from_day_number_and_fraction(
+            dt.day_number(),
+            dt.day_fraction(),
+        )
+        .unwrap();
+        assert_ne!(back, dt);
+        assert!(back.approx_eq(&dt, nanosecond));
+        assert!(dt.approx_eq(&back, nanosecond));
+        let tt = DateTime::<Gregorian, Tt>::from(Instant::from(back));
+        let tcg = DateTime::<Gregorian, Tcg>::from(Instant::from(tt));
+        assert!(tcg.approx_eq(&dt, nanosecond));
+
+        // The tolerance is measured in TT, so find the edges there
+        assert!(tt.approx_eq(&(tt + nanosecond), nanosecond));
+        assert!(!tt.approx_eq(&(tt + nanosecond + Duration::new(0, 1)), nanosecond));
+        assert!(!(tt - nanosecond - Duration::new(0, 1)).approx_eq(&tt, nanosecond));
+
+        // Across a leap second
+        let leap = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 0).unwrap();
+        let before = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 59, 0).unwrap();
+        assert!(leap.approx_eq(&before, Duration::new(1, 0)));
+        let after = DateTime::<Gregorian, Utc>::new(2017, 1, 1, 0, 0, 0, 0).unwrap();
+        assert!(!after.approx_eq(&before, Duration::new(1, 0)));
+    }
 
-        let g1 = DateTime::<Gregorian, Tt>::new(2000, 1, 1, 0, 0, 0, 0).unwrap();
-        let g2 = DateTime::<Gregorian, Tt>::new(2001, 2, 2, 1, 3, 5, 11).unwrap();
-        let diff = g2 - g1;
+    #[test]
+    fn test_min_and_max() {
+        type Dt = DateTime<Gregorian, Tt>;
+
+        crate::setup_logging();
+
+        assert_eq!(Dt::MIN.date(), (i32::MIN, 1, 1));
+        assert_eq!(Dt::MIN.time(), (0, 0, 0, 0));
+        assert_eq!(Dt::MAX.date(), (i32::MAX, 12, 31));
+        assert_eq!(Dt::MAX.time(), (23, 59, 59, 999_999_999_999_999_999));
+        assert!(Dt::MIN.is_valid() && Dt::MAX.is_valid());
+
+        // Both round trip through an Instant, but no further
+        let atto = Duration::new(0, 1);
+        for (dt, beyond) in [(Dt::MIN, -atto), (Dt::MAX, atto)] {
+            let i = Instant::from(dt);
+            assert_eq!(Dt::try_from_instant(i).unwrap(), dt);
+            assert!(matches!(
+                Dt::try_from_instant(i + beyond),
+                Err(Error::RangeError)
+            ));
+            assert_eq!(Dt::saturating_from_instant(i + beyond), dt);
+        }
+        let i = Instant::from(DateTime::<Julian, Utc>::MAX);
         assert_eq!(
-            diff.seconds_part(),
-            366 * 86400 + 31 * 86400 + 1 * 86400 + 1 * 3600 + 3 * 60 + 5
+            DateTime::<Julian, Utc>::from(i),
+            DateTime::<Julian, Utc>::MAX
         );
-        assert_eq!(diff.attos_part(), 11);
+        assert_eq!(Dt::saturating_from_instant(Instant::MIN), Dt::MIN);
+        assert_eq!(Dt::saturating_from_instant(Instant::MAX), Dt::MAX);
+
+        // Going further is an error where it is checked
+        assert!(matches!(
+            Dt::try_new_abnormal(i32::MAX, 12, 31, 23, 59, 60, 0),
+            Err(Error::RangeError)
+        ));
     }
 
     #[test]
-    fn test_print_extremes() {
+    #[should_panic(expected = "RangeError")]
+    fn test_add_beyond_max() {
         crate::setup_logging();
 
-        let min = DateTime::<Gregorian, Tt>::new(std::i32::MIN, 1, 1, 0, 0, 0, 0).unwrap();
-        info!("Min gregorian: {}", min);
-        let max = DateTime::<Gregorian, Tt>::new(
-            std::i32::MAX,
-            12,
-            31,
-            23,
-            59,
-            59,
-            999_999_999_999_999_999,
-        )
-        .unwrap();
-        info!("Max gregorian: {}", max);
+        let _ = DateTime::<Gregorian, Tt>::MAX + Duration::new(1, 0);
     }
 
     #[test]
-    fn test_bc_day_numbers() {
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "value does not fit in its packed field")]
+    fn test_pack_rejects_oversized_fields() {
         crate::setup_logging();
 
-        let mar1 = DateTime::<Gregorian, Tt>::new(0, 3, 1, 0, 0, 0, 0).unwrap();
-        let feb29 = DateTime::<Gregorian, Tt>::new(0, 2, 29, 0, 0, 0, 0).unwrap();
-        let feb28 = DateTime::<Gregorian, Tt>::new(0, 2, 28, 0, 0, 0, 0).unwrap();
-        assert_eq!(mar1.day_number(), -306);
-        assert_eq!(feb29.day_number(), -307);
-        assert_eq!(feb28.day_number(), -308);
+        // Day 40 would spill into the hour
+        let _ = unsafe { DateTime::<Gregorian, Tt>::new_unchecked(2000, 1, 40, 0, 0, 0, 0) };
+    }
 
-        let mar1x = DateTime::<Gregorian, Tt>::from_day_number(-306).unwrap();
-        let feb29x = DateTime::<Gregorian, Tt>::from_day_number(-307).unwrap();
-        let feb28x = DateTime::<Gregorian, Tt>::from_day_number(-308).unwrap();
-        assert_eq!(mar1, mar1x);
-        assert_eq!(feb29, feb29x);
-        assert_eq!(feb28, feb28x);
+    #[test]
+    fn test_iso_weeks() {
+        type Dt = DateTime<Gregorian, Utc>;
+
+        crate::setup_logging();
+
+        let long_years: Vec<i32> = (1990..=2040)
+            .filter(|&year| Dt::weeks_in_year(year) == 53)
+            .collect();
+        assert_eq!(
+            long_years,
+            vec![1992, 1998, 2004, 2009, 2015, 2020, 2026, 2032, 2037]
+        );
+
+        let date = |year, week, weekday| Dt::from_iso_week(year, week, weekday).map(|dt| dt.date());
+        assert_eq!(date(2020, 53, Weekday::Thursday).unwrap(), (2020, 12, 31));
+        assert_eq!(date(2020, 53, Weekday::Sunday).unwrap(), (2021, 1, 3));
+        assert!(matches!(
+            date(2021, 53, Weekday::Monday),
+            Err(Error::RangeError)
+        ));
+        assert!(matches!(
+            date(2021, 0, Weekday::Monday),
+            Err(Error::RangeError)
+        ));
+        assert_eq!(date(2021, 1, Weekday::Monday).unwrap(), (2021, 1, 4));
+        assert_eq!(date(2009, 1, Weekday::Monday).unwrap(), (2008, 12, 29));
+        assert_eq!(date(2004, 53, Weekday::Sunday).unwrap(), (2005, 1, 2));
+        assert_eq!(date(-1, 1, Weekday::Monday).unwrap(), (-1, 1, 4));
+
+        let dt = Dt::new(2021, 1, 1, 12, 0, 0, 0).unwrap();
+        assert_eq!(dt.iso_week(), (2020, 53, Weekday::Friday));
+        let dt = Dt::new(2008, 12, 29, 0, 0, 0, 0).unwrap();
+        assert_eq!(dt.iso_week(), (2009, 1, Weekday::Monday));
+
+        // Every day round trips
+        let mut dt = Dt::new(1999, 12, 1, 0, 0, 0, 0).unwrap();
+        for _ in 0..(366 * 9) {
+            let (year, week, weekday) = dt.iso_week();
+            assert!(week >= 1 && week <= Dt::weeks_in_year(year));
+            assert_eq!(Dt::from_iso_week(year, week, weekday).unwrap(), dt);
+            dt = dt.tomorrow().unwrap();
+        }
     }
 
     #[test]
-    fn test_convert_calendar() {
+    fn test_format_era() {
         crate::setup_logging();
 
-        let j = DateTime::<Julian, Tt>::new(1582, 10, 5, 0, 0, 0, 0).unwrap();
-        let g = DateTime::<Gregorian, Tt>::new(1582, 10, 15, 0, 0, 0, 0).unwrap();
-        let j2: DateTime<Julian, Tt> = TryFrom::try_from(g).unwrap();
-        assert_eq!(j, j2);
-        let g2: DateTime<Gregorian, Tt> = TryFrom::try_from(j).unwrap();
-        assert_eq!(g, g2);
+        let dt = DateTime::<Julian, Tt>::new(0, 1, 1, 0, 0, 0, 0).unwrap();
+        assert_eq!(
+            dt.format_era(),
+            "1 BCE 01-01 00:00:00.000000000000000000 Julian TT"
+        );
 
-        let j = DateTime::<Julian, Tt>::new(1582, 10, 4, 0, 0, 0, 0).unwrap();
-        let g = DateTime::<Gregorian, Tt>::new(1582, 10, 14, 0, 0, 0, 0).unwrap();
-        let j2: DateTime<Julian, Tt> = TryFrom::try_from(g).unwrap();
-        assert_eq!(j, j2);
-        let g2: DateTime<Gregorian, Tt> = TryFrom::try_from(j).unwrap();
-        assert_eq!(g, g2);
+        let dt = DateTime::<Julian, Tt>::new(-43, 3, 15, 12, 0, 0, 0).unwrap();
+        assert_eq!(
+            dt.format_era(),
+            "44 BCE 03-15 12:00:00.000000000000000000 Julian TT"
+        );
 
-        let j = DateTime::<Julian, Tt>::new(-4713, 1, 1, 0, 0, 0, 0).unwrap();
-        let g = DateTime::<Gregorian, Tt>::new(-4714, 11, 24, 0, 0, 0, 0).unwrap();
-        let j2: DateTime<Julian, Tt> = TryFrom::try_from(g).unwrap();
-        assert_eq!(j, j2);
-        let g2: DateTime<Gregorian, Tt> = TryFrom::try_from(j).unwrap();
-        assert_eq!(g, g2);
+        let dt = DateTime::<Gregorian, Utc>::new(2023, 6, 1, 1, 2, 3, 4).unwrap();
+        assert_eq!(
+            dt.format_era(),
+            "2023 CE 06-01 01:02:03.000000000000000004 Gregorian UTC"
+        );
 
-        let j = DateTime::<Julian, Tt>::new(1, 1, 3, 0, 0, 0, 0).unwrap();
-        let g = DateTime::<Gregorian, Tt>::new(1, 1, 1, 0, 0, 0, 0).unwrap();
-        let j2: DateTime<Julian, Tt> = TryFrom::try_from(g).unwrap();
-        assert_eq!(j, j2);
-        let g2: DateTime<Gregorian, Tt> = TryFrom::try_from(j).unwrap();
-        assert_eq!(g, g2);
+        let dt = DateTime::<Gregorian, Tt>::new(i32::MIN, 1, 1, 0, 0, 0, 0).unwrap();
+        assert!(dt.format_era().starts_with("2147483649 BCE "));
 
-        let j = DateTime::<Julian, Tt>::new(1, 1, 1, 0, 0, 0, 0).unwrap();
-        let g = DateTime::<Gregorian, Tt>::new(0, 12, 30, 0, 0, 0, 0).unwrap();
-        let j2: DateTime<Julian, Tt> = TryFrom::try_from(g).unwrap();
-        assert_eq!(j, j2);
-        let g2: DateTime<Gregorian, Tt> = TryFrom::try_from(j).unwrap();
-        assert_eq!(g, g2);
+        // Display is unchanged
+        let dt = DateTime::<Julian, Tt>::new(-43, 3, 15, 12, 0, 0, 0).unwrap();
+        assert_eq!(
+            format!("{dt}"),
+            "-043-03-15 12:00:00.000000000000000000 Julian TT"
+        );
     }
 
     #[test]
-    fn test_epoch_duration() {
+    fn test_parse_from() {
         crate::setup_logging();
 
-        let g = DateTime::<Gregorian, Tt>::new(1582, 10, 14, 0, 0, 0, 0).unwrap();
-        let h = DateTime::<Gregorian, Tt>::from_duration_from_epoch(g.duration_from_epoch());
-        assert_eq!(g, h);
+        // US style
+        let dt = DateTime::<Gregorian, Utc>::parse_from("%m/%d/%Y", "07/04/1976").unwrap();
+        assert_eq!(dt, DateTime::new(1976, 7, 4, 0, 0, 0, 0).unwrap());
 
-        let g = DateTime::<Julian, Tt>::new(1582, 10, 14, 11, 0, 5, 130).unwrap();
-        let h = DateTime::<Julian, Tt>::from_duration_from_epoch(g.duration_from_epoch());
-        assert_eq!(g, h);
+        // European style
+        let dt =
+            DateTime::<Gregorian, Utc>::parse_from("%d.%m.%Y %H:%M", "4.7.1976 13:05").unwrap();
+        assert_eq!(dt, DateTime::new(1976, 7, 4, 13, 5, 0, 0).unwrap());
+
+        // Day of the year, in a leap year
+        let dt = DateTime::<Gregorian, Utc>::parse_from("%Y-%j", "2024-366").unwrap();
+        assert_eq!(dt, DateTime::new(2024, 12, 31, 0, 0, 0, 0).unwrap());
+        assert!(DateTime::<Gregorian, Utc>::parse_from("%Y-%j", "2023-366").is_err());
+        assert!(DateTime::<Gregorian, Utc>::parse_from("%Y-%j %m", "2024-366 11").is_err());
+
+        // Fractional seconds and a leap second
+        let dt = DateTime::<Gregorian, Utc>::parse_from("%Y%m%dT%H%M%S.%f", "19981231T235960.5")
+            .unwrap();
+        assert_eq!(
+            dt,
+            DateTime::new(1998, 12, 31, 23, 59, 60, 500_000_000_000_000_000).unwrap()
+        );
+        assert!(matches!(
+            DateTime::<Gregorian, Utc>::parse_from("%Y-%m-%d %H:%M:%S", "1999-12-31 23:59:61"),
+            Err(Error::RangeError)
+        ));
+
+        // Only where the leap second table has one
+        assert!(matches!(
+            DateTime::<Gregorian, Utc>::parse_from("%Y-%m-%d %H:%M:%S", "2015-06-29 23:59:60"),
+            Err(Error::RangeError)
+        ));
+        assert!(
+            DateTime::<Gregorian, Utc>::parse_from("%Y-%m-%d %H:%M:%S", "2015-06-30 23:59:60")
+                .is_ok()
+        );
+        assert!(matches!(
+            DateTime::<Gregorian, Tt>::parse_from("%Y-%m-%d %H:%M:%S", "2015-06-30 23:59:60"),
+            Err(Error::RangeError)
+        ));
+
+        // Literals must match
+        assert!(matches!(
+            DateTime::<Gregorian, Utc>::parse_from("%d/%m/%Y", "04-07-1976"),
+            Err(Error::ParseError(_))
+        ));
+        assert!(matches!(
+            DateTime::<Gregorian, Utc>::parse_from("%m/%d", "07/04"),
+            Err(Error::ParseError(_))
+        ));
     }
 }
@@ -1,6 +1,9 @@
-use std::convert::TryFrom;
-use std::fmt;
-use std::ops::{Add, Mul, Neg, Sub};
+use alloc::format;
+use alloc::string::String;
+use core::convert::TryFrom;
+use core::fmt;
+use core::ops::{Add, Mul, Neg, Rem, Sub};
+use core::str::FromStr;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -13,7 +16,9 @@ use serde::{Deserialize, Serialize};
 /// Negative values are supported.
 ///
 /// Stored in 128 bits.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+///
+/// The default `Duration` is zero.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Duration {
     pub(crate) secs: i64,
@@ -25,10 +30,49 @@ pub struct Duration {
 }
 
 impl Duration {
-    pub(crate) fn normalize(&mut self) {
+    /// A zero length `Duration`
+    pub const ZERO: Self = Self { secs: 0, attos: 0 };
+
+    /// One second
+    pub const SECOND: Self = Self { secs: 1, attos: 0 };
+
+    /// One minute (60 seconds)
+    pub const MINUTE: Self = Self { secs: 60, attos: 0 };
+
+    /// One hour (3600 seconds)
+    pub const HOUR: Self = Self {
+        secs: 3600,
+        attos: 0,
+    };
+
+    /// One day of 86400 seconds (UTC days with leap seconds are longer or shorter)
+    pub const DAY: Self = Self {
+        secs: 86400,
+        attos: 0,
+    };
+
+    /// One week (7 days of 86400 seconds)
+    pub const WEEK: Self = Self {
+        secs: 604_800,
+        attos: 0,
+    };
+
+    /// The most negative `Duration`, almost 2^63 seconds before zero
+    pub const MIN: Self = Self {
+        secs: i64::MIN,
+        attos: -999_999_999_999_999_999,
+    };
+
+    /// The most positive `Duration`, almost 2^63 seconds after zero
+    pub const MAX: Self = Self {
+        secs: i64::MAX,
+        attos: 999_999_999_999_999_999,
+    };
+
+    pub(crate) const fn normalize(&mut self) {
         // This doesn't need divmod_i64 euclidean modulus because we reflect
         // negatives through zero
-        self.secs += (self.attos / 1_000_000_000_000_000_000) as i64;
+        self.secs += self.attos / 1_000_000_000_000_000_000;
         self.attos %= 1_000_000_000_000_000_000;
         if self.secs < 0 && self.attos > 0 {
             self.attos -= 1_000_000_000_000_000_000;
@@ -41,7 +85,7 @@ impl Duration {
 
     /// Make a new `Duration` with given number of seconds and attoseconds.
     #[must_use]
-    pub fn new(secs: i64, attos: i64) -> Self {
+    pub const fn new(secs: i64, attos: i64) -> Self {
         let mut d = Self { secs, attos };
         d.normalize();
         d
@@ -63,14 +107,110 @@ impl Duration {
 
     /// The full value expressed in attoseconds. Returns None on overflow.
     ///
-    /// This overflows on durations more than about 18 seconds.
+    /// This overflows on durations more than about 18 seconds. See
+    /// `as_attos_i128`() which does not.
     #[must_use]
     pub const fn as_attos(&self) -> Option<i64> {
-        let sec_part = match self.secs.checked_mul(1_000_000_000_000_000_000) {
-            Some(ns) => ns,
-            None => return None,
+        let Some(sec_part) = self.secs.checked_mul(1_000_000_000_000_000_000) else {
+            return None;
         };
-        sec_part.checked_add(self.attos as i64)
+        sec_part.checked_add(self.attos)
+    }
+
+    /// The full value expressed in attoseconds, which always fits in an `i128`
+    #[must_use]
+    #[allow(clippy::cast_lossless)]
+    pub const fn as_attos_i128(&self) -> i128 {
+        self.secs as i128 * 1_000_000_000_000_000_000 + self.attos as i128
+    }
+
+    /// The full value expressed in nanoseconds, truncated toward zero
+    #[must_use]
+    pub const fn as_nanos_i128(&self) -> i128 {
+        self.as_attos_i128() / 1_000_000_000
+    }
+
+    /// The full value expressed in microseconds, truncated toward zero
+    #[must_use]
+    pub const fn as_micros_i128(&self) -> i128 {
+        self.as_attos_i128() / 1_000_000_000_000
+    }
+
+    /// The full value expressed in milliseconds, truncated toward zero
+    #[must_use]
+    pub const fn as_millis_i128(&self) -> i128 {
+        self.as_attos_i128() / 1_000_000_000_000_000
+    }
+
+    /// The full value expressed in seconds (low precision)
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn as_secs_f64(&self) -> f64 {
+        self.secs as f64 + self.attos as f64 / 1_000_000_000_000_000_000.
+    }
+
+    /// Create from a number of seconds (low precision)
+    ///
+    /// An `f64` has a 53-bit mantissa, so only about 16 significant digits
+    /// survive: a duration of a few seconds is good to about a femtosecond,
+    /// but one of a century (about 3e9 seconds) only to about a microsecond.
+    /// Any remaining fraction of an attosecond is truncated toward zero, as
+    /// with multiplying a `Duration` by an `f64`.
+    #[must_use]
+    pub fn from_secs_f64(secs: f64) -> Self {
+        Self::SECOND * secs
+    }
+
+    /// Create from a number of hours (low precision)
+    ///
+    /// See `from_secs_f64`() for the precision available.
+    #[must_use]
+    pub fn from_hours_f64(hours: f64) -> Self {
+        Self::HOUR * hours
+    }
+
+    /// Create from a number of days of 86400 seconds (low precision)
+    ///
+    /// See `from_secs_f64`() for the precision available.
+    #[must_use]
+    pub fn from_days_f64(days: f64) -> Self {
+        Self::DAY * days
+    }
+
+    /// Create from a number of Julian years of exactly 365.25 days (low
+    /// precision)
+    ///
+    /// See `from_secs_f64`() for the precision available.
+    #[must_use]
+    pub fn from_julian_years_f64(years: f64) -> Self {
+        Self {
+            secs: 31_557_600,
+            attos: 0,
+        } * years
+    }
+
+    // The inverse of as_attos_i128(). Panics if the seconds overflow.
+    pub(crate) fn from_attos_i128(attos: i128) -> Self {
+        Self::checked_from_attos_i128(attos).expect("Duration overflow")
+    }
+
+    // The inverse of as_attos_i128(), or None if the seconds overflow
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) fn checked_from_attos_i128(attos: i128) -> Option<Self> {
+        let secs = i64::try_from(attos / 1_000_000_000_000_000_000).ok()?;
+        Some(Self {
+            secs,
+            attos: (attos % 1_000_000_000_000_000_000) as i64,
+        })
+    }
+
+    // Split into a whole number of `unit`s (rounded down) and the remaining
+    // attoseconds. Panics if `unit` is not positive.
+    pub(crate) fn split_units(&self, unit: Self) -> (i128, i128) {
+        let unit = unit.as_attos_i128();
+        assert!(unit > 0, "unit must be positive");
+        let attos = self.as_attos_i128();
+        (attos.div_euclid(unit), attos.rem_euclid(unit))
     }
 
     /// Determine if the duration is zero
@@ -78,13 +218,208 @@ impl Duration {
     pub const fn is_zero(&self) -> bool {
         self.secs == 0 && self.attos == 0
     }
+
+    /// Determine if the duration is greater than zero
+    #[must_use]
+    pub const fn is_positive(&self) -> bool {
+        self.secs > 0 || self.attos > 0
+    }
+
+    /// Determine if the duration is less than zero
+    #[must_use]
+    pub const fn is_negative(&self) -> bool {
+        self.secs < 0 || self.attos < 0
+    }
+
+    /// The sign of the duration: -1 if negative, 0 if zero, or 1 if positive
+    #[must_use]
+    pub const fn signum(&self) -> i8 {
+        if self.is_negative() {
+            -1
+        } else if self.is_positive() {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// The absolute value of the duration
+    ///
+    /// # Panics
+    ///
+    /// Panics if the seconds are `i64::MIN`, whose absolute value does not fit.
+    /// See `checked_abs`() for a non-panicking version.
+    #[must_use]
+    pub const fn abs(&self) -> Self {
+        match self.checked_abs() {
+            Some(d) => d,
+            None => panic!("Duration overflow"),
+        }
+    }
+
+    /// The absolute value of the duration, or `None` if the seconds are
+    /// `i64::MIN`, whose absolute value does not fit.
+    #[must_use]
+    pub const fn checked_abs(&self) -> Option<Self> {
+        if !self.is_negative() {
+            return Some(*self);
+        }
+        // Seconds and attoseconds always share a sign, so negating both keeps
+        // the duration normalized
+        let Some(secs) = self.secs.checked_neg() else {
+            return None;
+        };
+        Some(Self {
+            secs,
+            attos: -self.attos,
+        })
+    }
+
+    /// Subtract `rhs`, or return `None` if the seconds would overflow
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        // i128 attoseconds cannot overflow here
+        let attos = self.as_attos_i128() - rhs.as_attos_i128();
+        let secs = i64::try_from(attos / 1_000_000_000_000_000_000).ok()?;
+        Some(Self {
+            secs,
+            attos: (attos % 1_000_000_000_000_000_000) as i64,
+        })
+    }
+
+    /// How many whole `step`s fit, and what is left over
+    ///
+    /// The count is rounded down and the remainder is never negative, so for a
+    /// negative duration the count is negative too. For example, 10.5 seconds
+    /// split by 3 seconds is 3 steps with 1.5 seconds left, and -10.5 seconds
+    /// is -4 steps with 1.5 seconds left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not positive, or if the count does not fit in an
+    /// `i64`.
+    #[must_use]
+    pub fn split(&self, step: Self) -> (i64, Self) {
+        let (steps, remainder) = self.split_units(step);
+        (
+            i64::try_from(steps).expect("Step count overflow"),
+            Self::from_attos_i128(remainder),
+        )
+    }
+
+    /// Scale by the exact ratio `num` / `den`, rounding toward zero
+    ///
+    /// Unlike multiplying by an `f64`, this is exact to the attosecond.
+    /// Returns `None` if `den` is zero or if the result overflows.
+    #[must_use]
+    pub fn mul_div(&self, num: i64, den: i64) -> Option<Self> {
+        let (mut num, mut den) = (i128::from(num), i128::from(den));
+        if den == 0 {
+            return None;
+        }
+        if den < 0 {
+            num = -num;
+            den = -den;
+        }
+
+        // Split so that nothing overflows unless the result does. The two
+        // parts have the same sign, so rounding the second rounds the sum.
+        let attos = self.as_attos_i128();
+        let (quotient, remainder) = (attos / den, attos % den);
+        let scaled = quotient
+            .checked_mul(num)?
+            .checked_add(remainder * num / den)?;
+        Self::checked_from_attos_i128(scaled)
+    }
+
+    /// A rough description of the length, such as `3 hours` or `1 day`
+    ///
+    /// Only the largest whole unit (weeks, days, hours, minutes or seconds) is
+    /// given, rounded down, and the sign is ignored.
+    #[must_use]
+    pub fn humanize(&self) -> String {
+        const UNITS: [(u64, &str); 5] = [
+            (604_800, "week"),
+            (86400, "day"),
+            (3600, "hour"),
+            (60, "minute"),
+            (1, "second"),
+        ];
+
+        let secs = self.secs.unsigned_abs();
+        let (size, name) = UNITS
+            .iter()
+            .copied()
+            .find(|(size, _)| secs >= *size)
+            .unwrap_or((1, "second"));
+        let count = secs / size;
+        if count == 1 {
+            format!("1 {name}")
+        } else {
+            format!("{count} {name}s")
+        }
+    }
+
+    /// Format as a clock reading such as `01:02:03.500`, with `decimals`
+    /// digits of the fraction of a second
+    ///
+    /// The fraction is rounded to the nearest, with halves rounded away from
+    /// zero. Hours are not wrapped into days, so there may be more than two
+    /// digits of them. Negative durations have a leading `-`. Only 18 digits of
+    /// the fraction are significant, and any more are zero.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_clock_string(&self, decimals: usize) -> String {
+        use core::fmt::Write;
+
+        let significant = decimals.min(18) as u32;
+        let unit = 10_i128.pow(18 - significant);
+        let scale = 10_i128.pow(significant);
+
+        let attos = self.as_attos_i128();
+        let rounded = (attos.abs() + unit / 2) / unit;
+        let (secs, fraction) = (rounded / scale, rounded % scale);
+        let mut s = format!(
+            "{}{:02}:{:02}:{:02}",
+            if attos < 0 && rounded != 0 { "-" } else { "" },
+            secs / 3600,
+            secs / 60 % 60,
+            secs % 60
+        );
+        if decimals > 0 {
+            // Writing to a String cannot fail
+            let _ = write!(
+                s,
+                ".{fraction:0width$}{:0<pad$}",
+                "",
+                width = significant as usize,
+                pad = decimals - significant as usize
+            );
+        }
+        s
+    }
+
+    /// Parse an ISO 8601 duration such as `P1DT2H1M1.5S` or `-PT30S`
+    ///
+    /// Weeks, days, hours, minutes and seconds are accepted, and the seconds
+    /// may have up to 18 fractional digits. Years and months are not accepted
+    /// because they do not have a fixed length.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::ParseError` if the input is malformed, or
+    /// `Error::RangeError` if the value is too large.
+    pub fn parse_iso8601(s: &str) -> Result<Self, crate::error::Error> {
+        crate::iso8601::parse_duration(s)
+    }
 }
 
 impl fmt::Display for Duration {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         // herein we reflect through 0, so no div_modulo.
         // We only show a negative sign at the front
-        if self.secs < 0 {
+        if self.secs < 0 || self.attos < 0 {
             write!(f, "-P")?; // negative period designator
         } else {
             write!(f, "P")?; // period designator
@@ -96,7 +431,7 @@ impl fmt::Display for Duration {
         let days = s / 86400;
         s %= 86400; // only days should show any negative values
         if days != 0 {
-            write!(f, "{}D", days)?;
+            write!(f, "{days}D")?;
         }
 
         if s != 0 || a != 0 {
@@ -106,25 +441,33 @@ impl fmt::Display for Duration {
         let hours = s / 3600;
         s %= 3600;
         if hours != 0 {
-            write!(f, "{}H", hours)?;
+            write!(f, "{hours}H")?;
         }
 
         let minutes = s / 60;
         s %= 60;
         if minutes != 0 {
-            write!(f, "{}M", minutes)?;
+            write!(f, "{minutes}M")?;
         }
         if s != 0 || a != 0 {
             if a == 0 {
-                write!(f, "{}S", s)?;
+                write!(f, "{s}S")?;
             } else {
-                write!(f, "{}.{:018}S", s, a)?;
+                write!(f, "{s}.{a:018}S")?;
             }
         }
         Ok(())
     }
 }
 
+impl FromStr for Duration {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_iso8601(s)
+    }
+}
+
 impl Neg for Duration {
     type Output = Self;
 
@@ -170,8 +513,8 @@ impl Mul<f64> for Duration {
     #[allow(clippy::cast_precision_loss)]
     fn mul(self, rhs: f64) -> Self {
         let newsecs = self.secs as f64 * rhs;
-        let secs = newsecs.trunc() as i64;
-        let overflow_attos = (newsecs.fract() * 1_000_000_000_000_000_000.) as i64;
+        let (secs, fraction) = crate::trunc_fract(newsecs);
+        let overflow_attos = (fraction * 1_000_000_000_000_000_000.) as i64;
 
         let mut d = Self {
             secs,
@@ -182,12 +525,46 @@ impl Mul<f64> for Duration {
     }
 }
 
-impl TryFrom<std::time::Duration> for Duration {
+/// The Euclidean remainder, which is never negative
+///
+/// For example `elapsed % Duration::DAY` is the time of day. As with
+/// `i64::rem_euclid`(), a negative dividend still gives a non-negative result,
+/// so `Duration::new(-1, 0) % Duration::DAY` is 86399 seconds.
+///
+/// # Panics
+///
+/// Panics if `rhs` is zero.
+impl Rem for Duration {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        // i128 attoseconds cannot overflow, and the remainder is smaller than
+        // `rhs` so it always fits back into a Duration
+        Self::from_attos_i128(self.as_attos_i128().rem_euclid(rhs.as_attos_i128()))
+    }
+}
+
+/// The Euclidean remainder after dividing by `rhs` seconds
+///
+/// `d % 60` is the same as `d % Duration::MINUTE`.
+///
+/// # Panics
+///
+/// Panics if `rhs` is zero.
+impl Rem<i64> for Duration {
+    type Output = Self;
+
+    fn rem(self, rhs: i64) -> Self {
+        self % Self::new(rhs, 0)
+    }
+}
+
+impl TryFrom<core::time::Duration> for Duration {
     type Error = crate::error::Error;
 
     #[allow(clippy::cast_lossless)]
     #[allow(clippy::cast_possible_wrap)]
-    fn try_from(d: std::time::Duration) -> Result<Self, Self::Error> {
+    fn try_from(d: core::time::Duration) -> Result<Self, Self::Error> {
         if d.as_secs() > i64::MAX as u64 {
             // Duration will not fit! (and is ridiculously long)
             return Err(crate::error::Error::RangeError);
@@ -224,6 +601,59 @@ mod test {
         assert_eq!(d.attos, 100_000_000_000_000_000);
     }
 
+    #[test]
+    fn test_duration_default() {
+        crate::setup_logging();
+
+        let d = Duration::default();
+        assert!(d.is_zero());
+        assert_eq!(d, Duration::new(0, 0));
+    }
+
+    #[test]
+    fn test_duration_consts() {
+        // usable in const contexts
+        const TWO_DAYS: i64 = Duration::DAY.seconds_part() * 2;
+
+        crate::setup_logging();
+
+        assert!(Duration::ZERO.is_zero());
+        assert_eq!(Duration::SECOND, Duration::new(1, 0));
+        assert_eq!(Duration::MINUTE, Duration::new(60, 0));
+        assert_eq!(Duration::HOUR, Duration::new(3600, 0));
+        assert_eq!(Duration::DAY, Duration::new(86400, 0));
+        assert_eq!(Duration::WEEK, Duration::new(86400 * 7, 0));
+        assert_eq!(TWO_DAYS, 172_800);
+        assert_eq!(Duration::HOUR + Duration::MINUTE, Duration::new(3660, 0));
+    }
+
+    #[test]
+    fn test_duration_abs_and_signum() {
+        crate::setup_logging();
+
+        let tiny = Duration::new(0, -5);
+        assert!(tiny.is_negative());
+        assert!(!tiny.is_positive());
+        assert_eq!(tiny.signum(), -1);
+        assert_eq!(tiny.abs(), Duration::new(0, 5));
+
+        let d = Duration::new(-3, -250_000_000_000_000_000);
+        assert_eq!(d.abs(), Duration::new(3, 250_000_000_000_000_000));
+        assert_eq!(d.abs().signum(), 1);
+        assert_eq!(d.abs(), -d);
+
+        assert_eq!(Duration::ZERO.signum(), 0);
+        assert!(!Duration::ZERO.is_negative());
+        assert!(!Duration::ZERO.is_positive());
+        assert_eq!(Duration::ZERO.abs(), Duration::ZERO);
+        assert_eq!(Duration::HOUR.abs(), Duration::HOUR);
+
+        let min = Duration::new(i64::MIN, 0);
+        assert_eq!(min.checked_abs(), None);
+        let almost_min = Duration::new(i64::MIN + 1, -1);
+        assert_eq!(almost_min.checked_abs(), Some(Duration::new(i64::MAX, 1)));
+    }
+
     #[test]
     fn test_add_duration() {
         crate::setup_logging();
@@ -269,6 +699,247 @@ mod test {
         assert_eq!(d3.attos, 999_999_999_999_997_000);
     }
 
+    #[test]
+    fn test_duration_rem() {
+        crate::setup_logging();
+
+        let elapsed = Duration::new(3 * 86400 + 3723, 5);
+        assert_eq!(elapsed % Duration::DAY, Duration::new(3723, 5));
+        assert_eq!(elapsed % 60, Duration::new(3, 5));
+        assert_eq!(Duration::new(59, 0) % 60, Duration::new(59, 0));
+        assert_eq!(Duration::new(120, 0) % Duration::MINUTE, Duration::ZERO);
+
+        // Negative dividends give a non-negative remainder, as divmod_i64 does
+        assert_eq!(crate::divmod_i64(-1, 86400), (-1, 86399));
+        assert_eq!(
+            Duration::new(-1, 0) % Duration::DAY,
+            Duration::new(86399, 0)
+        );
+        assert_eq!(
+            Duration::new(0, -1) % Duration::SECOND,
+            Duration::new(0, 999_999_999_999_999_999)
+        );
+        assert_eq!(Duration::new(-90, 0) % 60, Duration::new(30, 0));
+        assert_eq!(Duration::new(-90, 0) % -60, Duration::new(30, 0));
+
+        // Far beyond what as_attos() can hold
+        let big = Duration::new(i64::MAX, 999_999_999_999_999_999);
+        assert_eq!(
+            big % Duration::new(i64::MAX, 0),
+            Duration::new(0, 999_999_999_999_999_999)
+        );
+        // -2^63 * 10^18 is one less than a multiple of 7
+        assert_eq!(
+            Duration::new(i64::MIN, 0) % Duration::new(0, 7),
+            Duration::new(0, 6)
+        );
+    }
+
+    #[test]
+    fn test_duration_wide_units() {
+        use float_cmp::ApproxEq;
+
+        crate::setup_logging();
+
+        // Just past where as_attos() overflows
+        let d = Duration::new(18, 500_000_000_000_000_000);
+        assert_eq!(d.as_attos(), None);
+        assert_eq!(d.as_attos_i128(), 18_500_000_000_000_000_000);
+        assert_eq!(d.as_nanos_i128(), 18_500_000_000);
+        assert_eq!(d.as_micros_i128(), 18_500_000);
+        assert_eq!(d.as_millis_i128(), 18_500);
+        assert!(d.as_secs_f64().approx_eq(18.5, (0.0, 1)));
+        let d = Duration::new(9, 0);
+        assert_eq!(d.as_attos().map(i128::from), Some(d.as_attos_i128()));
+
+        // Negative durations truncate toward zero
+        let d = -Duration::new(20, 1_500_000);
+        assert_eq!(d.as_nanos_i128(), -20_000_000_000);
+        assert_eq!(d.as_millis_i128(), -20_000);
+        assert!(d.as_secs_f64().approx_eq(-20.0, (1e-9, 0)));
+
+        // The extremes
+        let max = Duration::new(i64::MAX, 999_999_999_999_999_999);
+        assert_eq!(
+            max.as_attos_i128(),
+            i128::from(i64::MAX) * 1_000_000_000_000_000_000 + 999_999_999_999_999_999
+        );
+        assert_eq!(
+            max.as_nanos_i128(),
+            i128::from(i64::MAX) * 1_000_000_000 + 999_999_999
+        );
+    }
+
+    #[test]
+    fn test_duration_from_f64() {
+        crate::setup_logging();
+
+        assert_eq!(Duration::from_days_f64(1.5), Duration::new(129_600, 0));
+        assert_eq!(Duration::from_hours_f64(-0.5), Duration::new(-1800, 0));
+        assert_eq!(
+            Duration::from_secs_f64(2.25),
+            Duration::new(2, 250_000_000_000_000_000)
+        );
+        assert_eq!(
+            Duration::from_julian_years_f64(0.25),
+            Duration::from_days_f64(91.3125)
+        );
+        assert_eq!(
+            Duration::from_julian_years_f64(0.25),
+            Duration::new(7_889_400, 0)
+        );
+    }
+
+    #[test]
+    fn test_duration_min_and_max() {
+        crate::setup_logging();
+
+        assert!(Duration::MIN < Duration::ZERO && Duration::ZERO < Duration::MAX);
+        // Like the integers, MIN is one second further from zero than MAX
+        assert_eq!(-Duration::MAX - Duration::new(1, 0), Duration::MIN);
+        assert_eq!(Duration::MIN.checked_abs(), None);
+        assert_eq!(Duration::MAX.checked_sub(Duration::new(-1, 0)), None);
+        assert_eq!(Duration::MIN.checked_sub(Duration::new(0, 1)), None);
+        assert_eq!(
+            Duration::MAX.checked_sub(Duration::MAX),
+            Some(Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_duration_clock_string() {
+        crate::setup_logging();
+
+        assert_eq!(Duration::ZERO.to_clock_string(0), "00:00:00");
+        assert_eq!(Duration::ZERO.to_clock_string(3), "00:00:00.000");
+        let d = Duration::new(3723, 500_000_000_000_000_000);
+        assert_eq!(d.to_clock_string(3), "01:02:03.500");
+        assert_eq!((-d).to_clock_string(1), "-01:02:03.5");
+        assert_eq!(
+            Duration::new(100 * 3600 + 59, 0).to_clock_string(0),
+            "100:00:59"
+        );
+
+        // Rounding at the requested place, carrying into the seconds
+        let d = Duration::new(59, 999_500_000_000_000_000);
+        assert_eq!(d.to_clock_string(4), "00:00:59.9995");
+        assert_eq!(d.to_clock_string(3), "00:01:00.000");
+        assert_eq!(d.to_clock_string(2), "00:01:00.00");
+        assert_eq!(
+            Duration::new(0, 1_449_999_999_999_999).to_clock_string(3),
+            "00:00:00.001"
+        );
+        assert_eq!(
+            Duration::new(0, 1_500_000_000_000_000).to_clock_string(3),
+            "00:00:00.002"
+        );
+        assert_eq!(
+            Duration::new(0, -400_000_000_000_000_000).to_clock_string(0),
+            "00:00:00"
+        );
+        assert_eq!(
+            Duration::new(0, -500_000_000_000_000_000).to_clock_string(0),
+            "-00:00:01"
+        );
+
+        // Beyond attoseconds
+        assert_eq!(
+            Duration::new(1, 1).to_clock_string(20),
+            "00:00:01.00000000000000000100"
+        );
+        assert_eq!(Duration::MIN.to_clock_string(0), "-2562047788015215:30:09");
+    }
+
+    #[test]
+    fn test_duration_humanize() {
+        crate::setup_logging();
+
+        assert_eq!(Duration::ZERO.humanize(), "0 seconds");
+        assert_eq!(
+            Duration::new(0, 999_999_999_999_999_999).humanize(),
+            "0 seconds"
+        );
+        assert_eq!(
+            Duration::new(1, 500_000_000_000_000_000).humanize(),
+            "1 second"
+        );
+        assert_eq!(Duration::new(119, 0).humanize(), "1 minute");
+        assert_eq!(Duration::new(3 * 3600 + 59 * 60, 0).humanize(), "3 hours");
+        assert_eq!((-Duration::DAY).humanize(), "1 day");
+        assert_eq!(Duration::new(7 * 86400 - 1, 0).humanize(), "6 days");
+        assert_eq!(Duration::new(520 * 604_800, 0).humanize(), "520 weeks");
+    }
+
+    #[test]
+    fn test_duration_split() {
+        crate::setup_logging();
+
+        let span = Duration::new(10, 500_000_000_000_000_000);
+        let step = Duration::new(3, 0);
+        let leftover = Duration::new(1, 500_000_000_000_000_000);
+        assert_eq!(span.split(step), (3, leftover));
+        assert_eq!((-span).split(step), (-4, leftover));
+        assert_eq!(span.split(span), (1, Duration::new(0, 0)));
+        assert_eq!(Duration::new(0, 0).split(step), (0, Duration::new(0, 0)));
+        assert_eq!(
+            span.split(Duration::new(0, 1000)),
+            (10_500_000_000_000_000, Duration::new(0, 0))
+        );
+
+        // Recombining gives back the span
+        for d in [span, -span, Duration::new(-3, 0), Duration::new(2, 999)] {
+            let (steps, remainder) = d.split(step);
+            assert_eq!(step.mul_div(steps, 1).unwrap() + remainder, d);
+            assert!(!remainder.is_negative() && remainder < step);
+        }
+    }
+
+    #[test]
+    fn test_duration_mul_div() {
+        crate::setup_logging();
+
+        // A century scaled by a rate just under one
+        let century = Duration::new(3_153_600_000, 0);
+        let exact = century.mul_div(999_999_999, 1_000_000_000).unwrap();
+        assert_eq!(exact, Duration::new(3_153_599_996, 846_400_000_000_000_000));
+        let lossy = century * (999_999_999.0 / 1_000_000_000.0);
+        assert_ne!(lossy, exact);
+        assert!((lossy - exact).abs() > Duration::new(0, 1000));
+
+        // Rounding is toward zero, for either sign
+        let d = Duration::new(1, 0);
+        assert_eq!(
+            d.mul_div(1, 3),
+            Some(Duration::new(0, 333_333_333_333_333_333))
+        );
+        assert_eq!(
+            d.mul_div(-1, 3),
+            Some(Duration::new(0, -333_333_333_333_333_333))
+        );
+        assert_eq!(
+            d.mul_div(1, -3),
+            Some(Duration::new(0, -333_333_333_333_333_333))
+        );
+        assert_eq!(
+            (-d).mul_div(-1, -3),
+            Some(Duration::new(0, -333_333_333_333_333_333))
+        );
+        assert_eq!(d.mul_div(i64::MAX, i64::MAX), Some(d));
+
+        // Large values which only fit once scaled down
+        let max = Duration::new(i64::MAX, 0);
+        assert_eq!(max.mul_div(i64::MAX, i64::MAX), Some(max));
+        assert_eq!(
+            max.mul_div(1, 2),
+            Some(Duration::new(i64::MAX / 2, 500_000_000_000_000_000))
+        );
+
+        // Failures
+        assert_eq!(d.mul_div(1, 0), None);
+        assert_eq!(max.mul_div(2, 1), None);
+        assert_eq!(max.mul_div(i64::MIN, 1), None);
+    }
+
     #[test]
     fn test_duration_display() {
         crate::setup_logging();
@@ -277,30 +948,53 @@ mod test {
             secs: 86400 * 100,
             attos: 12000,
         };
-        assert_eq!(&*format!("{}", d), "P100DT0.000000000000012000S");
+        assert_eq!(&*format!("{d}"), "P100DT0.000000000000012000S");
         let d = Duration {
             secs: 86400 + 3600 * 2 + 60 + 1,
             attos: 120,
         };
-        assert_eq!(&*format!("{}", d), "P1DT2H1M1.000000000000000120S");
+        assert_eq!(&*format!("{d}"), "P1DT2H1M1.000000000000000120S");
         let d = Duration {
             secs: 60 * 3 + 5,
             attos: 15000,
         };
-        assert_eq!(&*format!("{}", d), "PT3M5.000000000000015000S");
+        assert_eq!(&*format!("{d}"), "PT3M5.000000000000015000S");
         let d = Duration {
             secs: -1,
             attos: -101,
         };
-        assert_eq!(&*format!("{}", d), "-PT1.000000000000000101S");
+        assert_eq!(&*format!("{d}"), "-PT1.000000000000000101S");
         let d = Duration {
             secs: -86400 * 3,
             attos: 31,
         };
-        assert_eq!(&*format!("{}", d), "-P3DT0.000000000000000031S");
+        assert_eq!(&*format!("{d}"), "-P3DT0.000000000000000031S");
         let d = Duration { secs: 0, attos: 31 };
-        assert_eq!(&*format!("{}", d), "PT0.000000000000000031S");
+        assert_eq!(&*format!("{d}"), "PT0.000000000000000031S");
+        let d = Duration {
+            secs: 0,
+            attos: -31,
+        };
+        assert_eq!(&*format!("{d}"), "-PT0.000000000000000031S");
         let d = Duration { secs: 0, attos: 0 };
-        assert_eq!(&*format!("{}", d), "P");
+        assert_eq!(&*format!("{d}"), "P");
+    }
+
+    #[test]
+    fn test_duration_from_str() {
+        crate::setup_logging();
+
+        let d: Duration = "P1DT30S".parse().unwrap();
+        assert_eq!(d, Duration::new(86430, 0));
+
+        let d = Duration::new(86400 + 3600 * 2 + 60 + 1, 120);
+        assert_eq!(format!("{d}").parse::<Duration>().unwrap(), d);
+
+        let d = Duration::new(-1, -101);
+        assert_eq!(format!("{d}").parse::<Duration>().unwrap(), d);
+
+        assert!("1 day".parse::<Duration>().is_err());
+        assert!("P1Y2M".parse::<Duration>().is_err());
+        assert!("PT1.S".parse::<Duration>().is_err());
     }
 }
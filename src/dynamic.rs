@@ -0,0 +1,230 @@
+use alloc::format;
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::calendar::{Calendar, Gregorian, Julian};
+use crate::date_time::DateTime;
+use crate::error::Error;
+use crate::instant::Instant;
+use crate::standard::{Standard, Tai, Tcb, Tcg, Tt, Utc};
+
+/// A `Calendar` chosen at runtime, such as from a command line option
+///
+/// This parses from the `Calendar` name, ignoring case, e.g. "gregorian". See
+/// `parse_datetime_dynamic`().
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnyCalendar {
+    /// The `Gregorian` calendar
+    Gregorian,
+    /// The `Julian` calendar
+    Julian,
+}
+
+impl AnyCalendar {
+    /// Every `AnyCalendar`
+    pub const ALL: [Self; 2] = [Self::Gregorian, Self::Julian];
+
+    /// The name of the `Calendar`, e.g. "Gregorian"
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Self::Gregorian => Gregorian::name(),
+            Self::Julian => Julian::name(),
+        }
+    }
+}
+
+impl fmt::Display for AnyCalendar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for AnyCalendar {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|c| c.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| Error::ParseError(format!("Unknown calendar: {s}")))
+    }
+}
+
+impl TryFrom<&str> for AnyCalendar {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// A time `Standard` chosen at runtime, such as from a command line option
+///
+/// This parses from the `Standard` abbreviation, ignoring case, e.g. "utc".
+/// See `parse_datetime_dynamic`().
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnyStandard {
+    /// Terrestrial Time, `Tt`
+    Tt,
+    /// International Atomic Time, `Tai`
+    Tai,
+    /// Coordinated Universal Time, `Utc`
+    Utc,
+    /// Geocentric Coordinate Time, `Tcg`
+    Tcg,
+    /// Barycentric Coordinate Time, `Tcb`
+    Tcb,
+}
+
+impl AnyStandard {
+    /// Every `AnyStandard`
+    pub const ALL: [Self; 5] = [Self::Tt, Self::Tai, Self::Utc, Self::Tcg, Self::Tcb];
+
+    /// The abbreviation of the `Standard`, e.g. "UTC"
+    #[must_use]
+    pub fn abbrev(&self) -> &'static str {
+        match *self {
+            Self::Tt => Tt::abbrev(),
+            Self::Tai => Tai::abbrev(),
+            Self::Utc => Utc::abbrev(),
+            Self::Tcg => Tcg::abbrev(),
+            Self::Tcb => Tcb::abbrev(),
+        }
+    }
+}
+
+impl fmt::Display for AnyStandard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.abbrev())
+    }
+}
+
+impl FromStr for AnyStandard {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|std| std.abbrev().eq_ignore_ascii_case(s))
+            .ok_or_else(|| Error::ParseError(format!("Unknown time standard: {s}")))
+    }
+}
+
+impl TryFrom<&str> for AnyStandard {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Parse an ISO 8601 date and time in a `Calendar` and time `Standard` chosen
+/// at runtime
+///
+/// This is `DateTime::<C, S>::parse_iso8601`() for the `C` and `S` given by
+/// `cal` and `std`, converted to an `Instant`.
+///
+/// # Errors
+///
+/// Will return `Error::ParseError` if the input is malformed, or
+/// `Error::RangeError` if any field is outside of the normal range.
+pub fn parse_datetime_dynamic(
+    s: &str,
+    cal: AnyCalendar,
+    std: AnyStandard,
+) -> Result<Instant, Error> {
+    match cal {
+        AnyCalendar::Gregorian => parse_in_standard::<Gregorian>(s, std),
+        AnyCalendar::Julian => parse_in_standard::<Julian>(s, std),
+    }
+}
+
+fn parse_in_standard<C: Calendar>(s: &str, std: AnyStandard) -> Result<Instant, Error> {
+    match std {
+        AnyStandard::Tt => parse::<C, Tt>(s),
+        AnyStandard::Tai => parse::<C, Tai>(s),
+        AnyStandard::Utc => parse::<C, Utc>(s),
+        AnyStandard::Tcg => parse::<C, Tcg>(s),
+        AnyStandard::Tcb => parse::<C, Tcb>(s),
+    }
+}
+
+fn parse<C: Calendar, S: Standard>(s: &str) -> Result<Instant, Error> {
+    Ok(Instant::from(DateTime::<C, S>::parse_iso8601(s)?))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_datetime_dynamic, AnyCalendar, AnyStandard};
+    use crate::calendar::{Gregorian, Julian};
+    use crate::date_time::DateTime;
+    use crate::error::Error;
+    use crate::instant::Instant;
+    use crate::standard::{Tai, Tcb, Tcg, Tt, Utc};
+    use core::convert::TryFrom;
+
+    #[test]
+    fn test_any_calendar_and_standard() {
+        crate::setup_logging();
+
+        assert_eq!(
+            "gregorian".parse::<AnyCalendar>().unwrap(),
+            AnyCalendar::Gregorian
+        );
+        assert_eq!(
+            AnyCalendar::try_from("JULIAN").unwrap(),
+            AnyCalendar::Julian
+        );
+        assert!(matches!(
+            "hebrew".parse::<AnyCalendar>(),
+            Err(Error::ParseError(_))
+        ));
+        assert_eq!("utc".parse::<AnyStandard>().unwrap(), AnyStandard::Utc);
+        assert_eq!(AnyStandard::try_from("Tcb").unwrap(), AnyStandard::Tcb);
+        assert!(matches!(
+            AnyStandard::try_from("UTCS"),
+            Err(Error::ParseError(_))
+        ));
+        for cal in AnyCalendar::ALL {
+            assert_eq!(cal.to_string().parse::<AnyCalendar>().unwrap(), cal);
+        }
+        for std in AnyStandard::ALL {
+            assert_eq!(std.to_string().parse::<AnyStandard>().unwrap(), std);
+        }
+
+        // Every combination agrees with the typed path
+        let s = "2016-12-31T23:59:59.5";
+        let typed = [
+            Instant::from(DateTime::<Gregorian, Tt>::parse_iso8601(s).unwrap()),
+            Instant::from(DateTime::<Gregorian, Tai>::parse_iso8601(s).unwrap()),
+            Instant::from(DateTime::<Gregorian, Utc>::parse_iso8601(s).unwrap()),
+            Instant::from(DateTime::<Gregorian, Tcg>::parse_iso8601(s).unwrap()),
+            Instant::from(DateTime::<Gregorian, Tcb>::parse_iso8601(s).unwrap()),
+            Instant::from(DateTime::<Julian, Tt>::parse_iso8601(s).unwrap()),
+            Instant::from(DateTime::<Julian, Tai>::parse_iso8601(s).unwrap()),
+            Instant::from(DateTime::<Julian, Utc>::parse_iso8601(s).unwrap()),
+            Instant::from(DateTime::<Julian, Tcg>::parse_iso8601(s).unwrap()),
+            Instant::from(DateTime::<Julian, Tcb>::parse_iso8601(s).unwrap()),
+        ];
+        let mut n = 0;
+        for cal in AnyCalendar::ALL {
+            for std in AnyStandard::ALL {
+                let i = parse_datetime_dynamic(s, cal, std).unwrap();
+                assert_eq!(i, typed[n], "{cal} {std}");
+                n += 1;
+            }
+        }
+
+        // Distinct combinations give distinct instants
+        for (n, a) in typed.iter().enumerate() {
+            assert!(typed[n + 1..].iter().all(|b| b != a));
+        }
+
+        assert!(matches!(
+            parse_datetime_dynamic("2016-13-01", AnyCalendar::Gregorian, AnyStandard::Utc),
+            Err(Error::RangeError)
+        ));
+    }
+}
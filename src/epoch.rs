@@ -2,6 +2,7 @@ use crate::duration::Duration;
 use crate::instant::Instant;
 
 /// A reference for a well known `Instant` in time, used for offsetting events from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Epoch {
     /// The start of the Julian Period,
     /// which is 4713 BCE on Jan 1st Julian, 00:00:00.0
@@ -36,6 +37,18 @@ pub enum Epoch {
     // JD 2415020.5
     E1900_0,
 
+    /// The NTP prime epoch (the start of NTP era 0),
+    /// which is January 1st, 1900 CE gregorian, 00:00:00.0
+    /// Specified in UTC
+    // JD 2415020.5 (approx, modify for UTC)
+    Ntp,
+
+    /// The B1950.0 Besselian epoch,
+    /// which is December 31, 1949 CE gregorian, 22:09:46.86192
+    /// Specified in TT (Besselian years are defined in ephemeris time)
+    // JD 2433282.42345905 (from B = 1900.0 + (JD - 2415020.31352) / 365.242198781)
+    B1950_0,
+
     /// The UNIX Epoch,
     /// which is January 1st, 1970 CE gregorian, 00:00:00.0
     /// Specified in UTC
@@ -48,6 +61,13 @@ pub enum Epoch {
     // JD 2443144.5003725 (https://en.wikipedia.org/wiki/International_Atomic_Time)
     TimeStandard,
 
+    /// The Year 1977
+    /// which is January 1st, 1977 CE gregorian, 00:00:00.0
+    /// Specified in UTC
+    /// This is the first instant after the leap second at the end of 1976.
+    // JD 2443144.5 (approx, modify for UTC)
+    Y1977,
+
     /// The J1991.25 astronomical epoch,
     /// which is April 2, 1991 CE gregorian, 13:30:00.0
     /// Specified in TT
@@ -85,53 +105,123 @@ impl Epoch {
     pub const fn as_instant(&self) -> Instant {
         match *self {
             // NOTE: all instants are internally represented in TT standard.
-            Epoch::JulianPeriod => Instant(Duration {
+            Self::JulianPeriod => Instant(Duration {
                 secs: -211_087_684_832,
                 attos: -184_000_000_000_000_000,
             }),
-            Epoch::JulianCalendar => Instant(Duration {
+            Self::JulianCalendar => Instant(Duration {
                 secs: -62_356_694_432,
                 attos: -184_000_000_000_000_000,
             }),
-            Epoch::GregorianCalendar => Instant(Duration {
+            Self::GregorianCalendar => Instant(Duration {
                 secs: -62_356_521_632,
                 attos: -184_000_000_000_000_000,
             }),
-            Epoch::J1900_0 => Instant(Duration {
+            Self::J1900_0 => Instant(Duration {
                 secs: -2_429_956_832,
                 attos: -184_000_000_000_000_000,
             }),
-            Epoch::E1900_0 => Instant(Duration {
+            Self::E1900_0 => Instant(Duration {
                 secs: -2_429_913_632,
                 attos: -184_000_000_000_000_000,
             }),
-            Epoch::Unix => Instant(Duration {
+            Self::Ntp => Instant(Duration {
+                secs: -2_429_913_591,
+                attos: 0,
+            }),
+            Self::B1950_0 => Instant(Duration {
+                secs: -852_083_445,
+                attos: -322_080_000_000_000_000,
+            }),
+            Self::Unix => Instant(Duration {
                 secs: -220_924_791,
                 attos: 0,
             }),
-            Epoch::TimeStandard => Instant(Duration { secs: 0, attos: 0 }),
-            Epoch::J1991_25 => Instant(Duration {
+            Self::TimeStandard => Instant(Duration { secs: 0, attos: 0 }),
+            Self::Y1977 => Instant(Duration { secs: 16, attos: 0 }),
+            Self::J1991_25 => Instant(Duration {
                 secs: 449_674_167,
                 attos: 816_000_000_000_000_000,
             }),
-            Epoch::Y2k => Instant(Duration {
+            Self::Y2k => Instant(Duration {
                 secs: 725_760_032,
                 attos: 0,
             }),
-            Epoch::J2000_0 => Instant(Duration {
+            Self::J2000_0 => Instant(Duration {
                 secs: 725_803_167,
                 attos: 816_000_000_000_000_000,
             }),
-            Epoch::J2100_0 => Instant(Duration {
+            Self::J2100_0 => Instant(Duration {
                 secs: 3_881_563_167,
                 attos: 816_000_000_000_000_000,
             }),
-            Epoch::J2200_0 => Instant(Duration {
+            Self::J2200_0 => Instant(Duration {
                 secs: 7_037_323_167,
                 attos: 816_000_000_000_000_000,
             }),
         }
     }
+
+    /// The exact offset of this `Epoch` from `other`, positive if this one is
+    /// later
+    #[must_use]
+    pub const fn duration_from(&self, other: Self) -> Duration {
+        let (this, other) = (self.as_instant().0, other.as_instant().0);
+        Duration::new(this.secs - other.secs, this.attos - other.attos)
+    }
+
+    /// Every `Epoch`, in chronological order
+    #[must_use]
+    pub const fn all() -> &'static [Self] {
+        &[
+            Self::JulianPeriod,
+            Self::JulianCalendar,
+            Self::GregorianCalendar,
+            Self::J1900_0,
+            Self::E1900_0,
+            Self::Ntp,
+            Self::B1950_0,
+            Self::Unix,
+            Self::TimeStandard,
+            Self::Y1977,
+            Self::J1991_25,
+            Self::Y2k,
+            Self::J2000_0,
+            Self::J2100_0,
+            Self::J2200_0,
+        ]
+    }
+
+    /// The name of this `Epoch`, such as `J2000.0` or `UNIX`
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match *self {
+            Self::JulianPeriod => "Julian Period",
+            Self::JulianCalendar => "Julian Calendar",
+            Self::GregorianCalendar => "Gregorian Calendar",
+            Self::J1900_0 => "J1900.0",
+            Self::E1900_0 => "1900.0",
+            Self::Ntp => "NTP",
+            Self::B1950_0 => "B1950.0",
+            Self::Unix => "UNIX",
+            Self::TimeStandard => "Time Standard",
+            Self::Y1977 => "1977",
+            Self::J1991_25 => "J1991.25",
+            Self::Y2k => "Y2K",
+            Self::J2000_0 => "J2000.0",
+            Self::J2100_0 => "J2100.0",
+            Self::J2200_0 => "J2200.0",
+        }
+    }
+
+    /// The `Epoch` with the given `name`(), ignoring case, if there is one
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all()
+            .iter()
+            .find(|e| e.name().eq_ignore_ascii_case(name))
+            .copied()
+    }
 }
 
 #[cfg(test)]
@@ -227,6 +317,20 @@ mod test {
         let check: Instant = From::from(dt);
         assert_eq!(instant, check);
 
+        let instant = Epoch::TimeStandard.as_instant();
+        let dt: DateTime<Gregorian, Tt> = From::from(instant);
+        assert_eq!(
+            dt,
+            DateTime::<Gregorian, Tt>::new(1977, 1, 1, 0, 0, 32, 184_000_000_000_000_000).unwrap()
+        );
+        let check: Instant = From::from(dt);
+        assert_eq!(instant, check);
+    }
+
+    #[test]
+    fn check_utc_epochs_and_conversion() {
+        crate::setup_logging();
+
         let instant = Epoch::Unix.as_instant();
         let dt: DateTime<Gregorian, Utc> = From::from(instant);
         assert_eq!(
@@ -236,6 +340,24 @@ mod test {
         let check: Instant = From::from(dt);
         assert_eq!(instant, check);
 
+        let instant = Epoch::Ntp.as_instant();
+        let dt: DateTime<Gregorian, Utc> = From::from(instant);
+        assert_eq!(
+            dt,
+            DateTime::<Gregorian, Utc>::new(1900, 1, 1, 0, 0, 0, 0).unwrap()
+        );
+        let check: Instant = From::from(dt);
+        assert_eq!(instant, check);
+
+        let instant = Epoch::Y1977.as_instant();
+        let dt: DateTime<Gregorian, Utc> = From::from(instant);
+        assert_eq!(
+            dt,
+            DateTime::<Gregorian, Utc>::new(1977, 1, 1, 0, 0, 0, 0).unwrap()
+        );
+        let check: Instant = From::from(dt);
+        assert_eq!(instant, check);
+
         let instant = Epoch::Y2k.as_instant();
         let dt: DateTime<Gregorian, Utc> = From::from(instant);
         assert_eq!(
@@ -244,12 +366,103 @@ mod test {
         );
         let check: Instant = From::from(dt);
         assert_eq!(instant, check);
+    }
 
-        let instant = Epoch::TimeStandard.as_instant();
+    #[test]
+    fn test_epoch_differences() {
+        use crate::duration::Duration;
+
+        const DAY: i64 = 86400;
+        const CENTURY: Duration = Epoch::J2000_0.duration_from(Epoch::J1900_0);
+
+        crate::setup_logging();
+
+        // Julian centuries
+        assert_eq!(CENTURY, Duration::new(36525 * DAY, 0));
+        assert_eq!(Epoch::J2100_0.duration_from(Epoch::J2000_0), CENTURY);
+        assert_eq!(Epoch::J2200_0.duration_from(Epoch::J2100_0), CENTURY);
+        assert_eq!(Epoch::J1900_0.duration_from(Epoch::J2000_0), -CENTURY);
+
+        // Julian day numbers
+        let jd = |epoch: Epoch| epoch.duration_from(Epoch::JulianPeriod);
+        assert_eq!(jd(Epoch::J1900_0), Duration::new(2_415_020 * DAY, 0));
+        assert_eq!(
+            jd(Epoch::E1900_0),
+            Duration::new(2_415_020 * DAY + DAY / 2, 0)
+        );
+        assert_eq!(jd(Epoch::J2000_0), Duration::new(2_451_545 * DAY, 0));
+        assert_eq!(
+            jd(Epoch::J1991_25),
+            Duration::new(2_448_349 * DAY + DAY / 16, 0)
+        );
+        assert_eq!(
+            jd(Epoch::JulianCalendar),
+            Duration::new(1_721_423 * DAY + DAY / 2, 0)
+        );
+        assert_eq!(
+            Epoch::GregorianCalendar.duration_from(Epoch::JulianCalendar),
+            Duration::new(2 * DAY, 0)
+        );
+        assert_eq!(
+            Epoch::B1950_0.duration_from(Epoch::J1900_0),
+            Duration::new(18262 * DAY + 36586, 861_920_000_000_000_000)
+        );
+
+        // UTC epochs include the leap seconds in between
+        assert_eq!(
+            Epoch::Y2k.duration_from(Epoch::Unix),
+            Duration::new(10957 * DAY + 23, 0)
+        );
+        assert_eq!(
+            Epoch::Unix.duration_from(Epoch::Ntp),
+            Duration::new(25567 * DAY, 0)
+        );
+        assert_eq!(
+            Epoch::J2000_0.duration_from(Epoch::Y2k),
+            Duration::new(DAY / 2 - 64, -184_000_000_000_000_000)
+        );
+        assert_eq!(
+            Epoch::TimeStandard.duration_from(Epoch::Y1977),
+            Duration::new(-16, 0)
+        );
+        for epoch in Epoch::all() {
+            assert!(epoch.duration_from(*epoch).is_zero());
+        }
+    }
+
+    #[test]
+    fn test_epoch_names() {
+        crate::setup_logging();
+
+        for epoch in Epoch::all() {
+            assert_eq!(Epoch::from_name(epoch.name()), Some(*epoch));
+            assert_eq!(Epoch::from_name(&epoch.name().to_lowercase()), Some(*epoch));
+        }
+        assert_eq!(Epoch::all().len(), 15);
+        assert!(Epoch::all()
+            .windows(2)
+            .all(|pair| pair[0].as_instant() < pair[1].as_instant()));
+
+        assert_eq!(Epoch::from_name("j2000.0"), Some(Epoch::J2000_0));
+        assert_eq!(Epoch::from_name("unix"), Some(Epoch::Unix));
+        assert_eq!(Epoch::from_name("J2000"), None);
+        assert_eq!(Epoch::from_name(""), None);
+    }
+
+    #[test]
+    fn check_besselian_epoch() {
+        crate::setup_logging();
+
+        let instant = Epoch::B1950_0.as_instant();
+        assert_eq!(
+            instant,
+            Instant::from_julian_day_str("2433282.42345905").unwrap()
+        );
         let dt: DateTime<Gregorian, Tt> = From::from(instant);
         assert_eq!(
             dt,
-            DateTime::<Gregorian, Tt>::new(1977, 1, 1, 0, 0, 32, 184_000_000_000_000_000).unwrap()
+            DateTime::<Gregorian, Tt>::new(1949, 12, 31, 22, 9, 46, 861_920_000_000_000_000)
+                .unwrap()
         );
         let check: Instant = From::from(dt);
         assert_eq!(instant, check);
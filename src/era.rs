@@ -0,0 +1,49 @@
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A historical era, for counting years without a year zero
+///
+/// Historians count years back from 1 AD as 1 BC, 2 BC, and so on, so that 1 BC
+/// is immediately followed by 1 AD. `DateTime` years follow ISO 8601 instead,
+/// where year 0 is 1 BC. See `DateTime::new_historical`().
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Era {
+    /// Before Christ, the years up to and including ISO year 0
+    Bc,
+    /// Anno Domini, the years from ISO year 1 onwards
+    Ad,
+}
+
+impl Era {
+    /// The abbreviation, either "BC" or "AD"
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match *self {
+            Self::Bc => "BC",
+            Self::Ad => "AD",
+        }
+    }
+}
+
+impl fmt::Display for Era {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Era;
+
+    #[test]
+    fn test_era() {
+        crate::setup_logging();
+
+        assert_eq!(format!("{}", Era::Bc), "BC");
+        assert_eq!(Era::Ad.as_str(), "AD");
+        assert!(Era::Bc < Era::Ad);
+    }
+}
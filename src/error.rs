@@ -1,5 +1,5 @@
-use std::error::Error as StdError;
-use std::fmt;
+use alloc::string::String;
+use core::fmt;
 
 /// Error type for the crate
 #[derive(Debug)]
@@ -8,10 +8,15 @@ pub enum Error {
     General(String),
     /// Out of Range
     RangeError,
+    /// The named field is out of range
+    FieldRangeError(&'static str),
+    /// Input could not be parsed
+    ParseError(String),
 }
 
-impl StdError for Error {
-    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         None
     }
 }
@@ -19,8 +24,10 @@ impl StdError for Error {
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match *self {
-            Error::General(ref s) => write!(f, "{}", s),
-            Error::RangeError => write!(f, "Value provided is out of range"),
+            Self::General(ref s) => write!(f, "{s}"),
+            Self::RangeError => write!(f, "Value provided is out of range"),
+            Self::FieldRangeError(field) => write!(f, "Value provided for {field} is out of range"),
+            Self::ParseError(ref s) => write!(f, "Parse error: {s}"),
         }
     }
 }
@@ -0,0 +1,165 @@
+use core::fmt;
+
+use crate::calendar::Calendar;
+use crate::date_time::{iso8601_fraction, DateTime};
+use crate::duration::Duration;
+use crate::standard::Utc;
+
+/// A UTC `DateTime` shown in local civil time at a fixed offset from UTC,
+/// such as `+05:30`
+///
+/// This only handles a fixed offset. There is no time zone database, so
+/// daylight saving time and other changes of offset are up to the caller.
+/// Create one with `DateTime::with_fixed_offset`().
+///
+/// It formats as an ISO 8601 date and time with the offset appended, such as
+/// `2000-01-01T05:30:00+05:30`, which `DateTime::parse_iso8601`() parses back
+/// to the UTC `DateTime`.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedOffsetDateTime<C: Calendar> {
+    utc: DateTime<C, Utc>,
+    local: DateTime<C, Utc>,
+    offset: Duration,
+}
+
+impl<C: Calendar> FixedOffsetDateTime<C> {
+    pub(crate) const fn new(
+        utc: DateTime<C, Utc>,
+        local: DateTime<C, Utc>,
+        offset: Duration,
+    ) -> Self {
+        Self { utc, local, offset }
+    }
+
+    /// The local civil date and time, which is the UTC date and time with the
+    /// offset added to its fields
+    ///
+    /// A leap second stays at second 60 of the local minute.
+    #[must_use]
+    pub const fn local(&self) -> DateTime<C, Utc> {
+        self.local
+    }
+
+    /// The offset of local civil time ahead of UTC
+    #[must_use]
+    pub const fn offset(&self) -> Duration {
+        self.offset
+    }
+
+    /// The UTC date and time
+    #[must_use]
+    pub const fn utc(&self) -> DateTime<C, Utc> {
+        self.utc
+    }
+}
+
+impl<C: Calendar> PartialEq for FixedOffsetDateTime<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.utc == other.utc && self.offset == other.offset
+    }
+}
+
+impl<C: Calendar> Eq for FixedOffsetDateTime<C> {}
+
+/// Formats as `2000-01-01T05:30:00+05:30`
+///
+/// The fraction of a second is only written if it is not zero, and without
+/// trailing zeroes. Years outside of 0 .. 9999 are written with a sign and at
+/// least four digits.
+impl<C: Calendar> fmt::Display for FixedOffsetDateTime<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let local = &self.local;
+        let year = local.year();
+        let sign = if year < 0 {
+            "-"
+        } else if year > 9999 {
+            "+"
+        } else {
+            ""
+        };
+        write!(
+            f,
+            "{sign}{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            year.unsigned_abs(),
+            local.month(),
+            local.day(),
+            local.hour(),
+            local.minute(),
+            local.second()
+        )?;
+        f.write_str(&iso8601_fraction(local.attosecond()))?;
+
+        let minutes = self.offset.seconds_part() / 60;
+        write!(
+            f,
+            "{}{:02}:{:02}",
+            if minutes < 0 { "-" } else { "+" },
+            minutes.abs() / 60,
+            minutes.abs() % 60
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::calendar::{Gregorian, Julian};
+    use crate::date_time::DateTime;
+    use crate::duration::Duration;
+    use crate::error::Error;
+    use crate::standard::Utc;
+
+    #[test]
+    fn test_fixed_offset() {
+        type Dt = DateTime<Gregorian, Utc>;
+
+        crate::setup_logging();
+
+        let india = Duration::new(5 * 3600 + 30 * 60, 0);
+        let midnight = Dt::new(2000, 1, 1, 0, 0, 0, 0).unwrap();
+        let local = midnight.with_fixed_offset(india).unwrap();
+        assert_eq!(local.local(), Dt::new(2000, 1, 1, 5, 30, 0, 0).unwrap());
+        assert_eq!(local.offset(), india);
+        assert_eq!(local.utc(), midnight);
+        assert_eq!(local.to_string(), "2000-01-01T05:30:00+05:30");
+
+        // The date rolls over either way
+        let evening = Dt::new(1999, 12, 31, 20, 0, 0, 250_000_000_000_000_000).unwrap();
+        let local = evening.with_fixed_offset(india).unwrap();
+        assert_eq!(local.to_string(), "2000-01-01T01:30:00.25+05:30");
+        assert_eq!(Dt::parse_iso8601(&local.to_string()).unwrap(), evening);
+        let pacific = Duration::new(-8 * 3600, 0);
+        let local = midnight.with_fixed_offset(pacific).unwrap();
+        assert_eq!(local.to_string(), "1999-12-31T16:00:00-08:00");
+        assert_eq!(Dt::parse_iso8601(&local.to_string()).unwrap(), midnight);
+        let local = midnight
+            .with_fixed_offset(Duration::new(-30 * 60, 0))
+            .unwrap();
+        assert_eq!(local.to_string(), "1999-12-31T23:30:00-00:30");
+
+        // A leap second stays a leap second
+        let leap = Dt::new(2016, 12, 31, 23, 59, 60, 500_000_000_000_000_000).unwrap();
+        let local = leap.with_fixed_offset(india).unwrap();
+        assert_eq!(local.to_string(), "2017-01-01T05:29:60.5+05:30");
+        assert_eq!(local.utc(), leap);
+
+        // Other calendars
+        let julian = DateTime::<Julian, Utc>::new(2000, 2, 29, 23, 0, 0, 0).unwrap();
+        let local = julian.with_fixed_offset(Duration::new(3600, 0)).unwrap();
+        assert_eq!(local.local().date(), (2000, 3, 1));
+        assert_eq!(local.utc(), julian);
+
+        // Offsets must be whole minutes, less than a day
+        for bad in [
+            Duration::new(86400, 0),
+            Duration::new(-86400, 0),
+            Duration::new(30, 0),
+            Duration::new(60, 1),
+        ] {
+            assert!(matches!(
+                midnight.with_fixed_offset(bad),
+                Err(Error::RangeError)
+            ));
+        }
+        assert!(midnight.with_fixed_offset(Duration::ZERO).is_ok());
+    }
+}
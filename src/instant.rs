@@ -1,5 +1,9 @@
-use std::convert::TryFrom;
-use std::ops::{Add, Sub};
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::ops::{Add, Sub};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -9,7 +13,14 @@ use crate::date_time::DateTime;
 use crate::duration::Duration;
 use crate::epoch::Epoch;
 use crate::error::Error;
-use crate::standard::Standard;
+use crate::interval::Interval;
+use crate::standard::{Standard, Utc};
+
+// The length of the tropical year at B1900.0 in days, which defines Besselian years
+const BESSELIAN_YEAR_DAYS: f64 = 365.242_198_781;
+
+// The length of a Julian year in days, which defines Julian epochs
+const JULIAN_YEAR_DAYS: f64 = 365.25;
 
 /// An `Instant` is a precise moment in time according to a particular time `Standard`.
 ///
@@ -20,14 +31,41 @@ use crate::standard::Standard;
 /// with Durations, avoids the complexity of the `Calendar`, and spans a much larger time
 /// span, able to handle times from about 20 times as old as the age of the
 /// universe backwards, and the same distance forwards, with attosecond (10^-18) precision.
+///
+/// The default `Instant` is `Epoch::TimeStandard` (January 1st, 1977 CE gregorian,
+/// 00:00:32.184 TT).
 //
 // Internally, Instants are Duration offsets from `Epoch::TimeStandard`, which is
 // January 1st, 1977 CE gregorian, 00:00:32.184 Tt
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Instant(pub(crate) Duration);
 
 impl Instant {
+    /// The earliest `Instant`, which is `Duration::MIN` from the internal epoch
+    pub const MIN: Self = Self(Duration::MIN);
+
+    /// The latest `Instant`, which is `Duration::MAX` from the internal epoch
+    pub const MAX: Self = Self(Duration::MAX);
+
+    /// The time elapsed since `Epoch::TimeStandard` (1977-01-01 00:00:32.184
+    /// TT), measured in TT
+    ///
+    /// This is how an `Instant` is stored, so it is exact.
+    #[must_use]
+    pub const fn tt_duration_since_epoch(&self) -> Duration {
+        self.0
+    }
+
+    /// Create from the time elapsed since `Epoch::TimeStandard` (1977-01-01
+    /// 00:00:32.184 TT), measured in TT
+    ///
+    /// This is the inverse of `tt_duration_since_epoch`().
+    #[must_use]
+    pub const fn from_tt_duration_since_epoch(duration: Duration) -> Self {
+        Self(duration)
+    }
+
     /// Create from a Julian Day (low precision)
     ///
     /// This is not as precise as `from_julian_day_parts`(), and much less precise than
@@ -36,8 +74,8 @@ impl Instant {
     #[allow(clippy::cast_possible_truncation)]
     pub fn from_julian_day_f64(jd: f64) -> Self {
         let fsecs = jd * 86400.0;
-        let whole_secs = fsecs.trunc() as i64;
-        let attos = (fsecs.fract() * 1_000_000_000_000_000_000.) as i64;
+        let (whole_secs, fraction) = crate::trunc_fract(fsecs);
+        let attos = (fraction * 1_000_000_000_000_000_000.) as i64;
         Epoch::JulianPeriod.as_instant() + Duration::new(whole_secs, attos)
     }
 
@@ -50,8 +88,8 @@ impl Instant {
     pub fn from_julian_day_parts(day: i64, day_fraction: f64) -> Self {
         // FIXME - range bound this
         let fsecs = day_fraction * 86400.;
-        let mut whole_secs = fsecs.trunc() as i64;
-        let attos = (fsecs.fract() * 1_000_000_000_000_000_000.) as i64;
+        let (mut whole_secs, fraction) = crate::trunc_fract(fsecs);
+        let attos = (fraction * 1_000_000_000_000_000_000.) as i64;
         whole_secs += day * 86400;
         Epoch::JulianPeriod.as_instant() + Duration::new(whole_secs, attos)
     }
@@ -82,6 +120,21 @@ impl Instant {
         Ok(Epoch::JulianPeriod.as_instant() + Duration::new(secs, attoseconds))
     }
 
+    /// Create from a Julian Day written as a decimal string (maximum precision)
+    ///
+    /// The string is parsed exactly, without going through floating point, so
+    /// a value such as `"2451545.123456789012345"` keeps all of its digits. The
+    /// result is truncated to the attosecond. A leading sign is accepted.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::ParseError` if the string is not a decimal number,
+    /// or `Error::RangeError` if it is too large.
+    pub fn from_julian_day_str(s: &str) -> Result<Self, Error> {
+        let days = crate::iso8601::parse_decimal_days(s)?;
+        Ok(Epoch::JulianPeriod.as_instant() + days)
+    }
+
     /// As Julian day (low precision)
     #[must_use]
     #[allow(clippy::cast_precision_loss)]
@@ -115,13 +168,620 @@ impl Instant {
     }
 
     /// As julian day (formatted as a string)
+    ///
+    /// This is low precision, such as `"JD 2451545.5"`. Instants before
+    /// `Epoch::JulianPeriod` have a negative Julian Day, such as `"JD -0.5"`.
+    /// See `as_julian_day_str`() for an exact decimal string.
     #[must_use]
+    #[allow(clippy::cast_precision_loss)]
     pub fn as_julian_day_formatted(&self) -> String {
-        let (day, frac) = self.as_julian_day_parts();
-        let fraction = format!("{}", frac)
-            .trim_start_matches(|c| c == '-' || c == '0')
-            .to_owned();
-        format!("JD {}{}", day, fraction)
+        let since = *self - Epoch::JulianPeriod.as_instant();
+        let sign = if since.is_negative() { "-" } else { "" };
+        let since = since.abs();
+        let mut day = since.secs / 86400;
+        let frac = ((since.secs % 86400) as f64 + since.attos as f64 / 1_000_000_000_000_000_000.)
+            / 86400.;
+        // The fraction can round up to a whole day
+        let fraction = if frac >= 1.0 {
+            day += 1;
+            String::new()
+        } else {
+            format!("{frac}").trim_start_matches('0').to_owned()
+        };
+        format!("JD {sign}{day}{fraction}")
+    }
+
+    /// As a Julian Day written as a decimal string (maximum precision)
+    ///
+    /// This is the exact inverse of `from_julian_day_str`(). An attosecond is
+    /// not a whole number of decimal places of a day, so the fraction is
+    /// written with 23 digits (rounded away from zero), which is the fewest
+    /// that always parse back to the same attosecond. Trailing zeroes are
+    /// omitted, so J2000.0 is `"2451545.0"`.
+    #[must_use]
+    pub fn as_julian_day_str(&self) -> String {
+        let since = (*self - Epoch::JulianPeriod.as_instant()).as_attos_i128();
+        let sign = if since < 0 { "-" } else { "" };
+        let since = since.unsigned_abs();
+        let day = since / 86_400_000_000_000_000_000_000;
+        let rem = since % 86_400_000_000_000_000_000_000;
+        // rem / 86400e18 * 10^23 is rem * 125 / 108
+        let fraction = (rem * 125).div_ceil(108);
+        let fraction = format!("{fraction:023}");
+        let fraction = match fraction.trim_end_matches('0') {
+            "" => "0",
+            f => f,
+        };
+        format!("{sign}{day}.{fraction}")
+    }
+
+    /// As a Besselian year, such as 1950.0 for B1950.0
+    ///
+    /// This is `B = 1900.0 + (JD - 2415020.31352) / 365.242198781` where the
+    /// Julian Day is in ephemeris time, which is taken to be TT.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn as_besselian_year(&self) -> f64 {
+        let since = *self - Epoch::B1950_0.as_instant();
+        let days = (since.secs as f64 + since.attos as f64 / 1_000_000_000_000_000_000.) / 86400.;
+        1950.0 + days / BESSELIAN_YEAR_DAYS
+    }
+
+    /// Create from a Besselian year, such as 1950.0 for B1950.0
+    ///
+    /// This is the inverse of `as_besselian_year`(), with the year in ephemeris
+    /// time, which is taken to be TT.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_besselian_year(b: f64) -> Self {
+        let fsecs = (b - 1950.0) * BESSELIAN_YEAR_DAYS * 86400.;
+        let (whole_secs, fraction) = crate::trunc_fract(fsecs);
+        let attos = (fraction * 1_000_000_000_000_000_000.) as i64;
+        Epoch::B1950_0.as_instant() + Duration::new(whole_secs, attos)
+    }
+
+    /// As a Julian year (Julian epoch), such as 2000.0 for J2000.0
+    ///
+    /// This is `J = 2000.0 + (JD - 2451545.0) / 365.25` with the Julian Day
+    /// in TT. This is the epoch used for proper motion reduction.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn as_julian_year(&self) -> f64 {
+        let since = *self - Epoch::J2000_0.as_instant();
+        let days = (since.secs as f64 + since.attos as f64 / 1_000_000_000_000_000_000.) / 86400.;
+        2000.0 + days / JULIAN_YEAR_DAYS
+    }
+
+    /// Create from a Julian year (Julian epoch), such as 2017.5 for J2017.5
+    ///
+    /// This is the inverse of `as_julian_year`(), with the year in TT.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_julian_year(j: f64) -> Self {
+        let fsecs = (j - 2000.0) * JULIAN_YEAR_DAYS * 86400.;
+        let (whole_secs, fraction) = crate::trunc_fract(fsecs);
+        let attos = (fraction * 1_000_000_000_000_000_000.) as i64;
+        Epoch::J2000_0.as_instant() + Duration::new(whole_secs, attos)
+    }
+
+    /// Seconds since J2000.0 in TT (low precision)
+    ///
+    /// This is the time argument of SPICE and many other ephemeris routines.
+    /// They usually want TDB, which differs from TT by less than 2
+    /// milliseconds.
+    #[must_use]
+    pub fn seconds_past_j2000_tt(&self) -> f64 {
+        (*self - Epoch::J2000_0.as_instant()).as_secs_f64()
+    }
+
+    /// Attoseconds since J2000.0 in TT
+    ///
+    /// This is exact, and always fits in an `i128`.
+    #[must_use]
+    pub const fn attoseconds_past_j2000_tt(&self) -> i128 {
+        self.0.as_attos_i128() - Epoch::J2000_0.as_instant().0.as_attos_i128()
+    }
+
+    /// Create from seconds since J2000.0 in TT (low precision)
+    ///
+    /// This is the inverse of `seconds_past_j2000_tt`(). See
+    /// `Duration::from_secs_f64`() for the precision available.
+    #[must_use]
+    pub fn from_seconds_past_j2000_tt(secs: f64) -> Self {
+        Epoch::J2000_0.as_instant() + Duration::from_secs_f64(secs)
+    }
+
+    /// Create from an NTP date (seconds and a 32-bit binary fraction of a second
+    /// since 1900-01-01 00:00:00 UTC, not counting leap seconds)
+    ///
+    /// `ntp_seconds` counts across eras, so values of 2^32 and above refer to
+    /// dates after the NTP era rollover on 2036-02-07. The 64-bit NTP timestamp
+    /// format only carries the low 32 bits of this; use `from_ntp_era_date`() to
+    /// supply the era separately.
+    ///
+    /// Leap seconds are added to produce a continuous `Instant`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ntp_seconds` is 2^63 or more.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_ntp_date(ntp_seconds: u64, ntp_fraction: u32) -> Self {
+        let era = i32::try_from(ntp_seconds >> 32).expect("NTP seconds out of range");
+        Self::from_ntp_era_date(era, ntp_seconds as u32, ntp_fraction)
+    }
+
+    /// Create from an NTP date in a given era
+    ///
+    /// NTP era 0 began 1900-01-01 00:00:00 UTC, era 1 begins at the 32-bit
+    /// seconds rollover on 2036-02-07 06:28:16 UTC, and negative eras are before
+    /// 1900. `era_offset` is the seconds within that era, and `fraction` is a
+    /// 32-bit binary fraction of a second. Leap seconds are not counted in the
+    /// offset, but are added to produce a continuous `Instant`.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn from_ntp_era_date(era: i32, era_offset: u32, fraction: u32) -> Self {
+        let secs = (i64::from(era) << 32) + i64::from(era_offset);
+
+        // Round up so that as_ntp_era_date() gives back the same fraction
+        let attos = ((u128::from(fraction) * 1_000_000_000_000_000_000 + 0xFFFF_FFFF) >> 32) as i64;
+
+        // NTP seconds are offset from the unadjusted UTC equivalent of E1900
+        let unadjusted = Epoch::E1900_0.as_instant().0 + Duration::new(secs, attos);
+        Self(Utc::to_tt(unadjusted))
+    }
+
+    /// As an NTP date (seconds and a 32-bit binary fraction of a second since
+    /// 1900-01-01 00:00:00 UTC, not counting leap seconds)
+    ///
+    /// The seconds count across eras (see `from_ntp_date`()). The fraction is
+    /// truncated. During a leap second, this repeats the following second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `Instant` is before 1900-01-01 00:00:00 UTC. Use
+    /// `as_ntp_era_date`() for such instants.
+    #[must_use]
+    pub fn as_ntp_date(&self) -> (u64, u32) {
+        let (era, era_offset, fraction) = self.as_ntp_era_date();
+        let era = u64::try_from(era).expect("Instant is before the NTP prime epoch");
+        ((era << 32) | u64::from(era_offset), fraction)
+    }
+
+    /// As an NTP date, returning the era, the seconds within the era, and the
+    /// 32-bit binary fraction of a second
+    ///
+    /// See `from_ntp_era_date`() for the meaning of these. The fraction is
+    /// truncated. During a leap second, this repeats the following second.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn as_ntp_era_date(&self) -> (i32, u32, u32) {
+        let since = Utc::from_tt(self.0) - Epoch::E1900_0.as_instant().0;
+
+        let (mut secs, mut attos) = (since.secs, since.attos);
+        if attos < 0 {
+            secs -= 1;
+            attos += 1_000_000_000_000_000_000;
+        }
+
+        let (era, era_offset) = crate::divmod_i64(secs, 1 << 32);
+        let fraction = (((attos as u128) << 32) / 1_000_000_000_000_000_000) as u32;
+        (era as i32, era_offset as u32, fraction)
+    }
+
+    /// Create from a UNIX timestamp (seconds since 1970-01-01 00:00:00 UTC, not
+    /// counting leap seconds)
+    ///
+    /// Leap seconds are added to produce a continuous `Instant`, the same way
+    /// as `TryFrom<SystemTime>` does.
+    #[must_use]
+    pub fn from_unix_timestamp(secs: i64) -> Self {
+        Self::from_unix_duration(Duration::new(secs, 0))
+    }
+
+    /// Create from a UNIX timestamp in nanoseconds
+    ///
+    /// See `from_unix_timestamp`().
+    ///
+    /// # Panics
+    ///
+    /// Panics if the seconds do not fit in an `i64`.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_unix_timestamp_nanos(nanos: i128) -> Self {
+        let secs = i64::try_from(nanos.div_euclid(1_000_000_000)).expect("Duration overflow");
+        let attos = nanos.rem_euclid(1_000_000_000) as i64 * 1_000_000_000;
+        Self::from_unix_duration(Duration::new(secs, attos))
+    }
+
+    /// As a UNIX timestamp (whole seconds since 1970-01-01 00:00:00 UTC, not
+    /// counting leap seconds)
+    ///
+    /// This rounds down, like POSIX time. During a leap second, this repeats
+    /// the following second.
+    #[must_use]
+    pub fn as_unix_timestamp(&self) -> i64 {
+        let since = self.unix_duration();
+        if since.attos < 0 {
+            since.secs - 1
+        } else {
+            since.secs
+        }
+    }
+
+    /// As a UNIX timestamp, with a fraction of a second (low precision)
+    ///
+    /// See `as_unix_timestamp`().
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn as_unix_timestamp_f64(&self) -> f64 {
+        let since = self.unix_duration();
+        since.secs as f64 + since.attos as f64 / 1_000_000_000_000_000_000.
+    }
+
+    /// As a UNIX timestamp in nanoseconds, rounded down
+    ///
+    /// See `as_unix_timestamp`().
+    #[must_use]
+    pub fn as_unix_timestamp_nanos(&self) -> i128 {
+        self.unix_duration()
+            .as_attos_i128()
+            .div_euclid(1_000_000_000)
+    }
+
+    // UNIX time, like unadjusted UTC, does not count leap seconds, so we offset
+    // from the UNIX epoch as UTC and let the Utc standard add the leap seconds.
+    fn from_unix_duration(since_unix_epoch_less_leaps: Duration) -> Self {
+        let unix_epoch_utc = Utc::from_tt(Epoch::Unix.as_instant().0);
+        Self(Utc::to_tt(unix_epoch_utc + since_unix_epoch_less_leaps))
+    }
+
+    // The inverse of from_unix_duration()
+    fn unix_duration(&self) -> Duration {
+        Utc::from_tt(self.0) - Utc::from_tt(Epoch::Unix.as_instant().0)
+    }
+
+    /// The earlier of this and `other`
+    #[must_use]
+    pub fn min(self, other: Self) -> Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// The later of this and `other`
+    #[must_use]
+    pub fn max(self, other: Self) -> Self {
+        if other > self {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// This `Instant` restricted to the range `min` ..= `max`
+    ///
+    /// In debug builds this asserts that `min <= max`.
+    #[must_use]
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        debug_assert!(min <= max, "clamp: min must not be after max");
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// This `Instant` restricted to within `range`
+    ///
+    /// An `Interval` does not include its end, so an `Instant` at or after the
+    /// end is moved to the last attosecond before it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is empty.
+    #[must_use]
+    pub fn clamp_to(&self, range: &Interval) -> Self {
+        assert!(!range.is_empty(), "clamp_to: range must not be empty");
+        (*self).clamp(range.start(), range.end() - Duration::new(0, 1))
+    }
+
+    /// This `Instant`, if it is within `range`
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if this is before the start of `range`,
+    /// or is at or after its end.
+    pub fn require_within(&self, range: &Interval) -> Result<Self, Error> {
+        if range.contains(*self) {
+            Ok(*self)
+        } else {
+            Err(Error::RangeError)
+        }
+    }
+
+    /// The `Instant` halfway between this and `other`
+    ///
+    /// This is exact when the span between them is an even number of
+    /// attoseconds, and otherwise rounds toward the earlier of the two. It
+    /// cannot overflow, however far apart they are.
+    #[must_use]
+    pub fn midpoint(&self, other: &Self) -> Self {
+        let earlier = Self::min(*self, *other).0.as_attos_i128();
+        let later = Self::max(*self, *other).0.as_attos_i128();
+        Self(Duration::from_attos_i128(earlier + (later - earlier) / 2))
+    }
+
+    /// Linear interpolation from this `Instant` (at `t` = 0) to `other` (at
+    /// `t` = 1)
+    ///
+    /// `t` is normally in the range 0 ..= 1; values outside of it extrapolate.
+    /// The span is scaled in floating point, so the result is only as precise
+    /// as an `f64` fraction of the span.
+    #[must_use]
+    pub fn lerp(&self, other: &Self, t: f64) -> Self {
+        *self + (*other - *self) * t
+    }
+
+    /// Where this `Instant` sits in the span from `start` (0.0) to `end` (1.0)
+    ///
+    /// This is the inverse of `lerp`(). If `clamp` is true the result is
+    /// restricted to the range 0.0 ..= 1.0, otherwise an `Instant` outside of
+    /// the span gives a result outside of that range. An empty span (where
+    /// `start == end`) gives 0.0.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn fraction_between(&self, start: &Self, end: &Self, clamp: bool) -> f64 {
+        let span = (*end - *start).as_attos_i128();
+        if span == 0 {
+            return 0.0;
+        }
+        let fraction = (*self - *start).as_attos_i128() as f64 / span as f64;
+        if clamp {
+            fraction.clamp(0.0, 1.0)
+        } else {
+            fraction
+        }
+    }
+
+    /// The `Duration` from `earlier` to this `Instant`, or `None` if it is too
+    /// long to represent
+    ///
+    /// This is the same as `self - earlier`, which overflows on spans of more
+    /// than about 292 billion years.
+    #[must_use]
+    pub fn checked_duration_since(&self, earlier: &Self) -> Option<Duration> {
+        self.0.checked_sub(earlier.0)
+    }
+
+    /// Round down to a multiple of `unit` measured from `Epoch::TimeStandard`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `unit` is not positive.
+    #[must_use]
+    pub fn floor_to(&self, unit: Duration) -> Self {
+        let (units, _) = self.0.split_units(unit);
+        Self(Duration::from_attos_i128(units * unit.as_attos_i128()))
+    }
+
+    /// Round up to a multiple of `unit` measured from `Epoch::TimeStandard`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `unit` is not positive.
+    #[must_use]
+    pub fn ceil_to(&self, unit: Duration) -> Self {
+        let (units, remainder) = self.0.split_units(unit);
+        let units = if remainder == 0 { units } else { units + 1 };
+        Self(Duration::from_attos_i128(units * unit.as_attos_i128()))
+    }
+
+    /// Round to the nearest multiple of `unit` measured from `Epoch::TimeStandard`
+    ///
+    /// An `Instant` exactly halfway between two multiples is rounded to the even
+    /// multiple.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `unit` is not positive.
+    #[must_use]
+    pub fn round_to(&self, unit: Duration) -> Self {
+        let (units, remainder) = self.0.split_units(unit);
+        let units = match (remainder * 2).cmp(&unit.as_attos_i128()) {
+            Ordering::Less => units,
+            Ordering::Greater => units + 1,
+            Ordering::Equal => units + units.rem_euclid(2),
+        };
+        Self(Duration::from_attos_i128(units * unit.as_attos_i128()))
+    }
+
+    /// Encode as 16 bytes whose lexicographic order is chronological order
+    ///
+    /// This is suitable as a key in a sorted store. The seconds and then the
+    /// attoseconds of the duration since the time standard epoch are each
+    /// written big-endian with their sign bit flipped, so negative values sort
+    /// before positive ones. Decode with `from_sortable_bytes`().
+    #[must_use]
+    #[allow(clippy::cast_sign_loss)]
+    pub const fn to_sortable_bytes(&self) -> [u8; 16] {
+        let secs = (self.0.secs as u64 ^ (1 << 63)).to_be_bytes();
+        let attos = (self.0.attos as u64 ^ (1 << 63)).to_be_bytes();
+        let mut bytes = [0; 16];
+        let mut i = 0;
+        while i < 8 {
+            bytes[i] = secs[i];
+            bytes[i + 8] = attos[i];
+            i += 1;
+        }
+        bytes
+    }
+
+    /// Decode the bytes produced by `to_sortable_bytes`()
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if the bytes could not have been produced
+    /// by `to_sortable_bytes`() (the attoseconds are out of range, or have a
+    /// different sign than the seconds), which can only happen if they were
+    /// corrupted.
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn from_sortable_bytes(bytes: [u8; 16]) -> Result<Self, Error> {
+        let mut secs = [0; 8];
+        let mut attos = [0; 8];
+        secs.copy_from_slice(&bytes[..8]);
+        attos.copy_from_slice(&bytes[8..]);
+        let secs = (u64::from_be_bytes(secs) ^ (1 << 63)) as i64;
+        let attos = (u64::from_be_bytes(attos) ^ (1 << 63)) as i64;
+
+        if attos.unsigned_abs() >= 1_000_000_000_000_000_000
+            || (secs < 0 && attos > 0)
+            || (secs > 0 && attos < 0)
+        {
+            return Err(Error::RangeError);
+        }
+        Ok(Self(Duration { secs, attos }))
+    }
+
+    /// Whether this is earlier than `other`
+    #[must_use]
+    pub fn is_before(&self, other: Self) -> bool {
+        *self < other
+    }
+
+    /// Whether this is later than `other`
+    #[must_use]
+    pub fn is_after(&self, other: Self) -> bool {
+        *self > other
+    }
+
+    /// Whether this is from `start` up to (but not including) `end`
+    ///
+    /// This is never true if `end` is not after `start`.
+    #[must_use]
+    pub fn is_between(&self, start: Self, end: Self) -> bool {
+        start <= *self && *self < end
+    }
+
+    /// Whether this and `other` are no further apart than `tolerance`
+    ///
+    /// This is for comparing the results of conversions which go through an
+    /// `f64`, such as `from_julian_day_f64`(), where `==` is too strict.
+    #[must_use]
+    pub fn approx_eq(&self, other: &Self, tolerance: Duration) -> bool {
+        // Instants too far apart to subtract are certainly not close
+        self.0
+            .checked_sub(other.0)
+            .and_then(|difference| difference.checked_abs())
+            .is_some_and(|difference| difference <= tolerance)
+    }
+
+    /// Describe this instant relative to `reference`, such as `3 hours ago` or
+    /// `in 2 days`
+    ///
+    /// Instants less than a minute either side of `reference` are `just now`.
+    /// See `Duration::humanize`() for how the difference is rounded.
+    #[must_use]
+    pub fn relative_to(&self, reference: Self) -> String {
+        // The difference between instants too far apart to subtract saturates
+        let difference = self
+            .0
+            .checked_sub(reference.0)
+            .unwrap_or(if *self > reference {
+                Duration::MAX
+            } else {
+                Duration::MIN
+            });
+        if difference
+            .checked_abs()
+            .is_some_and(|difference| difference < Duration::MINUTE)
+        {
+            "just now".to_owned()
+        } else if difference.is_negative() {
+            format!("{} ago", difference.humanize())
+        } else {
+            format!("in {}", difference.humanize())
+        }
+    }
+
+    /// Describe this instant relative to the current time, such as
+    /// `3 hours ago`
+    ///
+    /// See `relative_to`() for the wording. This has the same requirements as
+    /// `now`().
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if the system clock is set so far from the
+    /// UNIX epoch that it cannot be represented.
+    #[cfg(all(
+        feature = "std",
+        not(all(target_arch = "wasm32", target_os = "unknown"))
+    ))]
+    pub fn relative_to_now(&self) -> Result<String, Error> {
+        Ok(self.relative_to(Self::now()?))
+    }
+
+    /// The current `Instant`, from the system clock
+    ///
+    /// This converts `std::time::SystemTime::now`() the same way as
+    /// `TryFrom<SystemTime>`, including its correction for the leap seconds that
+    /// the system clock does not count.
+    ///
+    /// Requires the `std` feature, and is not available on
+    /// `wasm32-unknown-unknown`, which has no system clock.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if the system clock is set so far from the
+    /// UNIX epoch that it cannot be represented.
+    #[cfg(all(
+        feature = "std",
+        not(all(target_arch = "wasm32", target_os = "unknown"))
+    ))]
+    pub fn now() -> Result<Self, Error> {
+        TryFrom::try_from(std::time::SystemTime::now())
+    }
+
+    /// The current `Instant`, never going backward
+    ///
+    /// The system clock is read once, on the first call, and later calls add
+    /// the time elapsed since then on the monotonic `std::time::Instant` clock.
+    /// So unlike `now`(), the results never go backward when the system clock
+    /// is stepped, such as by NTP, which makes them suitable for timestamping a
+    /// stream of events. The price is that they drift from the system clock
+    /// (and any corrections made to it) over a long run, so their absolute
+    /// accuracy degrades the longer the program runs.
+    ///
+    /// This has the same requirements as `now`().
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if the system clock is set so far from the
+    /// UNIX epoch that it cannot be represented.
+    #[cfg(all(
+        feature = "std",
+        not(all(target_arch = "wasm32", target_os = "unknown"))
+    ))]
+    pub fn now_monotonic() -> Result<Self, Error> {
+        static ANCHOR: std::sync::OnceLock<(std::time::Instant, Instant)> =
+            std::sync::OnceLock::new();
+
+        let (monotonic, wall) = if let Some(anchor) = ANCHOR.get() {
+            *anchor
+        } else {
+            // If two threads race here, only one anchor is kept
+            let anchor = (std::time::Instant::now(), Self::now()?);
+            *ANCHOR.get_or_init(|| anchor)
+        };
+        let elapsed: Duration = TryFrom::try_from(monotonic.elapsed())?;
+        Ok(wall + elapsed)
     }
 }
 
@@ -150,14 +810,12 @@ impl Sub<Self> for Instant {
 }
 
 impl<C: Calendar, S: Standard> From<Instant> for DateTime<C, S> {
+    /// # Panics
+    ///
+    /// Panics if the year would be out of the range of an i32. See
+    /// `DateTime::try_from_instant`() for a non-panicking version.
     fn from(i: Instant) -> Self {
-        // Conversion between time standards
-        let dur: Duration = S::from_tt(i.0);
-
-        // NOTE: if we ever move the epoch that Durations are based on
-        //       away from TimeStandard, then replace `C::epoch()` below
-        //       with `C::epoch() - Epoch::TimeStandard.as_instant()`
-        Self::from_duration_from_epoch(dur - C::epoch().0)
+        Self::try_from_instant(i).expect("Instant is out of the range of DateTime")
     }
 }
 
@@ -168,11 +826,19 @@ impl<C: Calendar, S: Standard> From<DateTime<C, S>> for Instant {
         //       with `C::epoch() - Epoch::TimeStandard.as_instant()`
         let dur: Duration = dt.duration_from_epoch() + C::epoch().0;
 
+        // A leap second (:60) has the same duration from the epoch as the first
+        // second of the next minute, so locate it from the second before it.
+        if dt.second() == 60 {
+            let second = Duration::new(1, 0);
+            return Self(S::to_tt(dur - second) + second);
+        }
+
         // Conversion between time standards
         Self(S::to_tt(dur))
     }
 }
 
+#[cfg(feature = "std")]
 impl TryFrom<std::time::SystemTime> for Instant {
     type Error = Error;
 
@@ -192,32 +858,19 @@ impl TryFrom<std::time::SystemTime> for Instant {
             }
         };
 
-        let time_less_leaps = Epoch::Unix.as_instant() + since_unix_epoch_less_leaps;
-
-        // Add the missing leap seconds in two passes
-        let leap_seconds_elapsed_try1 = crate::standard::leap_seconds_elapsed(time_less_leaps);
-        let time_maybe_missing_one_leap =
-            time_less_leaps + Duration::new(leap_seconds_elapsed_try1, 0);
-
-        // Check that we didn't hit another leap second in the process
-        let leap_seconds_elapsed_try2 =
-            crate::standard::leap_seconds_elapsed(time_maybe_missing_one_leap);
-
-        Ok(if leap_seconds_elapsed_try2 > leap_seconds_elapsed_try1 {
-            time_maybe_missing_one_leap + Duration::new(1, 0)
-        } else {
-            time_maybe_missing_one_leap
-        })
+        Ok(Self::from_unix_duration(since_unix_epoch_less_leaps))
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::unreadable_literal)]
 mod test {
     use super::Instant;
     use crate::calendar::Gregorian;
     use crate::date_time::DateTime;
+    use crate::duration::Duration;
     use crate::epoch::Epoch;
-    use crate::standard::{Tai, Utc};
+    use crate::standard::{Tai, Tt, Utc};
 
     #[test]
     fn test_instant_julian_day_conversions() {
@@ -271,6 +924,173 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_instant_julian_day_str() {
+        crate::setup_logging();
+
+        // 0.123456789012345 days is exactly 10666.666570666608 seconds
+        assert_eq!(
+            Instant::from_julian_day_str("2451545.123456789012345").unwrap(),
+            Instant::from_julian_day_precise(2451545, 10666, 666_570_666_608_000_000).unwrap()
+        );
+        // f64 cannot hold this many digits
+        assert_ne!(
+            Instant::from_julian_day_f64("2451545.123456789012345".parse().unwrap()),
+            Instant::from_julian_day_str("2451545.123456789012345").unwrap()
+        );
+
+        assert_eq!(
+            Instant::from_julian_day_str("2451545").unwrap(),
+            Epoch::J2000_0.as_instant()
+        );
+        assert_eq!(
+            Instant::from_julian_day_str("2448349.0625").unwrap(),
+            Epoch::J1991_25.as_instant()
+        );
+        assert_eq!(
+            Instant::from_julian_day_str("-0.5").unwrap(),
+            Epoch::JulianPeriod.as_instant() - Duration::new(43200, 0)
+        );
+
+        assert_eq!(Epoch::J2000_0.as_instant().as_julian_day_str(), "2451545.0");
+        assert_eq!(
+            Epoch::J1991_25.as_instant().as_julian_day_str(),
+            "2448349.0625"
+        );
+        assert_eq!(
+            (Epoch::JulianPeriod.as_instant() - Duration::new(43200, 0)).as_julian_day_str(),
+            "-0.5"
+        );
+        for i in [
+            Epoch::J2000_0.as_instant() + Duration::new(0, 1),
+            Epoch::J2000_0.as_instant() - Duration::new(0, 1),
+            Epoch::JulianPeriod.as_instant() - Duration::new(86399, 999_999_999_999_999_999),
+            Instant::from_julian_day_precise(2451545, 10666, 666_570_666_608_000_001).unwrap(),
+            Instant::from_julian_day_str("-99999999999999.99999").unwrap(),
+        ] {
+            assert_eq!(
+                Instant::from_julian_day_str(&i.as_julian_day_str()).unwrap(),
+                i
+            );
+        }
+
+        assert!(Instant::from_julian_day_str("JD 2451545").is_err());
+        assert!(Instant::from_julian_day_str("2451545.").is_err());
+    }
+
+    #[test]
+    fn test_instant_julian_day_formatted() {
+        crate::setup_logging();
+
+        let jd0 = Epoch::JulianPeriod.as_instant();
+        assert_eq!(
+            Epoch::J2000_0.as_instant().as_julian_day_formatted(),
+            "JD 2451545"
+        );
+        assert_eq!(
+            Epoch::J1991_25.as_instant().as_julian_day_formatted(),
+            "JD 2448349.0625"
+        );
+        assert_eq!(jd0.as_julian_day_formatted(), "JD 0");
+        assert_eq!(
+            (jd0 - Duration::new(43200, 0)).as_julian_day_formatted(),
+            "JD -0.5"
+        );
+        assert_eq!(
+            (jd0 - Duration::new(1234 * 86400 + 43200, 0)).as_julian_day_formatted(),
+            "JD -1234.5"
+        );
+        assert_eq!(
+            (jd0 - Duration::new(1_000_000 * 86400 + 21600, 0)).as_julian_day_formatted(),
+            "JD -1000000.25"
+        );
+
+        // A fraction which rounds to a whole day carries into the day number
+        let almost = Epoch::J2000_0.as_instant() - Duration::new(0, 1);
+        assert_eq!(almost.as_julian_day_formatted(), "JD 2451545");
+    }
+
+    #[test]
+    fn test_besselian_years() {
+        crate::setup_logging();
+
+        let b1950 = Instant::from_besselian_year(1950.0);
+        assert_eq!(b1950, Epoch::B1950_0.as_instant());
+        assert!((b1950.as_besselian_year() - 1950.0).abs() < 1e-12);
+        assert!((b1950.as_julian_day_f64() - 2433282.4235).abs() < 1e-4);
+
+        let b1900 = Instant::from_besselian_year(1900.0);
+        assert!((b1900.as_julian_day_f64() - 2415020.31352).abs() < 1e-6);
+        assert!((b1900.as_besselian_year() - 1900.0).abs() < 1e-12);
+
+        let b = Epoch::J2000_0.as_instant().as_besselian_year();
+        assert!((b - 2000.0012775).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_seconds_past_j2000_tt() {
+        use float_cmp::ApproxEq;
+
+        crate::setup_logging();
+
+        let j2000 = Epoch::J2000_0.as_instant();
+        assert!(j2000.seconds_past_j2000_tt().approx_eq(0.0, (0.0, 1)));
+        assert_eq!(j2000.attoseconds_past_j2000_tt(), 0);
+        assert_eq!(Instant::from_seconds_past_j2000_tt(0.0), j2000);
+
+        let next_day = Instant::from_julian_day_str("2451546").unwrap();
+        assert!(next_day
+            .seconds_past_j2000_tt()
+            .approx_eq(86400.0, (0.0, 1)));
+        assert_eq!(
+            next_day.attoseconds_past_j2000_tt(),
+            86_400_000_000_000_000_000_000
+        );
+        assert_eq!(Instant::from_seconds_past_j2000_tt(86400.0), next_day);
+
+        let before = j2000 - Duration::new(1, 500_000_000_000_000_000);
+        assert!(before.seconds_past_j2000_tt().approx_eq(-1.5, (0.0, 1)));
+        assert_eq!(
+            before.attoseconds_past_j2000_tt(),
+            -1_500_000_000_000_000_000
+        );
+        assert_eq!(Instant::from_seconds_past_j2000_tt(-1.5), before);
+    }
+
+    #[test]
+    fn test_julian_years() {
+        crate::setup_logging();
+
+        assert_eq!(
+            Instant::from_julian_year(2000.0),
+            Epoch::J2000_0.as_instant()
+        );
+        assert_eq!(
+            Instant::from_julian_year(1991.25),
+            Epoch::J1991_25.as_instant()
+        );
+        assert_eq!(
+            Instant::from_julian_year(2100.0),
+            Epoch::J2100_0.as_instant()
+        );
+        assert!((Epoch::J2000_0.as_instant().as_julian_year() - 2000.0).abs() < 1e-12);
+        assert!((Epoch::J1991_25.as_instant().as_julian_year() - 1991.25).abs() < 1e-12);
+
+        let j2017_5 = Instant::from_julian_year(2017.5);
+        assert_eq!(
+            j2017_5,
+            Instant::from_julian_day_str("2457936.875").unwrap()
+        );
+        assert!((j2017_5.as_julian_year() - 2017.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_instant_default() {
+        crate::setup_logging();
+
+        assert_eq!(Instant::default(), Epoch::TimeStandard.as_instant());
+    }
+
     #[test]
     fn test_time_standard_conversions() {
         crate::setup_logging();
@@ -297,4 +1117,519 @@ mod test {
             DateTime::<Gregorian, Utc>::new(2000, 1, 1, 0, 0, 0, 0).unwrap()
         );
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_system_time_across_leap_second() {
+        crate::setup_logging();
+
+        // UNIX time does not count leap seconds, so this is exactly
+        // 2017-01-01 00:00:00 UTC, just after the last leap second
+        let unix_secs: u64 = 1_483_228_800;
+        let at = std::time::UNIX_EPOCH + std::time::Duration::from_secs(unix_secs);
+        let instant: Instant = TryFrom::try_from(at).unwrap();
+        let dt: DateTime<Gregorian, Utc> = From::from(instant);
+        assert_eq!(
+            dt,
+            DateTime::<Gregorian, Utc>::new(2017, 1, 1, 0, 0, 0, 0).unwrap()
+        );
+
+        let before = at - std::time::Duration::from_secs(1);
+        let instant_before: Instant = TryFrom::try_from(before).unwrap();
+        let dt: DateTime<Gregorian, Utc> = From::from(instant_before);
+        assert_eq!(
+            dt,
+            DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 59, 0).unwrap()
+        );
+
+        // Two SI seconds elapsed, including the leap second
+        assert_eq!(instant - instant_before, Duration::new(2, 0));
+    }
+
+    #[test]
+    fn test_instant_comparisons() {
+        crate::setup_logging();
+
+        let at = |secs| Instant(Duration::new(secs, 0));
+        assert!(at(1).is_before(at(2)));
+        assert!(!at(2).is_before(at(2)));
+        assert!(at(3).is_after(at(2)));
+        assert!(!at(2).is_after(at(2)));
+
+        assert!(at(10).is_between(at(10), at(20)));
+        assert!(at(15).is_between(at(10), at(20)));
+        assert!((at(20) - Duration::new(0, 1)).is_between(at(10), at(20)));
+        assert!(!at(20).is_between(at(10), at(20)));
+        assert!(!at(9).is_between(at(10), at(20)));
+        assert!(!at(10).is_between(at(10), at(10)));
+        assert!(!at(15).is_between(at(20), at(10)));
+    }
+
+    #[test]
+    fn test_tt_duration_since_epoch() {
+        crate::setup_logging();
+
+        assert!(Epoch::TimeStandard
+            .as_instant()
+            .tt_duration_since_epoch()
+            .is_zero());
+        let d = Duration::new(-123_456_789, -42);
+        let i = Instant::from_tt_duration_since_epoch(d);
+        assert_eq!(i.tt_duration_since_epoch(), d);
+        assert_eq!(i - Epoch::TimeStandard.as_instant(), d);
+        assert_eq!(
+            Epoch::J2000_0.as_instant().tt_duration_since_epoch(),
+            Epoch::J2000_0.duration_from(Epoch::TimeStandard)
+        );
+    }
+
+    #[test]
+    fn test_instant_approx_eq() {
+        crate::setup_logging();
+
+        let microsecond = Duration::new(0, 1_000_000_000_000);
+        let i = Instant::from_unix_timestamp_nanos(1_700_000_000_123_456_789);
+        let back = Instant::from_julian_day_f64(i.as_julian_day_f64());
+        assert_ne!(back, i);
+        assert!(!back.approx_eq(&i, Duration::new(0, 1_000_000_000)));
+        assert!(back.approx_eq(&i, microsecond * 100.0));
+        assert!(i.approx_eq(&back, microsecond * 100.0));
+        assert!(i.approx_eq(&i, Duration::ZERO));
+        assert!(!(i + Duration::new(0, 1)).approx_eq(&i, Duration::ZERO));
+
+        // The extremes are too far apart to subtract
+        assert!(!Instant::MAX.approx_eq(&Instant::MIN, Duration::MAX));
+        assert!(!Instant::MIN.approx_eq(&Instant::MAX, Duration::MAX));
+        assert!(Instant::MAX.approx_eq(&Instant::MAX, Duration::ZERO));
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "std",
+        not(all(target_arch = "wasm32", target_os = "unknown"))
+    ))]
+    fn test_now_monotonic() {
+        crate::setup_logging();
+
+        let mut last = Instant::now_monotonic().unwrap();
+        for _ in 0..10_000 {
+            let next = Instant::now_monotonic().unwrap();
+            assert!(next >= last);
+            last = next;
+        }
+        let difference = Instant::now().unwrap() - last;
+        assert!(difference.abs() < Duration::new(60, 0));
+    }
+
+    #[test]
+    fn test_relative_to() {
+        crate::setup_logging();
+
+        let now = Instant::from_unix_timestamp(1_700_000_000);
+        assert_eq!(now.relative_to(now), "just now");
+        assert_eq!((now - Duration::new(59, 0)).relative_to(now), "just now");
+        assert_eq!((now + Duration::new(59, 0)).relative_to(now), "just now");
+        assert_eq!((now - Duration::MINUTE).relative_to(now), "1 minute ago");
+        assert_eq!(
+            (now - Duration::new(3 * 3600, 0)).relative_to(now),
+            "3 hours ago"
+        );
+        assert_eq!(
+            (now + Duration::new(2 * 86400, 0)).relative_to(now),
+            "in 2 days"
+        );
+        assert_eq!((now + Duration::WEEK).relative_to(now), "in 1 week");
+
+        let dt = DateTime::<Gregorian, Utc>::new(2023, 11, 15, 1, 13, 20, 0).unwrap();
+        assert_eq!(Instant::from(dt), now + Duration::new(3 * 3600, 0));
+        assert_eq!(dt.relative_to(now), "in 3 hours");
+
+        // The extremes are too far apart to subtract
+        assert!(Instant::MIN
+            .relative_to(Instant::MAX)
+            .ends_with(" weeks ago"));
+        assert!(Instant::MAX.relative_to(Instant::MIN).starts_with("in "));
+        assert!(Instant::MIN.relative_to(now).ends_with(" weeks ago"));
+        assert_eq!(Instant::MAX.relative_to(Instant::MAX), "just now");
+    }
+
+    #[test]
+    fn test_unix_timestamps() {
+        crate::setup_logging();
+
+        let unix = Epoch::Unix.as_instant();
+        assert_eq!(Instant::from_unix_timestamp(0), unix);
+        assert_eq!(unix.as_unix_timestamp(), 0);
+        assert_eq!(unix.as_unix_timestamp_nanos(), 0);
+        assert!(unix.as_unix_timestamp_f64().abs() < 1e-9);
+
+        // After the last leap second, 37 leap seconds (27 since 1972 and 10
+        // before) separate TAI from UTC
+        let y2017 = Instant::from_unix_timestamp(1_483_228_800);
+        assert_eq!(
+            y2017,
+            From::from(DateTime::<Gregorian, Utc>::new(2017, 1, 1, 0, 0, 0, 0).unwrap())
+        );
+        let tai: DateTime<Gregorian, Tai> = From::from(y2017);
+        assert_eq!(tai.time(), (0, 0, 37, 0));
+        assert_eq!(y2017.as_unix_timestamp(), 1_483_228_800);
+        assert_eq!(
+            y2017 - Instant::from_unix_timestamp(1_483_228_799),
+            Duration::new(2, 0)
+        );
+
+        // During the leap second, the following second repeats
+        let leap = y2017 - Duration::new(0, 500_000_000_000_000_000);
+        assert_eq!(leap.as_unix_timestamp(), 1_483_228_800);
+
+        // Nanoseconds and fractions, including before 1970
+        let i = Instant::from_unix_timestamp_nanos(1_483_228_800_250_000_000);
+        assert_eq!(i - y2017, Duration::new(0, 250_000_000_000_000_000));
+        assert_eq!(i.as_unix_timestamp_nanos(), 1_483_228_800_250_000_000);
+        assert!((i.as_unix_timestamp_f64() - 1_483_228_800.25).abs() < 1e-6);
+        let before = Instant::from_unix_timestamp_nanos(-1_500_000_000);
+        assert_eq!(before.as_unix_timestamp(), -2);
+        assert_eq!(before.as_unix_timestamp_nanos(), -1_500_000_000);
+        assert_eq!(unix - before, Duration::new(1, 500_000_000_000_000_000));
+
+        // Agrees with SystemTime
+        #[cfg(feature = "std")]
+        {
+            let at = std::time::UNIX_EPOCH + std::time::Duration::new(1_483_228_800, 250_000_000);
+            let from_system: Instant = TryFrom::try_from(at).unwrap();
+            assert_eq!(from_system, i);
+        }
+    }
+
+    #[test]
+    fn test_ntp_dates() {
+        crate::setup_logging();
+
+        // The UNIX epoch
+        let unix = Instant::from_ntp_date(2_208_988_800, 0);
+        assert_eq!(unix, Epoch::Unix.as_instant());
+        assert_eq!(unix.as_ntp_date(), (2_208_988_800, 0));
+
+        // The NTP prime epoch
+        assert_eq!(Instant::from_ntp_date(0, 0), Epoch::Ntp.as_instant());
+        assert_eq!(Epoch::Ntp.as_instant().as_ntp_date(), (0, 0));
+
+        // 1977, just after a leap second
+        let y1977 = Instant::from_ntp_date(2_429_913_600, 0);
+        assert_eq!(y1977, Epoch::Y1977.as_instant());
+        assert_eq!(
+            y1977 - Instant::from_ntp_date(2_429_913_599, 0),
+            Duration::new(2, 0)
+        );
+
+        // Fractions round trip
+        for fraction in [1, 0x8000_0000, 0xFFFF_FFFF] {
+            let i = Instant::from_ntp_date(3_692_217_600, fraction);
+            assert_eq!(i.as_ntp_date(), (3_692_217_600, fraction));
+        }
+        assert_eq!(
+            Instant::from_ntp_date(3_692_217_600, 0x8000_0000)
+                - Instant::from_ntp_date(3_692_217_600, 0),
+            Duration::new(0, 500_000_000_000_000_000)
+        );
+
+        // Era 1 begins at the 32-bit rollover
+        let rollover = Instant::from_ntp_era_date(1, 0, 0);
+        assert_eq!(
+            rollover,
+            From::from(DateTime::<Gregorian, Utc>::new(2036, 2, 7, 6, 28, 16, 0).unwrap())
+        );
+        assert_eq!(rollover, Instant::from_ntp_date(1 << 32, 0));
+        assert_eq!(rollover.as_ntp_era_date(), (1, 0, 0));
+        assert_eq!(rollover.as_ntp_date(), (1 << 32, 0));
+
+        // Before the prime epoch
+        let before = Instant::from_ntp_era_date(-1, 0xFFFF_FFFF, 0x8000_0000);
+        assert_eq!(before.as_ntp_era_date(), (-1, 0xFFFF_FFFF, 0x8000_0000));
+        assert_eq!(
+            Instant::from_ntp_era_date(0, 0, 0) - before,
+            Duration::new(0, 500_000_000_000_000_000)
+        );
+    }
+
+    #[test]
+    fn test_instant_rounding() {
+        crate::setup_logging();
+
+        let second = Duration::new(1, 0);
+        let at = |secs: i64, attos: i64| Instant(Duration::new(secs, attos));
+        let half = 500_000_000_000_000_000;
+
+        assert_eq!(at(0, half).round_to(second), at(0, 0));
+        assert_eq!(at(1, half).round_to(second), at(2, 0));
+        assert_eq!(at(1, half + 1).round_to(second), at(2, 0));
+        assert_eq!(at(1, half - 1).round_to(second), at(1, 0));
+        assert_eq!(at(0, -half).round_to(second), at(0, 0));
+        assert_eq!(at(-1, -half).round_to(second), at(-2, 0));
+
+        assert_eq!(at(1, 1).floor_to(second), at(1, 0));
+        assert_eq!(at(1, 1).ceil_to(second), at(2, 0));
+        assert_eq!(at(-1, -1).floor_to(second), at(-2, 0));
+        assert_eq!(at(-1, -1).ceil_to(second), at(-1, 0));
+        assert_eq!(at(-1, 0).ceil_to(second), at(-1, 0));
+
+        let tenth = Duration::new(0, 100_000_000_000_000_000);
+        assert_eq!(
+            at(5, 250_000_000_000_000_000).round_to(tenth),
+            at(5, 200_000_000_000_000_000)
+        );
+        assert_eq!(
+            at(5, 350_000_000_000_000_000).round_to(tenth),
+            at(5, 400_000_000_000_000_000)
+        );
+
+        // Multiples are measured from Epoch::TimeStandard
+        let minute = Duration::new(60, 0);
+        assert_eq!(
+            Epoch::Y1977.as_instant().round_to(minute),
+            Epoch::TimeStandard.as_instant()
+        );
+    }
+
+    #[test]
+    fn test_instant_midpoint_and_lerp() {
+        crate::setup_logging();
+
+        // A one attosecond span rounds toward the earlier
+        let a = Instant(Duration::new(-1, 0));
+        let b = a + Duration::new(0, 1);
+        assert_eq!(a.midpoint(&b), a);
+        assert_eq!(b.midpoint(&a), a);
+        let c = b + Duration::new(0, 1);
+        assert_eq!(a.midpoint(&c), b);
+        assert_eq!(a.midpoint(&a), a);
+
+        // A multi-century span
+        let start: Instant =
+            From::from(DateTime::<Gregorian, Tt>::new(1800, 1, 1, 0, 0, 0, 0).unwrap());
+        let end: Instant =
+            From::from(DateTime::<Gregorian, Tt>::new(2200, 1, 1, 0, 0, 0, 0).unwrap());
+        let mid: Instant =
+            From::from(DateTime::<Gregorian, Tt>::new(2000, 1, 1, 12, 0, 0, 0).unwrap());
+        assert_eq!(start.midpoint(&end), mid);
+        assert_eq!(end.midpoint(&start), mid);
+        assert_eq!(start.lerp(&end, 0.5), mid);
+        assert_eq!(start.lerp(&end, 0.0), start);
+        assert_eq!(start.lerp(&end, 1.0), end);
+        assert_eq!(end.lerp(&start, 0.25), start.lerp(&end, 0.75));
+
+        // The extremes do not overflow
+        let min = Instant(Duration::new(i64::MIN, -999_999_999_999_999_999));
+        let max = Instant(Duration::new(i64::MAX, 999_999_999_999_999_999));
+        assert_eq!(
+            min.midpoint(&max),
+            Instant(Duration::new(0, -500_000_000_000_000_000))
+        );
+    }
+
+    #[test]
+    fn test_checked_duration_since() {
+        crate::setup_logging();
+
+        let a = Epoch::JulianPeriod.as_instant();
+        let b = Epoch::J2000_0.as_instant();
+        assert_eq!(b.checked_duration_since(&a), Some(b - a));
+        assert_eq!(a.checked_duration_since(&b), Some(a - b));
+
+        let min = Instant(Duration::new(i64::MIN, -999_999_999_999_999_999));
+        let max = Instant(Duration::new(i64::MAX, 999_999_999_999_999_999));
+        let zero = Instant(Duration::new(0, 0));
+        assert_eq!(max.checked_duration_since(&zero), Some(max.0));
+        assert_eq!(min.checked_duration_since(&zero), Some(min.0));
+        assert_eq!(max.checked_duration_since(&max), Some(Duration::new(0, 0)));
+        assert_eq!(max.checked_duration_since(&min), None);
+        assert_eq!(min.checked_duration_since(&max), None);
+        assert_eq!(zero.checked_duration_since(&min), None);
+        assert_eq!(
+            (zero + Duration::new(-1, 0)).checked_duration_since(&min),
+            Some(Duration::new(i64::MAX, 999_999_999_999_999_999))
+        );
+    }
+
+    #[test]
+    fn test_instant_fraction_between() {
+        use float_cmp::ApproxEq;
+        crate::setup_logging();
+
+        let start: Instant =
+            From::from(DateTime::<Gregorian, Tt>::new(1800, 1, 1, 0, 0, 0, 0).unwrap());
+        let end: Instant =
+            From::from(DateTime::<Gregorian, Tt>::new(2200, 1, 1, 0, 0, 0, 0).unwrap());
+        let mid = start.midpoint(&end);
+
+        assert!(start
+            .fraction_between(&start, &end, true)
+            .approx_eq(0.0, (0.0, 1)));
+        assert!(end
+            .fraction_between(&start, &end, true)
+            .approx_eq(1.0, (0.0, 1)));
+        assert!(mid
+            .fraction_between(&start, &end, true)
+            .approx_eq(0.5, (0.0, 1)));
+        assert!(mid
+            .fraction_between(&end, &start, true)
+            .approx_eq(0.5, (0.0, 1)));
+        let t = 0.3;
+        let back = start.lerp(&end, t).fraction_between(&start, &end, false);
+        assert!((back - t).abs() < 1e-12);
+
+        // Outside of the span
+        let before = start - (end - start);
+        assert!(before
+            .fraction_between(&start, &end, false)
+            .approx_eq(-1.0, (0.0, 1)));
+        assert!(before
+            .fraction_between(&start, &end, true)
+            .approx_eq(0.0, (0.0, 1)));
+        let after = end + (end - start);
+        assert!(after
+            .fraction_between(&start, &end, false)
+            .approx_eq(2.0, (0.0, 1)));
+        assert!(after
+            .fraction_between(&start, &end, true)
+            .approx_eq(1.0, (0.0, 1)));
+
+        // An empty span
+        assert!(end
+            .fraction_between(&start, &start, false)
+            .approx_eq(0.0, (0.0, 1)));
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_wrap)]
+    fn test_sortable_bytes() {
+        crate::setup_logging();
+
+        // A simple xorshift generator, so the test is repeatable
+        let mut state: u64 = 0x2545F4914F6CDD1D;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        // Mostly small values, so that pairs often share their seconds
+        let mut random_instant = || {
+            let secs = match next() % 3 {
+                0 => next() as i64,
+                1 => (next() % 5) as i64 - 2,
+                _ => (next() % 2_000_000_000) as i64 - 1_000_000_000,
+            };
+            let attos = (next() % 1_999_999_999_999_999_999) as i64 - 999_999_999_999_999_999;
+            Instant(Duration::new(secs, attos))
+        };
+
+        for _ in 0..10000 {
+            let a = random_instant();
+            let b = random_instant();
+            assert_eq!(a.cmp(&b), a.to_sortable_bytes().cmp(&b.to_sortable_bytes()));
+            assert_eq!(
+                Instant::from_sortable_bytes(a.to_sortable_bytes()).unwrap(),
+                a
+            );
+        }
+
+        let zero = Instant(Duration::ZERO);
+        let tiny = Instant(Duration::new(0, 1));
+        let negative_tiny = Instant(Duration::new(0, -1));
+        assert!(negative_tiny.to_sortable_bytes() < zero.to_sortable_bytes());
+        assert!(zero.to_sortable_bytes() < tiny.to_sortable_bytes());
+
+        // Corrupt encodings
+        let mut bytes = Instant(Duration::new(5, 0)).to_sortable_bytes();
+        bytes[8] = 0; // a negative attosecond count
+        assert!(Instant::from_sortable_bytes(bytes).is_err());
+        assert!(Instant::from_sortable_bytes([0xFF; 16]).is_err());
+    }
+
+    #[test]
+    fn test_instant_min_max_clamp() {
+        crate::setup_logging();
+
+        // The leap second at the end of 2016, and the seconds around it
+        let after: Instant =
+            From::from(DateTime::<Gregorian, Utc>::new(2017, 1, 1, 0, 0, 0, 0).unwrap());
+        let leap = after - Duration::new(1, 0);
+        let before = after - Duration::new(2, 0);
+
+        assert_eq!(leap.min(after), leap);
+        assert_eq!(leap.max(after), after);
+        assert_eq!(before.max(leap), leap);
+        assert_eq!(leap.clamp(before, after), leap);
+        assert_eq!(before.clamp(leap, after), leap);
+        assert_eq!(after.clamp(before, leap), leap);
+    }
+
+    #[test]
+    fn test_instant_clamp_to_interval() {
+        use crate::error::Error;
+        use crate::interval::Interval;
+
+        crate::setup_logging();
+
+        let at = |secs| Instant(Duration::new(secs, 0));
+        let window = Interval::new(at(100), at(200)).unwrap();
+
+        // In range
+        assert_eq!(at(150).clamp_to(&window), at(150));
+        assert_eq!(at(100).clamp_to(&window), at(100));
+        assert_eq!(at(150).require_within(&window).unwrap(), at(150));
+
+        // Below the start
+        assert_eq!(at(-5).clamp_to(&window), at(100));
+        assert!(matches!(
+            at(99).require_within(&window),
+            Err(Error::RangeError)
+        ));
+
+        // At or above the end, which is not in the interval
+        let last = at(200) - Duration::new(0, 1);
+        assert_eq!(at(200).clamp_to(&window), last);
+        assert_eq!(at(1000).clamp_to(&window), last);
+        assert!(window.contains(at(1000).clamp_to(&window)));
+        assert!(matches!(
+            at(200).require_within(&window),
+            Err(Error::RangeError)
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "range must not be empty")]
+    fn test_instant_clamp_to_empty_interval() {
+        crate::setup_logging();
+
+        let at = Instant(Duration::new(100, 0));
+        let _ = at.clamp_to(&crate::interval::Interval::new(at, at).unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_instant_now() {
+        crate::setup_logging();
+
+        let now = Instant::now().unwrap();
+        let now_utc = DateTime::<Gregorian, Utc>::now().unwrap();
+        let unix_secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+
+        // UNIX time ignores leap seconds, just like UTC date labels do
+        let unix_epoch = DateTime::<Gregorian, Utc>::new(1970, 1, 1, 0, 0, 0, 0).unwrap();
+        let (h, m, s, _) = now_utc.time();
+        let label_secs = (now_utc.day_number() - unix_epoch.day_number()) * 86400
+            + i64::from(h) * 3600
+            + i64::from(m) * 60
+            + i64::from(s);
+        assert!((label_secs - i64::try_from(unix_secs).unwrap()).abs() < 5);
+
+        let since = Instant::from(now_utc) - now;
+        assert!(since >= Duration::new(0, 0));
+        assert!(since < Duration::new(5, 0));
+    }
 }
@@ -0,0 +1,220 @@
+use crate::duration::Duration;
+use crate::error::Error;
+use crate::instant::Instant;
+
+/// A span of time from a `start` `Instant` up to (but not including) an `end`
+/// `Instant`
+///
+/// The start is never after the end. An interval whose start and end are the
+/// same is empty: it contains no instants, and does not overlap anything.
+///
+/// ```
+/// # use astrotime::{Duration, Instant, Interval};
+/// let start = Instant::from_julian_day_f64(2451545.0);
+/// let night = Interval::new(start, start + Duration::new(8 * 3600, 0)).unwrap();
+/// let hours: Vec<Interval> = night.steps(Duration::HOUR).collect();
+/// assert_eq!(hours.len(), 8);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval {
+    start: Instant,
+    end: Instant,
+}
+
+impl Interval {
+    /// Create the interval from `start` up to `end`
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if `start` is after `end`.
+    pub fn new(start: Instant, end: Instant) -> Result<Self, Error> {
+        if start > end {
+            return Err(Error::RangeError);
+        }
+        Ok(Self { start, end })
+    }
+
+    /// The first instant of the interval
+    #[must_use]
+    pub const fn start(&self) -> Instant {
+        self.start
+    }
+
+    /// The instant just after the interval
+    #[must_use]
+    pub const fn end(&self) -> Instant {
+        self.end
+    }
+
+    /// The length of the interval
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+
+    /// Whether the interval is empty, with its start and end the same
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Whether `i` is within the interval
+    ///
+    /// The start is within the interval, but the end is not.
+    #[must_use]
+    pub fn contains(&self, i: Instant) -> bool {
+        self.start <= i && i < self.end
+    }
+
+    /// Whether this and `other` have any instants in common
+    ///
+    /// Intervals which only touch, with one ending where the other starts, do
+    /// not overlap.
+    #[must_use]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start.max(other.start) < self.end.min(other.end)
+    }
+
+    /// The instants which are in both this and `other`, or `None` if they do
+    /// not overlap
+    #[must_use]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        if !self.overlaps(other) {
+            return None;
+        }
+        Some(Self {
+            start: self.start.max(other.start),
+            end: self.end.min(other.end),
+        })
+    }
+
+    /// The instants which are in either this or `other`, or `None` if that
+    /// would leave a gap
+    ///
+    /// Intervals which overlap or touch can be joined.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        if self.start > other.end || other.start > self.end {
+            return None;
+        }
+        Some(Self {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        })
+    }
+
+    /// Split into consecutive intervals of `step`, in order
+    ///
+    /// The last interval is shorter than `step` if the duration is not a
+    /// multiple of it. An empty interval gives none.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not positive.
+    #[must_use]
+    pub fn steps(&self, step: Duration) -> Steps {
+        assert!(step.is_positive(), "step must be positive");
+        Steps {
+            remaining: *self,
+            step,
+        }
+    }
+}
+
+/// An iterator over consecutive sub-intervals of an `Interval`
+///
+/// See `Interval::steps`().
+#[derive(Debug, Clone)]
+pub struct Steps {
+    remaining: Interval,
+    step: Duration,
+}
+
+impl Iterator for Steps {
+    type Item = Interval;
+
+    fn next(&mut self) -> Option<Interval> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+        let start = self.remaining.start;
+        let end = if self.remaining.duration() > self.step {
+            start + self.step
+        } else {
+            self.remaining.end
+        };
+        self.remaining.start = end;
+        Some(Interval { start, end })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Interval;
+    use crate::duration::Duration;
+    use crate::instant::Instant;
+
+    #[test]
+    fn test_interval() {
+        crate::setup_logging();
+
+        let at = |secs| Instant(Duration::new(secs, 0));
+        let interval = |start, end| Interval::new(at(start), at(end)).unwrap();
+
+        assert!(Interval::new(at(1), at(0)).is_err());
+        let base = interval(0, 10);
+        assert_eq!(base.duration(), Duration::new(10, 0));
+        assert!(base.contains(at(0)));
+        assert!(base.contains(at(10) - Duration::new(0, 1)));
+        assert!(!base.contains(at(10)));
+        assert!(!base.contains(at(-1)));
+
+        // Overlapping
+        let overlapping = interval(5, 15);
+        assert!(base.overlaps(&overlapping) && overlapping.overlaps(&base));
+        assert_eq!(base.intersection(&overlapping), Some(interval(5, 10)));
+        assert_eq!(base.union(&overlapping), Some(interval(0, 15)));
+        let inner = interval(2, 3);
+        assert_eq!(base.intersection(&inner), Some(inner));
+        assert_eq!(inner.union(&base), Some(base));
+
+        // Touching
+        let touching = interval(10, 20);
+        assert!(!base.overlaps(&touching));
+        assert_eq!(base.intersection(&touching), None);
+        assert_eq!(touching.union(&base), Some(interval(0, 20)));
+
+        // Disjoint
+        let disjoint = interval(11, 20);
+        assert!(!base.overlaps(&disjoint));
+        assert_eq!(base.intersection(&disjoint), None);
+        assert_eq!(base.union(&disjoint), None);
+
+        // Empty
+        let empty = interval(5, 5);
+        assert!(empty.is_empty());
+        assert!(!empty.contains(at(5)));
+        assert!(!base.overlaps(&empty));
+    }
+
+    #[test]
+    fn test_interval_steps() {
+        crate::setup_logging();
+
+        let start = Instant(Duration::new(100, 0));
+        let interval = Interval::new(start, start + Duration::new(10, 1)).unwrap();
+        let steps: Vec<Interval> = interval.steps(Duration::new(2, 0)).collect();
+        assert_eq!(steps.len(), 6);
+        assert_eq!(steps[0].start(), interval.start());
+        assert_eq!(steps[5].end(), interval.end());
+        assert_eq!(steps[5].duration(), Duration::new(0, 1));
+        for pair in steps.windows(2) {
+            assert_eq!(pair[0].end(), pair[1].start());
+            assert_eq!(pair[0].duration(), Duration::new(2, 0));
+        }
+
+        assert_eq!(interval.steps(Duration::new(10, 1)).count(), 1);
+        let empty = Interval::new(start, start).unwrap();
+        assert_eq!(empty.steps(Duration::new(1, 0)).count(), 0);
+    }
+}
@@ -0,0 +1,565 @@
+// ISO 8601 parsing helpers
+
+use alloc::borrow::ToOwned;
+use alloc::format;
+
+use crate::duration::Duration;
+use crate::error::Error;
+
+// The fields of a parsed ISO 8601 date and time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Parsed {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub attosecond: u64,
+
+    // Offset of the local time ahead of the standard, in seconds (e.g. +05:30
+    // is 19800). 'Z' and a missing designator are both zero.
+    pub offset: i64,
+
+    // The day of the year, for an ordinal date such as 2023-181. The month and
+    // day are then both 1, and must be worked out from this in the calendar.
+    pub day_of_year: Option<u16>,
+}
+
+// A simple cursor over the input
+pub struct Cursor<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub const fn new(input: &'a str) -> Self {
+        Self {
+            input: input.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    pub const fn at_end(&self) -> bool {
+        self.pos >= self.input.len()
+    }
+
+    pub fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).copied()
+    }
+
+    // Consume the byte if it is one of `options`
+    pub fn eat(&mut self, options: &[u8]) -> Option<u8> {
+        match self.peek() {
+            Some(c) if options.contains(&c) => {
+                self.pos += 1;
+                Some(c)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn expect(&mut self, c: u8) -> Result<(), Error> {
+        if self.eat(&[c]).is_some() {
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{}'", char::from(c))))
+        }
+    }
+
+    // Consume a run of digits, returning them
+    pub fn digits(&mut self) -> &'a [u8] {
+        let start = self.pos;
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.pos += 1;
+        }
+        &self.input[start..self.pos]
+    }
+
+    // Consume exactly `n` digits as a number
+    pub fn fixed(&mut self, n: usize, what: &str) -> Result<u8, Error> {
+        let start = self.pos;
+        let digits = self.digits();
+        if digits.len() == n {
+            Ok(u8::try_from(number(digits)?).map_err(|_| Error::RangeError)?)
+        } else {
+            self.pos = start;
+            Err(self.error(&format!("expected {n} digit {what}")))
+        }
+    }
+
+    // Consume up to `n` digits (but at least one) as a number
+    pub fn up_to(&mut self, n: usize, what: &str) -> Result<i64, Error> {
+        let start = self.pos;
+        while self.pos - start < n && matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.pos += 1;
+        }
+        if self.pos == start {
+            return Err(self.error(&format!("expected {what}")));
+        }
+        number(&self.input[start..self.pos])
+    }
+
+    // Consume `s` if the input continues with it
+    pub fn eat_str(&mut self, s: &str) -> bool {
+        if self.input[self.pos..].starts_with(s.as_bytes()) {
+            self.pos += s.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn error(&self, msg: &str) -> Error {
+        Error::ParseError(format!("{msg} at position {}", self.pos))
+    }
+}
+
+// Convert a run of ascii digits into a number
+pub fn number(digits: &[u8]) -> Result<i64, Error> {
+    digits.iter().try_fold(0_i64, |acc, d| {
+        acc.checked_mul(10)
+            .and_then(|acc| acc.checked_add(i64::from(d - b'0')))
+            .ok_or(Error::RangeError)
+    })
+}
+
+// Convert a run of ascii digits after a decimal point into attoseconds
+pub fn fraction_to_attos(digits: &[u8]) -> Result<u64, Error> {
+    if digits.is_empty() || digits.len() > 18 {
+        return Err(Error::ParseError(
+            "fractional seconds must have from 1 to 18 digits".to_owned(),
+        ));
+    }
+    let mut attos: u64 = 0;
+    for i in 0..18 {
+        attos *= 10;
+        if let Some(d) = digits.get(i) {
+            attos += u64::from(d - b'0');
+        }
+    }
+    Ok(attos)
+}
+
+// Parse an ISO 8601 calendar or ordinal date with optional time and optional
+// UTC offset, such as:
+//   2000-01-01
+//   2000-182T12:00:00Z
+//   2000-01-01T12:00:00Z
+//   2000-01-01T12:00:00.5+05:30
+//   -0044-03-15T12:00:00
+//   +12345-01-01T00:00:00
+//
+// Years may have a sign and must have at least 4 digits.  Fractional seconds
+// may have up to 18 digits, separated by either '.' or ','.  A space is
+// accepted in place of the 'T' (as RFC 3339 allows).
+pub fn parse_datetime(s: &str) -> Result<Parsed, Error> {
+    parse_datetime_with_pivot(s, None)
+}
+
+// As parse_datetime(), but if `pivot` is given, an unsigned year of exactly 2
+// digits is also accepted. It is taken as the year from `pivot` up to 99 years
+// after it that ends in those digits.
+pub fn parse_datetime_with_pivot(s: &str, pivot: Option<i32>) -> Result<Parsed, Error> {
+    let mut c = Cursor::new(s);
+
+    let sign = c.eat(b"+-");
+    let digits = c.digits();
+    let mut year = match pivot {
+        Some(pivot) if sign.is_none() && digits.len() == 2 => {
+            let pivot = i64::from(pivot);
+            pivot + (number(digits)? - pivot).rem_euclid(100)
+        }
+        _ => {
+            if digits.len() < 4 {
+                return Err(c.error("expected a year of at least 4 digits"));
+            }
+            number(digits)?
+        }
+    };
+    if sign == Some(b'-') {
+        year = -year;
+    }
+    let year = i32::try_from(year).map_err(|_| Error::RangeError)?;
+
+    c.expect(b'-')?;
+    let field = c.digits();
+    let (month, day, day_of_year) = match field.len() {
+        2 => {
+            let month = u8::try_from(number(field)?).map_err(|_| Error::RangeError)?;
+            c.expect(b'-')?;
+            (month, c.fixed(2, "day")?, None)
+        }
+        3 => {
+            let day_of_year = u16::try_from(number(field)?).map_err(|_| Error::RangeError)?;
+            (1, 1, Some(day_of_year))
+        }
+        _ => return Err(c.error("expected 2 digit month or 3 digit day of the year")),
+    };
+
+    let mut parsed = Parsed {
+        year,
+        month,
+        day,
+        hour: 0,
+        minute: 0,
+        second: 0,
+        attosecond: 0,
+        offset: 0,
+        day_of_year,
+    };
+
+    if c.at_end() {
+        return Ok(parsed);
+    }
+
+    if c.eat(b"Tt ").is_none() {
+        return Err(c.error("expected 'T'"));
+    }
+    parse_time(&mut c, &mut parsed)?;
+
+    if !c.at_end() {
+        return Err(c.error("unexpected trailing characters"));
+    }
+
+    Ok(parsed)
+}
+
+// Parse HH:MM:SS[.fraction][offset] into `parsed`
+fn parse_time(c: &mut Cursor<'_>, parsed: &mut Parsed) -> Result<(), Error> {
+    parsed.hour = c.fixed(2, "hour")?;
+    c.expect(b':')?;
+    parsed.minute = c.fixed(2, "minute")?;
+    c.expect(b':')?;
+    parsed.second = c.fixed(2, "second")?;
+    if c.eat(b".,").is_some() {
+        parsed.attosecond = fraction_to_attos(c.digits())?;
+    }
+
+    match c.eat(b"Zz+-") {
+        None | Some(b'Z' | b'z') => {}
+        Some(sign) => {
+            let hours = c.fixed(2, "offset hour")?;
+            c.expect(b':')?;
+            let minutes = c.fixed(2, "offset minute")?;
+            if hours > 23 || minutes > 59 {
+                return Err(Error::RangeError);
+            }
+            let offset = i64::from(hours) * 3600 + i64::from(minutes) * 60;
+            parsed.offset = if sign == b'-' { -offset } else { offset };
+        }
+    }
+
+    Ok(())
+}
+
+// Parse an ISO 8601 duration such as "P1DT2H1M1.5S" or "-PT30S".
+//
+// Only weeks (W), days (D), hours (H), minutes (M) and seconds (S) are
+// accepted.  Years and months are rejected because they do not have a fixed
+// length. Only the seconds may have a fraction.
+pub fn parse_duration(s: &str) -> Result<Duration, Error> {
+    let mut c = Cursor::new(s);
+
+    let negative = c.eat(b"+-") == Some(b'-');
+    if c.eat(b"Pp").is_none() {
+        return Err(c.error("expected 'P'"));
+    }
+
+    let mut secs: i64 = 0;
+    let mut attos: u64 = 0;
+    let mut in_time = false;
+
+    // Each designator may only follow those before it
+    let mut last = 0;
+    while !c.at_end() {
+        if !in_time && c.eat(b"Tt").is_some() {
+            in_time = true;
+            if c.at_end() {
+                return Err(c.error("expected a time component after 'T'"));
+            }
+            continue;
+        }
+
+        let digits = c.digits();
+        if digits.is_empty() {
+            return Err(c.error("expected a number"));
+        }
+        let value = number(digits)?;
+
+        let fraction = if c.eat(b".,").is_some() {
+            Some(fraction_to_attos(c.digits())?)
+        } else {
+            None
+        };
+
+        let (order, multiplier) = match (in_time, c.eat(b"WDHMSwdhms")) {
+            (false, Some(b'W' | b'w')) => (1, 7 * 86400),
+            (false, Some(b'D' | b'd')) => (2, 86400),
+            (true, Some(b'H' | b'h')) => (3, 3600),
+            (true, Some(b'M' | b'm')) => (4, 60),
+            (true, Some(b'S' | b's')) => (5, 1),
+            _ => return Err(c.error("expected a valid designator")),
+        };
+        if order <= last {
+            return Err(c.error("designator out of order"));
+        }
+        last = order;
+
+        if let Some(f) = fraction {
+            if multiplier != 1 {
+                return Err(c.error("only seconds may have a fraction"));
+            }
+            attos = f;
+        }
+
+        secs = value
+            .checked_mul(multiplier)
+            .and_then(|v| secs.checked_add(v))
+            .ok_or(Error::RangeError)?;
+    }
+
+    let attos = i64::try_from(attos).map_err(|_| Error::RangeError)?;
+    let d = Duration::new(secs, attos);
+    Ok(if negative { -d } else { d })
+}
+
+// Parse a decimal number of seconds such as "86401.000000000000000120" or
+// "-1.5" exactly (without floating point)
+#[cfg_attr(not(feature = "serde"), allow(dead_code))]
+pub fn parse_decimal_seconds(s: &str) -> Result<Duration, Error> {
+    let mut c = Cursor::new(s);
+
+    let negative = c.eat(b"+-") == Some(b'-');
+    let digits = c.digits();
+    if digits.is_empty() {
+        return Err(c.error("expected a number"));
+    }
+    let secs = number(digits)?;
+    let attos = if c.eat(b".").is_some() {
+        fraction_to_attos(c.digits())?
+    } else {
+        0
+    };
+    if !c.at_end() {
+        return Err(c.error("unexpected trailing characters"));
+    }
+
+    let attos = i64::try_from(attos).map_err(|_| Error::RangeError)?;
+    let d = Duration::new(secs, attos);
+    Ok(if negative { -d } else { d })
+}
+
+// Parse a decimal number of days such as "2451545.123456789012345" exactly
+// (without floating point), truncating below an attosecond
+pub fn parse_decimal_days(s: &str) -> Result<Duration, Error> {
+    // Fraction digits beyond this are far below an attosecond, and more of them
+    // would overflow the arithmetic below
+    const MAX_FRACTION_DIGITS: usize = 32;
+
+    let mut c = Cursor::new(s);
+
+    let negative = c.eat(b"+-") == Some(b'-');
+    let digits = c.digits();
+    if digits.is_empty() {
+        return Err(c.error("expected a number"));
+    }
+    let days = number(digits)?;
+    let fraction = if c.eat(b".").is_some() {
+        let digits = c.digits();
+        if digits.is_empty() {
+            return Err(c.error("expected fractional digits"));
+        }
+        &digits[..digits.len().min(MAX_FRACTION_DIGITS)]
+    } else {
+        &[]
+    };
+    if !c.at_end() {
+        return Err(c.error("unexpected trailing characters"));
+    }
+
+    // The fraction of a day in attoseconds is fraction * 86400 * 10^18 / 10^len
+    let numerator = fraction
+        .iter()
+        .fold(0_i128, |acc, d| acc * 10 + i128::from(d - b'0'))
+        * 86400;
+    #[allow(clippy::cast_possible_truncation)]
+    let len = fraction.len() as u32;
+    let attos = if len <= 18 {
+        numerator * 10_i128.pow(18 - len)
+    } else {
+        numerator / 10_i128.pow(len - 18)
+    };
+
+    let attos = i128::from(days)
+        .checked_mul(86_400_000_000_000_000_000_000)
+        .and_then(|whole| whole.checked_add(attos))
+        .ok_or(Error::RangeError)?;
+    let secs = i64::try_from(attos / 1_000_000_000_000_000_000).map_err(|_| Error::RangeError)?;
+    #[allow(clippy::cast_possible_truncation)]
+    let d = Duration::new(secs, (attos % 1_000_000_000_000_000_000) as i64);
+    Ok(if negative { -d } else { d })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        parse_datetime, parse_datetime_with_pivot, parse_decimal_days, parse_decimal_seconds,
+        parse_duration, Parsed,
+    };
+    use crate::duration::Duration;
+    use crate::error::Error;
+
+    #[test]
+    fn test_parse_datetime() {
+        crate::setup_logging();
+
+        assert_eq!(
+            parse_datetime("2000-01-01T12:00:00Z").unwrap(),
+            Parsed {
+                year: 2000,
+                month: 1,
+                day: 1,
+                hour: 12,
+                minute: 0,
+                second: 0,
+                attosecond: 0,
+                offset: 0,
+                day_of_year: None,
+            }
+        );
+
+        let p = parse_datetime("-0044-03-15T07:08:09.25+05:30").unwrap();
+        assert_eq!((p.year, p.month, p.day), (-44, 3, 15));
+        assert_eq!((p.hour, p.minute, p.second), (7, 8, 9));
+        assert_eq!(p.attosecond, 250_000_000_000_000_000);
+        assert_eq!(p.offset, 5 * 3600 + 30 * 60);
+
+        let p = parse_datetime("+12345-06-07").unwrap();
+        assert_eq!((p.year, p.month, p.day), (12345, 6, 7));
+
+        let p = parse_datetime("2016-12-31 23:59:60.000000000000000001-01:00").unwrap();
+        assert_eq!(p.second, 60);
+        assert_eq!(p.attosecond, 1);
+        assert_eq!(p.offset, -3600);
+
+        let p = parse_datetime("2023-181T12:00:00Z").unwrap();
+        assert_eq!((p.year, p.day_of_year), (2023, Some(181)));
+        assert_eq!((p.hour, p.minute, p.second), (12, 0, 0));
+        let p = parse_datetime("-0044-074").unwrap();
+        assert_eq!((p.year, p.day_of_year), (-44, Some(74)));
+
+        // Two digit years only with a pivot
+        assert_eq!(
+            parse_datetime_with_pivot("68-01-01", Some(1969))
+                .unwrap()
+                .year,
+            2068
+        );
+        assert_eq!(
+            parse_datetime_with_pivot("2068-01-01", Some(1969)).unwrap(),
+            parse_datetime("2068-01-01").unwrap()
+        );
+        assert!(parse_datetime_with_pivot("68-01-01", None).is_err());
+
+        assert!(parse_datetime("").is_err());
+        assert!(parse_datetime("2000-1").is_err());
+        assert!(parse_datetime("2000-0001").is_err());
+        assert!(parse_datetime("2000-001-01").is_err());
+        assert!(parse_datetime("200-01-01").is_err());
+        assert!(parse_datetime("2000-1-01").is_err());
+        assert!(parse_datetime("2000-01-01T").is_err());
+        assert!(parse_datetime("2000-01-01T12:00").is_err());
+        assert!(parse_datetime("2000-01-01T12:00:00.").is_err());
+        assert!(parse_datetime("2000-01-01T12:00:00.1234567890123456789").is_err());
+        assert!(parse_datetime("2000-01-01T12:00:00Zjunk").is_err());
+        assert!(parse_datetime("2000-01-01T12:00:00+0530").is_err());
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        crate::setup_logging();
+
+        assert_eq!(parse_duration("P").unwrap(), Duration::new(0, 0));
+        assert_eq!(parse_duration("P1DT30S").unwrap(), Duration::new(86430, 0));
+        assert_eq!(
+            parse_duration("P1DT2H1M1.000000000000000120S").unwrap(),
+            Duration::new(86400 + 7200 + 60 + 1, 120)
+        );
+        assert_eq!(
+            parse_duration("-PT1.5S").unwrap(),
+            Duration::new(-1, -500_000_000_000_000_000)
+        );
+        assert_eq!(parse_duration("P2W").unwrap(), Duration::new(14 * 86400, 0));
+
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("1D").is_err());
+        assert!(parse_duration("P1Y").is_err());
+        assert!(parse_duration("P1M").is_err());
+        assert!(parse_duration("PT").is_err());
+        assert!(parse_duration("PT1S2M").is_err());
+        assert!(parse_duration("P1.5D").is_err());
+        assert!(parse_duration("PT1H1H").is_err());
+    }
+
+    #[test]
+    fn test_parse_decimal_seconds() {
+        crate::setup_logging();
+
+        assert_eq!(
+            parse_decimal_seconds("86401.000000000000000120").unwrap(),
+            Duration::new(86401, 120)
+        );
+        assert_eq!(
+            parse_decimal_seconds("-1.5").unwrap(),
+            Duration::new(-1, -500_000_000_000_000_000)
+        );
+        assert_eq!(parse_decimal_seconds("17").unwrap(), Duration::new(17, 0));
+
+        assert!(parse_decimal_seconds("").is_err());
+        assert!(parse_decimal_seconds(".5").is_err());
+        assert!(parse_decimal_seconds("1.").is_err());
+        assert!(parse_decimal_seconds("1e5").is_err());
+        assert!(parse_decimal_seconds("99999999999999999999").is_err());
+    }
+
+    #[test]
+    fn test_parse_decimal_days() {
+        crate::setup_logging();
+
+        assert_eq!(parse_decimal_days("2").unwrap(), Duration::new(172_800, 0));
+        assert_eq!(
+            parse_decimal_days("-0.5").unwrap(),
+            Duration::new(-43_200, 0)
+        );
+        // 10^-23 days is 0.864 attoseconds, which truncates away
+        assert_eq!(
+            parse_decimal_days("0.00000000000000000000001").unwrap(),
+            Duration::new(0, 0)
+        );
+        assert_eq!(
+            parse_decimal_days("0.0000000000000000000001").unwrap(),
+            Duration::new(0, 8)
+        );
+        // Overlong fractions are accepted
+        assert_eq!(
+            parse_decimal_days("1.0000000000000000000000000000000000000001").unwrap(),
+            Duration::new(86400, 0)
+        );
+
+        assert!(parse_decimal_days("").is_err());
+        assert!(parse_decimal_days(".5").is_err());
+        assert!(parse_decimal_days("1.").is_err());
+        assert!(parse_decimal_days("1.5d").is_err());
+        assert!(parse_decimal_days("999999999999999999").is_err());
+        // The whole days fit, but not with the fraction added
+        assert!(matches!(
+            parse_decimal_days("1969226660422097.9"),
+            Err(Error::RangeError)
+        ));
+        assert!(matches!(
+            parse_decimal_days("-1969226660422097.9"),
+            Err(Error::RangeError)
+        ));
+    }
+}
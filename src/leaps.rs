@@ -0,0 +1,1087 @@
+//! Leap seconds
+//!
+//! Leap seconds are inserted into UTC from time to time, making it
+//! discontinuous. This module answers questions about when they occur.
+//!
+//! The leap second table here is compiled in, and may go out of date. All
+//! leap seconds in the table are inserted seconds at the end of a UTC day.
+//! With the `std` feature, `set_leap_events`() replaces the table used for
+//! conversions and by the functions here, which may then include removed leap
+//! seconds too.
+
+use alloc::format;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use crate::calendar::Gregorian;
+use crate::date_time::DateTime;
+use crate::duration::Duration;
+use crate::error::Error;
+use crate::instant::Instant;
+use crate::standard::Utc;
+
+// This is the single source of truth for leap seconds. Both directions of the
+// UTC conversion count leaps against thresholds derived from the one table
+// below, so they always agree.
+//
+// https://www.ietf.org/timezones/data/leap-seconds.list
+//
+// A newer list can be installed at runtime with `set_leap_events()`.
+//
+// These are NTP timestamps (seconds since 1900-01-01 00:00:00 not counting
+// leap seconds) of the instant just after each leap second.
+#[allow(clippy::unreadable_literal)]
+pub(crate) const IANA_NTP_LEAP_SECONDS: [i64; 28] = [
+    2272060800, //	10	# 1 Jan 1972
+    2287785600, //	11	# 1 Jul 1972
+    2303683200, //	12	# 1 Jan 1973
+    2335219200, //	13	# 1 Jan 1974
+    2366755200, //	14	# 1 Jan 1975
+    2398291200, //	15	# 1 Jan 1976
+    2429913600, //	16	# 1 Jan 1977
+    2461449600, //	17	# 1 Jan 1978
+    2492985600, //	18	# 1 Jan 1979
+    2524521600, //	19	# 1 Jan 1980
+    2571782400, //	20	# 1 Jul 1981
+    2603318400, //	21	# 1 Jul 1982
+    2634854400, //	22	# 1 Jul 1983
+    2698012800, //	23	# 1 Jul 1985
+    2776982400, //	24	# 1 Jan 1988
+    2840140800, //	25	# 1 Jan 1990
+    2871676800, //	26	# 1 Jan 1991
+    2918937600, //	27	# 1 Jul 1992
+    2950473600, //	28	# 1 Jul 1993
+    2982009600, //	29	# 1 Jul 1994
+    3029443200, //	30	# 1 Jan 1996
+    3076704000, //	31	# 1 Jul 1997
+    3124137600, //	32	# 1 Jan 1999
+    3345062400, //	33	# 1 Jan 2006
+    3439756800, //	34	# 1 Jan 2009
+    3550089600, //	35	# 1 Jul 2012
+    3644697600, //	36	# 1 Jul 2015
+    3692217600, //	37	# 1 Jan 2017
+];
+
+// The date the leap second list above expires, as an NTP timestamp (28 June 2026).
+// No leap second is known to be scheduled before this.
+#[allow(clippy::unreadable_literal)]
+pub(crate) const IANA_EXPIRES: i64 = 3991593600;
+
+// Number of leap seconds (TAI - UTC) in effect before the first one in the table.
+// We presume these for all earlier dates (see `Utc`).
+pub(crate) const LEAPS_BEFORE_1972: i64 = 9;
+
+// Seconds from 1900-01-01 00:00:00 (NTP, or the same in TT) to
+// `Epoch::TimeStandard`, ignoring the 0.184 second part.
+#[allow(clippy::unreadable_literal)]
+const E1900_TO_TIME_STANDARD_SECS: i64 = 2429913632;
+
+// The unadjusted UTC durations (as passed to `Utc::to_tt()`) at which each leap
+// second has completed. These durations are offset from the UTC time
+// 1977-01-01 00:00:32.184, so NTP timestamps are offset by 32.184 seconds.
+#[allow(clippy::cast_possible_wrap)]
+const UTC_THRESHOLDS: [Duration; IANA_NTP_LEAP_SECONDS.len()] = {
+    let mut thresholds = [Duration { secs: 0, attos: 0 }; IANA_NTP_LEAP_SECONDS.len()];
+    let mut n = 0;
+    while n < thresholds.len() {
+        thresholds[n] = Duration::new(
+            IANA_NTP_LEAP_SECONDS[n] - E1900_TO_TIME_STANDARD_SECS,
+            -184_000_000_000_000_000,
+        );
+        n += 1;
+    }
+    thresholds
+};
+
+// The TT durations (the inside of an `Instant`) at which each leap second has
+// completed. At that moment TT - UTC is 32.184 plus all the leaps to date, which
+// cancels the 0.184 second part of the offset and leaves whole seconds.
+#[allow(clippy::cast_possible_wrap)]
+const TT_THRESHOLDS: [Duration; IANA_NTP_LEAP_SECONDS.len()] = {
+    let mut thresholds = [Duration { secs: 0, attos: 0 }; IANA_NTP_LEAP_SECONDS.len()];
+    let mut n = 0;
+    while n < thresholds.len() {
+        thresholds[n] = Duration::new(
+            IANA_NTP_LEAP_SECONDS[n] - E1900_TO_TIME_STANDARD_SECS
+                + 32
+                + LEAPS_BEFORE_1972
+                + n as i64
+                + 1,
+            0,
+        );
+        n += 1;
+    }
+    thresholds
+};
+
+// This returns the net number of leap seconds (in the table) that have passed.
+// (if the instant is inside of a leap second, that one does not get counted yet)
+#[allow(clippy::cast_possible_wrap)]
+pub(crate) fn leap_seconds_elapsed(at: Instant) -> i64 {
+    trace!("Comparing {at:?} to leap second list");
+
+    #[cfg(feature = "std")]
+    if let Some(elapsed) = custom_elapsed(|table| table.elapsed(&table.tt, at.0)) {
+        return elapsed;
+    }
+    TT_THRESHOLDS.partition_point(|&threshold| threshold <= at.0) as i64
+}
+
+// Similar to leap_seconds_elapsed(), but using an unadjusted duration computed
+// using UTC as if there were no leap seconds. This function is for converting
+// from UTC to TT.
+#[allow(clippy::cast_possible_wrap)]
+pub(crate) fn leap_seconds_elapsed_for_utc(unadjusted_dur: Duration) -> i64 {
+    trace!("Comparing {unadjusted_dur:?} to leap second list (from UTC)");
+
+    #[cfg(feature = "std")]
+    if let Some(elapsed) = custom_elapsed(|table| table.elapsed(&table.utc, unadjusted_dur)) {
+        return elapsed;
+    }
+    UTC_THRESHOLDS.partition_point(|&threshold| threshold <= unadjusted_dur) as i64
+}
+
+/// A change in TAI - UTC at the end of a UTC day
+///
+/// An inserted leap second (a `delta` of 1) makes the day end at `23:59:60`, and
+/// a removed one (a `delta` of -1) makes it end at `23:59:58`, skipping
+/// `23:59:59`. Every leap second so far has been inserted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LeapEvent {
+    /// The instant that the change takes effect, which is midnight UTC at the
+    /// start of the next day, with the new TAI - UTC
+    pub instant: Instant,
+    /// The change in TAI - UTC in seconds, either 1 or -1
+    pub delta: i8,
+}
+
+/// The leap events used for conversions, in order
+///
+/// This is the compiled in table, unless it has been replaced with
+/// `set_leap_events`().
+#[must_use]
+pub fn leap_events() -> Vec<LeapEvent> {
+    #[cfg(feature = "std")]
+    if let Some(table) = CUSTOM_TABLE
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+    {
+        return table.events.clone();
+    }
+    TT_THRESHOLDS
+        .iter()
+        .map(|&end| LeapEvent {
+            instant: Instant(end),
+            delta: 1,
+        })
+        .collect()
+}
+
+// A table of leap events set with `set_leap_events`(), with the TT durations
+// (the inside of an `Instant`) and the unadjusted UTC durations (as passed to
+// `Utc::to_tt()`) at which each takes effect, and the net number of leap
+// seconds elapsed from then on
+#[cfg(feature = "std")]
+struct CustomTable {
+    events: Vec<LeapEvent>,
+    tt: Vec<Duration>,
+    utc: Vec<Duration>,
+    elapsed: Vec<i64>,
+}
+
+#[cfg(feature = "std")]
+impl CustomTable {
+    fn elapsed(&self, thresholds: &[Duration], dur: Duration) -> i64 {
+        match thresholds.partition_point(|&threshold| threshold <= dur) {
+            0 => 0,
+            n => self.elapsed[n - 1],
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+static CUSTOM_TABLE: std::sync::RwLock<Option<CustomTable>> = std::sync::RwLock::new(None);
+
+// Apply `f` to the table set with `set_leap_events`(), if there is one
+#[cfg(feature = "std")]
+fn custom_elapsed<F: FnOnce(&CustomTable) -> i64>(f: F) -> Option<i64> {
+    CUSTOM_TABLE
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .as_ref()
+        .map(f)
+}
+
+/// Replace the leap second table used for conversions
+///
+/// This is for leap seconds announced after this crate was built, and for
+/// testing with hypothetical ones, including removed leap seconds. The events
+/// should start with the introduction of whole leap seconds on 1 January 1972,
+/// as `leap_events`() does. It affects `Utc` conversions (including those with
+/// a `LeapPolicy`) and the functions in this module across the whole program.
+/// `UtcSmeared` still uses the compiled in table.
+///
+/// A leap second list in the IANA format, such as one downloaded with
+/// `fetch_latest`(), can be installed with `load_from_str`():
+///
+/// ```
+/// # use astrotime::leaps;
+/// let list = include_str!("../tests/data/leap-seconds.list");
+/// leaps::set_leap_events(leaps::load_from_str(list).unwrap()).unwrap();
+/// assert_eq!(leaps::leap_events().len(), 28);
+/// leaps::reset_leap_events();
+/// ```
+///
+/// Requires the `std` feature.
+///
+/// # Errors
+///
+/// Will return `Error::RangeError` if a `delta` is not 1 or -1, or
+/// `Error::General` if the events are not in order.
+#[cfg(feature = "std")]
+pub fn set_leap_events(events: Vec<LeapEvent>) -> Result<(), Error> {
+    if events.iter().any(|event| event.delta.abs() != 1) {
+        return Err(Error::RangeError);
+    }
+    if events
+        .windows(2)
+        .any(|pair| pair[0].instant >= pair[1].instant)
+    {
+        return Err(Error::General("Leap events are out of order".into()));
+    }
+
+    let mut total = 0;
+    let elapsed: Vec<i64> = events
+        .iter()
+        .map(|event| {
+            total += i64::from(event.delta);
+            total
+        })
+        .collect();
+    let tt: Vec<Duration> = events.iter().map(|event| event.instant.0).collect();
+    // At each threshold TT - UTC is 32.184 seconds plus TAI - UTC from then on
+    let utc = tt
+        .iter()
+        .zip(&elapsed)
+        .map(|(&tt, &elapsed)| {
+            tt - Duration::new(32 + LEAPS_BEFORE_1972 + elapsed, 184_000_000_000_000_000)
+        })
+        .collect();
+
+    *CUSTOM_TABLE
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(CustomTable {
+        events,
+        tt,
+        utc,
+        elapsed,
+    });
+    Ok(())
+}
+
+/// Go back to the compiled in leap second table after `set_leap_events`()
+///
+/// Requires the `std` feature.
+#[cfg(feature = "std")]
+pub fn reset_leap_events() {
+    *CUSTOM_TABLE
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = None;
+}
+
+/// The instant at which the leap second table expires
+///
+/// The table is only known to be complete up until this instant (00:00:00 UTC
+/// on 28 June 2026). A leap second may have since been announced for a later
+/// date, so conversions between UTC and other time standards after this may be
+/// wrong.
+#[must_use]
+#[allow(clippy::cast_possible_wrap)]
+pub const fn expiry_instant() -> Instant {
+    Instant(Duration::new(
+        IANA_EXPIRES - E1900_TO_TIME_STANDARD_SECS
+            + 32
+            + LEAPS_BEFORE_1972
+            + IANA_NTP_LEAP_SECONDS.len() as i64,
+        0,
+    ))
+}
+
+/// Whether the instant is at or after the expiry of the leap second table
+///
+/// See `expiry_instant`().
+#[must_use]
+pub fn is_expired(at: Instant) -> bool {
+    at >= expiry_instant()
+}
+
+// Whether the expiry warning has been logged
+static EXPIRY_WARNED: AtomicBool = AtomicBool::new(false);
+
+// Log a warning, the first time only, if a UTC conversion is at an instant
+// after the leap second table has expired
+pub(crate) fn warn_if_expired(at: Instant) {
+    if is_expired(at) && !EXPIRY_WARNED.swap(true, Ordering::Relaxed) {
+        warn!(
+            "Converting UTC after the leap second table expires ({}). Any leap \
+             seconds announced since are not accounted for.",
+            DateTime::<Gregorian, Utc>::from(expiry_instant())
+        );
+    }
+}
+
+/// The instants at which each inserted leap second in the table begins, in
+/// order
+///
+/// These are the instants at the start of the `23:59:60` second in UTC. The
+/// table is the one from `leap_events`(), and removed leap seconds are skipped.
+#[must_use]
+pub fn leap_instants() -> impl DoubleEndedIterator<Item = Instant> + ExactSizeIterator {
+    leap_events()
+        .into_iter()
+        .filter(|event| event.delta == 1)
+        .map(|event| event.instant - Duration::new(1, 0))
+        .collect::<Vec<_>>()
+        .into_iter()
+}
+
+/// The `23:59:60` UTC date and time of each inserted leap second in the
+/// table, in order
+///
+/// This is the same as `leap_instants`(), but in human terms.
+#[must_use]
+pub fn leap_dates() -> impl DoubleEndedIterator<Item = DateTime<Gregorian, Utc>> + ExactSizeIterator
+{
+    leap_instants().map(|leap| {
+        // Converting an Instant never gives second 60, so take the date from
+        // the second before the leap second
+        let before = DateTime::<Gregorian, Utc>::from(leap - Duration::new(1, 0));
+        unsafe {
+            DateTime::new_unchecked(before.year(), before.month(), before.day(), 23, 59, 60, 0)
+        }
+    })
+}
+
+/// The instant that the next leap second begins, strictly after `after`
+///
+/// Returns `None` if there are no more leap seconds in the table.
+#[must_use]
+pub fn next_leap_instant(after: Instant) -> Option<Instant> {
+    leap_instants().find(|&leap| leap > after)
+}
+
+/// The instant that the previous leap second began, strictly before `before`
+///
+/// Returns `None` if `before` is not after the first leap second in the table.
+#[must_use]
+pub fn previous_leap_instant(before: Instant) -> Option<Instant> {
+    leap_instants().rev().find(|&leap| leap < before)
+}
+
+/// Whether the instant is within an inserted leap second
+///
+/// The leap seconds are those of `leap_instants`().
+#[must_use]
+pub fn is_during_leap_second(i: Instant) -> bool {
+    leap_instants().any(|leap| leap <= i && i < leap + Duration::new(1, 0))
+}
+
+/// The net number of leap seconds which begin from `a` up to (but not
+/// including) `b`
+///
+/// This is negative if `b` is before `a`. Add it to the difference between two
+/// UTC dates and times, reckoned as if every day had 86400 seconds, to get the
+/// elapsed time. The table is the one from `leap_events`(), where a removed
+/// leap second counts as -1 from the start of the last second of its day
+/// (`23:59:58`).
+#[must_use]
+pub fn leap_seconds_between(a: Instant, b: Instant) -> i64 {
+    let events = leap_events();
+    let begun_before = |i: Instant| -> i64 {
+        events
+            .iter()
+            .filter(|event| event.instant - Duration::new(1, 0) < i)
+            .map(|event| i64::from(event.delta))
+            .sum()
+    };
+    begun_before(b) - begun_before(a)
+}
+
+/// The total offset TAI - UTC at an instant
+///
+/// This is 10 seconds from 1972-01-01, increasing by one second after each
+/// leap second (it is 37 seconds since 2017-01-01). During a leap second, the
+/// offset from before it is returned.
+///
+/// Before 1972, UTC was not offset from TAI by whole leap seconds but by rubber
+/// seconds and fractional steps. These are not modeled: like `Utc`, this
+/// presumes a constant 9 seconds for all earlier instants.
+#[must_use]
+pub fn tai_minus_utc(i: Instant) -> Duration {
+    Duration::new(LEAPS_BEFORE_1972 + leap_seconds_elapsed(i), 0)
+}
+
+/// Where `fetch_latest`() downloads the leap second list from by default
+#[cfg(feature = "fetch")]
+pub const IANA_LEAP_SECONDS_URL: &str = "https://data.iana.org/time-zones/data/leap-seconds.list";
+
+/// Parse a leap second list in the format of the IANA `leap-seconds.list` file
+///
+/// This returns the inserted leap seconds listed, starting with the
+/// introduction of whole leap seconds on 1 January 1972, as events ready for
+/// `set_leap_events`().
+///
+/// # Errors
+///
+/// Will return `Error::ParseError` if a line is malformed, or if the entries
+/// are not in order with TAI - UTC increasing by one second each time (from
+/// 10 seconds at the first).
+pub fn load_from_str(s: &str) -> Result<Vec<LeapEvent>, Error> {
+    let mut events = Vec::new();
+    let mut last_ntp = None;
+    for (n, line) in s.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut fields = line.split_whitespace();
+        let (Some(ntp), Some(offset)) = (fields.next(), fields.next()) else {
+            return Err(Error::ParseError(format!(
+                "Leap second list line {} is malformed",
+                n + 1
+            )));
+        };
+        let (Ok(ntp), Ok(offset)) = (ntp.parse::<i64>(), offset.parse::<i64>()) else {
+            return Err(Error::ParseError(format!(
+                "Leap second list line {} is malformed",
+                n + 1
+            )));
+        };
+        #[allow(clippy::cast_possible_wrap)]
+        let expected = LEAPS_BEFORE_1972 + 1 + events.len() as i64;
+        if offset != expected || last_ntp.is_some_and(|last| last >= ntp) {
+            return Err(Error::ParseError(format!(
+                "Leap second list line {} is out of sequence",
+                n + 1
+            )));
+        }
+        last_ntp = Some(ntp);
+        // As with TT_THRESHOLDS, with the new TAI - UTC given by the offset
+        events.push(LeapEvent {
+            instant: Instant(Duration::new(
+                ntp - E1900_TO_TIME_STANDARD_SECS + 32 + offset,
+                0,
+            )),
+            delta: 1,
+        });
+    }
+    Ok(events)
+}
+
+/// Download and parse the latest leap second list
+///
+/// This fetches a file in the format of the IANA `leap-seconds.list` from
+/// `url`, or from `IANA_LEAP_SECONDS_URL` if that is `None`, and parses it with
+/// `load_from_str`(). The request blocks until it completes.
+///
+/// Requires the `fetch` feature.
+///
+/// # Errors
+///
+/// Will return `Error::General` if the download fails, or `Error::ParseError`
+/// if the file cannot be parsed.
+#[cfg(feature = "fetch")]
+pub fn fetch_latest(url: Option<&str>) -> Result<Vec<LeapEvent>, Error> {
+    let url = url.unwrap_or(IANA_LEAP_SECONDS_URL);
+    let body = ureq::get(url)
+        .call()
+        .map_err(|e| Error::General(format!("Unable to fetch {url}: {e}")))?
+        .into_string()
+        .map_err(|e| Error::General(format!("Unable to read {url}: {e}")))?;
+    load_from_str(&body)
+}
+
+/// How leap seconds are applied when converting to and from UTC
+///
+/// `Utc` always uses `LeapPolicy::Table`. The other policies are available
+/// through `Utc::to_tt_with_policy`() and `Utc::from_tt_with_policy`(), and
+/// through the `DateTime<C, Utc>` methods built on them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LeapPolicy {
+    /// Apply every leap second in the table, with no further leap seconds after
+    /// the last one. Before 1972, TAI - UTC is presumed to be 9 seconds.
+    #[default]
+    Table,
+
+    /// Like `Table`, but ignore any leap seconds after the compiled in table's
+    /// expiry date (28 June 2026), keeping TAI - UTC frozen at its value then.
+    ///
+    /// This only differs from `Table` when the table has been extended with
+    /// `set_leap_events`().
+    FreezeLatest,
+
+    /// Like `Table` from 1972 onwards, but model the rubber seconds and
+    /// fractional steps of 1961 through 1971 with the USNO TAI - UTC formulae.
+    /// Before 1961, TAI - UTC is held at its 1 January 1961 value of 1.422818
+    /// seconds.
+    ProportionalRubber,
+}
+
+// The USNO formulae for TAI - UTC from 1961 until 1972, as
+// (start MJD, offset, base MJD, rate), meaning that from the start MJD,
+// TAI - UTC = offset + (MJD - base MJD) * rate. Offsets are in units of 10^-7
+// seconds and rates in 10^-7 seconds per day.
+//
+// https://maia.usno.navy.mil/ser7/tai-utc.dat
+const RUBBER_FORMULAE: [(i64, i64, i64, i64); 13] = [
+    (37_300, 14_228_180, 37_300, 12_960), // 1961 Jan 1
+    (37_512, 13_728_180, 37_300, 12_960), // 1961 Aug 1
+    (37_665, 18_458_580, 37_665, 11_232), // 1962 Jan 1
+    (38_334, 19_458_580, 37_665, 11_232), // 1963 Nov 1
+    (38_395, 32_401_300, 38_761, 12_960), // 1964 Jan 1
+    (38_486, 33_401_300, 38_761, 12_960), // 1964 Apr 1
+    (38_639, 34_401_300, 38_761, 12_960), // 1964 Sep 1
+    (38_761, 35_401_300, 38_761, 12_960), // 1965 Jan 1
+    (38_820, 36_401_300, 38_761, 12_960), // 1965 Mar 1
+    (38_942, 37_401_300, 38_761, 12_960), // 1965 Jul 1
+    (39_004, 38_401_300, 38_761, 12_960), // 1965 Sep 1
+    (39_126, 43_131_700, 39_126, 25_920), // 1966 Jan 1
+    (39_887, 42_131_700, 39_126, 25_920), // 1968 Feb 1
+];
+
+// The MJD of 1900-01-01, the NTP prime epoch
+const MJD_OF_E1900: i64 = 15_020;
+
+// Attoseconds since 1900-01-01 00:00:00 (as an NTP timestamp) of the start of
+// a modified julian day
+fn mjd_to_ntp_attos(mjd: i64) -> i128 {
+    i128::from(mjd - MJD_OF_E1900) * 86_400_000_000_000_000_000_000
+}
+
+// TAI - UTC in attoseconds under one of the rubber second formulae, at a UTC
+// NTP timestamp in attoseconds
+fn rubber_formula(formula: (i64, i64, i64, i64), ntp_attos: i128) -> i128 {
+    let (_, offset, base, rate) = formula;
+    i128::from(offset) * 100_000_000_000
+        + (ntp_attos - mjd_to_ntp_attos(base)) * i128::from(rate) / (86400 * 10_000_000)
+}
+
+// TAI - UTC under the rubber second formulae. If `from_tai` is false, `dur` is
+// an unadjusted UTC duration before 1972 (as passed to `Utc::to_tt()`). If it
+// is true, `dur` is the corresponding TAI duration, which is the UTC duration
+// plus TAI - UTC.
+fn rubber_tai_minus_utc(dur: Duration, from_tai: bool) -> Duration {
+    // Both durations are offset from 1977-01-01 00:00:32.184
+    let ntp_attos = dur.as_attos_i128()
+        + i128::from(E1900_TO_TIME_STANDARD_SECS) * 1_000_000_000_000_000_000
+        + 184_000_000_000_000_000;
+
+    // Where each formula starts, in the same terms as `dur`
+    let starts = |&formula: &(i64, i64, i64, i64)| {
+        let start = mjd_to_ntp_attos(formula.0);
+        if from_tai {
+            start + rubber_formula(formula, start)
+        } else {
+            start
+        }
+    };
+
+    let index = RUBBER_FORMULAE.partition_point(|formula| starts(formula) <= ntp_attos);
+    if index == 0 {
+        let first = RUBBER_FORMULAE[0];
+        return Duration::from_attos_i128(rubber_formula(first, mjd_to_ntp_attos(first.0)));
+    }
+    let formula = RUBBER_FORMULAE[index - 1];
+
+    let offset = if from_tai {
+        // Solve UTC = TAI - f(UTC). The rate is tiny, so this converges quickly.
+        let mut utc = ntp_attos;
+        for _ in 0..4 {
+            utc = ntp_attos - rubber_formula(formula, utc);
+        }
+        ntp_attos - utc
+    } else {
+        rubber_formula(formula, ntp_attos)
+    };
+    Duration::from_attos_i128(offset)
+}
+
+// TAI - UTC when each leap second is smeared linearly over `window` seconds
+// centered on the midnight it ends at, at an unadjusted UTC duration (as passed
+// to `Utc::to_tt()`). Windows must not overlap.
+#[allow(clippy::cast_possible_wrap)]
+pub(crate) fn smeared_tai_minus_utc_for_utc(unadjusted_dur: Duration, window: i64) -> Duration {
+    let half = Duration::from_attos_i128(i128::from(window) * 500_000_000_000_000_000);
+    let completed = UTC_THRESHOLDS.partition_point(|&midnight| midnight + half <= unadjusted_dur);
+    let mut offset = Duration::new(LEAPS_BEFORE_1972 + completed as i64, 0);
+    if let Some(&midnight) = UTC_THRESHOLDS.get(completed) {
+        let start = midnight - half;
+        if start < unadjusted_dur {
+            // Smeared seconds are longer by 1/window
+            offset = offset
+                + Duration::from_attos_i128(
+                    (unadjusted_dur - start).as_attos_i128() / i128::from(window),
+                );
+        }
+    }
+    offset
+}
+
+// TAI - UTC as in smeared_tai_minus_utc_for_utc(), at a TT duration (the inside
+// of an `Instant`)
+#[allow(clippy::cast_possible_wrap)]
+pub(crate) fn smeared_tai_minus_utc_for_tt(tt: Duration, window: i64) -> Duration {
+    let half = Duration::from_attos_i128(i128::from(window) * 500_000_000_000_000_000);
+    let leaps = Duration::new(LEAPS_BEFORE_1972, 0);
+
+    // Offset from 1977-01-01 00:00:32.184 UTC, but including leap seconds
+    let tai = tt - Duration::new(32, 184_000_000_000_000_000) - leaps;
+
+    // Each window ends one second later here for every leap second so far
+    let completed = (0..UTC_THRESHOLDS.len())
+        .take_while(|&n| UTC_THRESHOLDS[n] + half + Duration::new(n as i64 + 1, 0) <= tai)
+        .count();
+    let completed_leaps = Duration::new(completed as i64, 0);
+    if let Some(&midnight) = UTC_THRESHOLDS.get(completed) {
+        let start = midnight - half;
+        let into = tai - completed_leaps - start;
+        if into.is_positive() {
+            // Invert the smear, solving for the unadjusted UTC duration
+            let window = i128::from(window);
+            let utc =
+                start + Duration::from_attos_i128(into.as_attos_i128() * window / (window + 1));
+            return tt - Duration::new(32, 184_000_000_000_000_000) - utc;
+        }
+    }
+    leaps + completed_leaps
+}
+
+// The unadjusted UTC duration (as passed to `Utc::to_tt()`) at which the
+// compiled in table expires
+const UTC_EXPIRY: Duration = Duration::new(
+    IANA_EXPIRES - E1900_TO_TIME_STANDARD_SECS,
+    -184_000_000_000_000_000,
+);
+
+// TAI - UTC under `policy` at an unadjusted UTC duration (as passed to
+// `Utc::to_tt()`), with leap seconds from the table in use
+pub(crate) fn tai_minus_utc_for_utc_with(unadjusted_dur: Duration, policy: LeapPolicy) -> Duration {
+    let elapsed = match policy {
+        LeapPolicy::Table => leap_seconds_elapsed_for_utc(unadjusted_dur),
+        LeapPolicy::FreezeLatest => leap_seconds_elapsed_for_utc(unadjusted_dur.min(UTC_EXPIRY)),
+        LeapPolicy::ProportionalRubber => {
+            if unadjusted_dur < UTC_THRESHOLDS[0] {
+                return rubber_tai_minus_utc(unadjusted_dur, false);
+            }
+            leap_seconds_elapsed_for_utc(unadjusted_dur)
+        }
+    };
+    Duration::new(LEAPS_BEFORE_1972 + elapsed, 0)
+}
+
+// TAI - UTC under `policy` at a TT duration (the inside of an `Instant`), with
+// leap seconds from the table in use
+pub(crate) fn tai_minus_utc_for_tt_with(tt: Duration, policy: LeapPolicy) -> Duration {
+    let elapsed = match policy {
+        LeapPolicy::Table => leap_seconds_elapsed(Instant(tt)),
+        LeapPolicy::FreezeLatest => leap_seconds_elapsed(Instant(tt).min(expiry_instant())),
+        LeapPolicy::ProportionalRubber => {
+            if tt < TT_THRESHOLDS[0] {
+                return rubber_tai_minus_utc(tt - Duration::new(32, 184_000_000_000_000_000), true);
+            }
+            leap_seconds_elapsed(Instant(tt))
+        }
+    };
+    Duration::new(LEAPS_BEFORE_1972 + elapsed, 0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        expiry_instant, is_during_leap_second, is_expired, leap_dates, leap_events, leap_instants,
+        leap_seconds_between, leap_seconds_elapsed, leap_seconds_elapsed_for_utc, load_from_str,
+        next_leap_instant, previous_leap_instant, tai_minus_utc, tai_minus_utc_for_tt_with,
+        tai_minus_utc_for_utc_with, LeapPolicy, IANA_NTP_LEAP_SECONDS, TT_THRESHOLDS,
+        UTC_THRESHOLDS,
+    };
+    use crate::calendar::{Calendar, Gregorian};
+    use crate::date_time::DateTime;
+    use crate::duration::Duration;
+    use crate::epoch::Epoch;
+    use crate::instant::Instant;
+    use crate::standard::{Standard, Tai, Utc};
+
+    #[test]
+    #[allow(clippy::cast_possible_wrap)]
+    fn test_thresholds_match_the_table() {
+        crate::setup_logging();
+
+        let e1900 = Epoch::E1900_0.as_instant();
+        for (n, &leap) in IANA_NTP_LEAP_SECONDS.iter().enumerate() {
+            // The NTP timestamp counted from 1900-01-01 00:00:00 UTC
+            let utc = UTC_THRESHOLDS[n] - e1900.0;
+            assert_eq!(utc, Duration::new(leap, 0));
+
+            // In TT, each leap completes 42.184 seconds after its NTP timestamp
+            // (9 leaps before 1972, 32.184 to TAI, the leap itself), plus one
+            // second for each of the earlier leaps in the table
+            let tt = Instant(TT_THRESHOLDS[n]) - e1900;
+            assert_eq!(
+                tt,
+                Duration::new(leap + 42 + n as i64, 184_000_000_000_000_000)
+            );
+        }
+
+        let dt: DateTime<Gregorian, Utc> = From::from(Instant(TT_THRESHOLDS[27]));
+        assert_eq!(
+            dt,
+            DateTime::<Gregorian, Utc>::new(2017, 1, 1, 0, 0, 0, 0).unwrap()
+        );
+        let dt: DateTime<Gregorian, Utc> = From::from(Instant(TT_THRESHOLDS[0]));
+        assert_eq!(
+            dt,
+            DateTime::<Gregorian, Utc>::new(1972, 1, 1, 0, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_wrap)]
+    fn test_both_directions_agree_at_every_leap() {
+        crate::setup_logging();
+
+        for (n, &threshold) in TT_THRESHOLDS.iter().enumerate() {
+            let n = n as i64;
+            for offset in [-2, 0, 1] {
+                for attos in [0, 1, 999_999_999_999_999_999] {
+                    let at = Instant(threshold) + Duration::new(offset, attos);
+                    let expected = if offset < 0 { n } else { n + 1 };
+
+                    let elapsed = leap_seconds_elapsed(at);
+                    assert_eq!(elapsed, expected, "{at:?}");
+
+                    let unadjusted = Utc::from_tt(at.0);
+                    assert_eq!(leap_seconds_elapsed_for_utc(unadjusted), elapsed);
+                    assert_eq!(Utc::to_tt(unadjusted), at.0);
+                }
+            }
+
+            // Inside of the leap second itself, it is not counted yet
+            let at = Instant(threshold) - Duration::new(0, 500_000_000_000_000_000);
+            assert_eq!(leap_seconds_elapsed(at), n);
+        }
+    }
+
+    #[test]
+    #[allow(clippy::cast_possible_wrap)]
+    fn test_leap_seconds_elapsed_matches_linear_scan() {
+        crate::setup_logging();
+
+        let linear = |thresholds: &[Duration], d: Duration| -> i64 {
+            thresholds
+                .iter()
+                .position(|&threshold| d < threshold)
+                .unwrap_or(thresholds.len()) as i64
+        };
+
+        let mut checks = vec![
+            Epoch::E1900_0.as_instant().0,
+            Duration::new(0, -1),
+            Duration::new(0, 0),
+        ];
+        for &threshold in TT_THRESHOLDS.iter().chain(UTC_THRESHOLDS.iter()) {
+            for offset in -70..70 {
+                for attos in [0, 1, 999_999_999_999_999_999] {
+                    checks.push(threshold + Duration::new(offset, attos));
+                }
+            }
+        }
+
+        for d in checks {
+            assert_eq!(leap_seconds_elapsed(Instant(d)), linear(&TT_THRESHOLDS, d));
+            assert_eq!(leap_seconds_elapsed_for_utc(d), linear(&UTC_THRESHOLDS, d));
+        }
+    }
+
+    #[test]
+    fn test_next_and_previous_leap_instants() {
+        crate::setup_logging();
+
+        let second = Duration::new(1, 0);
+        let tiny = Duration::new(0, 1);
+        let utc = |y, mo, d, h, mi, s| -> Instant {
+            From::from(DateTime::<Gregorian, Utc>::new(y, mo, d, h, mi, s, 0).unwrap())
+        };
+
+        assert_eq!(leap_instants().len(), IANA_NTP_LEAP_SECONDS.len());
+
+        // The first leap in the table is at the start of 1972, then mid 1972
+        let jan_1972 = utc(1972, 1, 1, 0, 0, 0) - second;
+        let jul_1972 = utc(1972, 7, 1, 0, 0, 0) - second;
+        assert_eq!(leap_instants().nth(1), Some(jul_1972));
+        assert_eq!(
+            next_leap_instant(utc(1972, 6, 30, 12, 0, 0)),
+            Some(jul_1972)
+        );
+        assert_eq!(next_leap_instant(jul_1972 - tiny), Some(jul_1972));
+        assert_eq!(previous_leap_instant(jul_1972 + tiny), Some(jul_1972));
+        assert_eq!(previous_leap_instant(jul_1972), Some(jan_1972));
+        assert_eq!(previous_leap_instant(jan_1972), None);
+        assert_eq!(next_leap_instant(utc(1960, 1, 1, 0, 0, 0)), Some(jan_1972));
+
+        assert!(!is_during_leap_second(jul_1972 - tiny));
+        assert!(is_during_leap_second(jul_1972));
+        assert!(is_during_leap_second(jul_1972 + second - tiny));
+        assert!(!is_during_leap_second(jul_1972 + second));
+
+        // The last leap in the table is at the end of 2016
+        let dec_2016 = utc(2017, 1, 1, 0, 0, 0) - second;
+        assert_eq!(dec_2016 - utc(2016, 12, 31, 23, 59, 59), second);
+        assert_eq!(leap_instants().last(), Some(dec_2016));
+        assert_eq!(
+            next_leap_instant(utc(2016, 12, 31, 0, 0, 0)),
+            Some(dec_2016)
+        );
+        assert_eq!(next_leap_instant(dec_2016), None);
+        assert_eq!(
+            previous_leap_instant(utc(2020, 1, 1, 0, 0, 0)),
+            Some(dec_2016)
+        );
+        assert!(is_during_leap_second(
+            dec_2016 + Duration::new(0, 500_000_000_000_000_000)
+        ));
+        assert!(!is_during_leap_second(utc(2016, 12, 31, 23, 59, 59)));
+        assert!(!is_during_leap_second(utc(2017, 1, 1, 0, 0, 0)));
+    }
+
+    #[test]
+    fn test_leap_seconds_between() {
+        crate::setup_logging();
+
+        let utc =
+            |y, mo, d, h, mi, s| DateTime::<Gregorian, Utc>::new(y, mo, d, h, mi, s, 0).unwrap();
+        let before = utc(2016, 12, 31, 23, 59, 59);
+        let leap = utc(2016, 12, 31, 23, 59, 60);
+        let after = utc(2017, 1, 1, 0, 0, 0);
+        let (i_before, i_leap, i_after) = (
+            Instant::from(before),
+            Instant::from(leap),
+            Instant::from(after),
+        );
+
+        assert_eq!(leap_seconds_between(i_before, i_after), 1);
+        assert_eq!(leap_seconds_between(i_after, i_before), -1);
+        assert_eq!(leap_seconds_between(i_before, i_leap), 0);
+        assert_eq!(leap_seconds_between(i_leap, i_after), 1);
+        assert_eq!(leap_seconds_between(i_leap, i_leap), 0);
+        let half = Duration::new(0, 500_000_000_000_000_000);
+        assert_eq!(leap_seconds_between(i_leap + half, i_after), 0);
+
+        // It makes up the difference from counting 86400 seconds a day
+        let naive = DateTime::<Gregorian, Tai>::new(2017, 1, 1, 0, 0, 0, 0).unwrap()
+            - DateTime::<Gregorian, Tai>::new(2016, 12, 31, 23, 59, 59, 0).unwrap();
+        assert_eq!(naive, Duration::new(1, 0));
+        assert_eq!(
+            i_after - i_before,
+            naive + Duration::new(leap_seconds_between(i_before, i_after), 0)
+        );
+
+        // Every leap second in the table
+        #[allow(clippy::cast_possible_wrap)]
+        let all = IANA_NTP_LEAP_SECONDS.len() as i64;
+        let (i1960, i2020) = (
+            Instant::from(utc(1960, 1, 1, 0, 0, 0)),
+            Instant::from(utc(2020, 1, 1, 0, 0, 0)),
+        );
+        assert_eq!(leap_seconds_between(i1960, i2020), all);
+        assert_eq!(leap_seconds_between(i2020, i1960), -all);
+    }
+
+    #[test]
+    fn test_leap_dates() {
+        crate::setup_logging();
+
+        assert_eq!(leap_dates().len(), IANA_NTP_LEAP_SECONDS.len());
+        assert_eq!(
+            leap_dates().next().unwrap(),
+            DateTime::new(1971, 12, 31, 23, 59, 60, 0).unwrap()
+        );
+        assert_eq!(
+            leap_dates().next_back().unwrap(),
+            DateTime::new(2016, 12, 31, 23, 59, 60, 0).unwrap()
+        );
+        for (date, leap) in leap_dates().zip(leap_instants()) {
+            assert_eq!(date.time(), (23, 59, 60, 0));
+            assert_eq!(Instant::from(date), leap);
+        }
+    }
+
+    #[test]
+    fn test_load_from_str() {
+        crate::setup_logging();
+
+        let events = load_from_str(include_str!("../tests/data/leap-seconds.list")).unwrap();
+        assert_eq!(events, leap_events());
+
+        assert!(load_from_str("").unwrap().is_empty());
+        assert!(load_from_str("# only a comment\n").unwrap().is_empty());
+        assert!(load_from_str("2272060800\n").is_err());
+        assert!(load_from_str("2272060800\tten\n").is_err());
+        assert!(load_from_str("2272060800\t11\n").is_err());
+        assert!(load_from_str("2287785600\t10\n2272060800\t11\n").is_err());
+    }
+
+    #[cfg(feature = "fetch")]
+    #[test]
+    fn test_fetch_latest() {
+        use super::fetch_latest;
+        use std::io::{Read, Write};
+        use std::net::TcpListener;
+
+        crate::setup_logging();
+
+        // Serve the sample file once, locally
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!(
+            "http://{}/leap-seconds.list",
+            listener.local_addr().unwrap()
+        );
+        let server = std::thread::spawn(move || {
+            let body = include_str!("../tests/data/leap-seconds.list");
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 1024];
+            let _ = stream.read(&mut request).unwrap();
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            )
+            .unwrap();
+        });
+
+        let events = fetch_latest(Some(&url)).unwrap();
+        server.join().unwrap();
+        assert_eq!(events, leap_events());
+
+        // Nothing is listening any more
+        assert!(matches!(
+            fetch_latest(Some(&url)),
+            Err(crate::error::Error::General(_))
+        ));
+    }
+
+    #[test]
+    fn test_expiry() {
+        crate::setup_logging();
+
+        let expiry: DateTime<Gregorian, Utc> = From::from(expiry_instant());
+        assert_eq!(expiry, DateTime::new(2026, 6, 28, 0, 0, 0, 0).unwrap());
+        assert!(is_expired(expiry_instant()));
+        assert!(!is_expired(expiry_instant() - Duration::new(0, 1)));
+    }
+
+    #[test]
+    fn test_tai_minus_utc() {
+        crate::setup_logging();
+
+        let utc = |y, mo, d| -> Instant {
+            From::from(DateTime::<Gregorian, Utc>::new(y, mo, d, 0, 0, 0, 0).unwrap())
+        };
+
+        assert_eq!(tai_minus_utc(utc(1960, 1, 1)), Duration::new(9, 0));
+        assert_eq!(tai_minus_utc(utc(1972, 1, 1)), Duration::new(10, 0));
+        assert_eq!(tai_minus_utc(utc(1999, 1, 1)), Duration::new(32, 0));
+        assert_eq!(tai_minus_utc(utc(2017, 1, 1)), Duration::new(37, 0));
+
+        // During the leap second, it is not yet counted
+        let leap = utc(2017, 1, 1) - Duration::new(0, 500_000_000_000_000_000);
+        assert_eq!(tai_minus_utc(leap), Duration::new(36, 0));
+
+        // Agrees with the Tai standard
+        let i = utc(2010, 6, 15);
+        let tai: DateTime<Gregorian, Tai> = From::from(i);
+        let utc: DateTime<Gregorian, Utc> = From::from(i);
+        assert_eq!(
+            tai.duration_from_epoch() - utc.duration_from_epoch(),
+            tai_minus_utc(i)
+        );
+    }
+
+    #[test]
+    fn test_leap_policy_with_compiled_table() {
+        crate::setup_logging();
+
+        // The compiled in table has no leap seconds after it expires, so the
+        // policies agree from 1972 onwards
+        for year in [1972, 2017, 2026, 2030] {
+            let dt = DateTime::<Gregorian, Utc>::new(year, 6, 1, 0, 0, 0, 0).unwrap();
+            let dur = dt.duration_from_epoch() + Gregorian::epoch().0;
+            let tt = Utc::to_tt(dur);
+            for policy in [
+                LeapPolicy::Table,
+                LeapPolicy::FreezeLatest,
+                LeapPolicy::ProportionalRubber,
+            ] {
+                assert_eq!(
+                    tai_minus_utc_for_utc_with(dur, policy),
+                    tai_minus_utc(Instant(tt))
+                );
+                assert_eq!(
+                    tai_minus_utc_for_tt_with(tt, policy),
+                    tai_minus_utc(Instant(tt))
+                );
+                let i = dt.to_instant_with_policy(policy);
+                assert_eq!(i, Instant::from(dt));
+                assert_eq!(
+                    DateTime::<Gregorian, Utc>::from_instant_with_policy(i, policy),
+                    dt
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_leap_policy_proportional_rubber() {
+        crate::setup_logging();
+
+        let offset = |y, mo, d, h| -> Duration {
+            let dt = DateTime::<Gregorian, Utc>::new(y, mo, d, h, 0, 0, 0).unwrap();
+            let i = dt.to_instant_with_policy(LeapPolicy::ProportionalRubber);
+            let back = DateTime::<Gregorian, Utc>::from_instant_with_policy(
+                i,
+                LeapPolicy::ProportionalRubber,
+            );
+            assert_eq!(back, dt);
+            i - Instant::from(DateTime::<Gregorian, Tai>::new(y, mo, d, h, 0, 0, 0).unwrap())
+        };
+
+        // At the base of a formula, the offset is exact
+        assert_eq!(
+            offset(1965, 1, 1, 0),
+            Duration::new(3, 540_130_000_000_000_000)
+        );
+        assert_eq!(
+            offset(1966, 1, 1, 0),
+            Duration::new(4, 313_170_000_000_000_000)
+        );
+
+        // Half a day later, half a day's rate has accrued
+        assert_eq!(
+            offset(1966, 1, 1, 12),
+            Duration::new(4, 314_466_000_000_000_000)
+        );
+
+        // Held at the 1961 value before then
+        assert_eq!(
+            offset(1955, 6, 1, 0),
+            Duration::new(1, 422_818_000_000_000_000)
+        );
+
+        // Just before 1972, the offset approached 10 seconds
+        let end_of_1971 = offset(1971, 12, 31, 0);
+        assert!(end_of_1971 > Duration::new(9, 800_000_000_000_000_000));
+        assert!(end_of_1971 < Duration::new(10, 0));
+        assert_eq!(offset(1972, 1, 1, 0), Duration::new(10, 0));
+    }
+}
@@ -1,32 +1,99 @@
 //! astrotime
 //!
 //! Time related types for scientific and astronomical usage.
+//!
+//! The `std` feature is enabled by default. Without it the crate is `no_std`
+//! (but still requires `alloc`), and the items that need an operating system,
+//! such as conversions from `std::time::SystemTime`, are not available.
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![warn(clippy::all, clippy::pedantic, clippy::nursery, clippy::cargo)]
 
+extern crate alloc;
+
 #[macro_use]
 extern crate log;
 
+mod builder;
+pub use builder::DateTimeBuilder;
+
 mod calendar;
-pub use calendar::{Calendar, Gregorian, Julian};
+pub use calendar::{gregorian_to_julian, julian_to_gregorian, Calendar, Gregorian, Julian};
 
 mod date_time;
-pub use date_time::DateTime;
+pub use date_time::{same_instant, DateTime};
 
 mod duration;
 pub use duration::Duration;
 
+mod dynamic;
+pub use dynamic::{parse_datetime_dynamic, AnyCalendar, AnyStandard};
+
 mod epoch;
 pub use epoch::Epoch;
 
+mod era;
+pub use era::Era;
+
 mod error;
 pub use error::Error;
 
+mod fixed_offset;
+pub use fixed_offset::FixedOffsetDateTime;
+
 mod instant;
 pub use instant::Instant;
 
+mod interval;
+pub use interval::{Interval, Steps};
+
+mod iso8601;
+
+mod macros;
+#[doc(hidden)]
+pub use macros::__datetime_fields;
+
+pub mod leaps;
+
+mod month;
+pub use month::Month;
+
+mod period;
+pub use period::Period;
+
+#[cfg(feature = "serde")]
+pub mod serde_duration;
+
+#[cfg(feature = "serde")]
+pub mod serde_julian_day;
+
+#[cfg(feature = "serde")]
+pub mod serde_rfc3339;
+
 mod standard;
-pub use standard::{Standard, Tai, Tt, Utc};
+pub use standard::{
+    tai_minus_tt, tt_minus_tai, tt_minus_utc, Continuous, Standard, Tai, Tcb, Tcg, Tt, Utc,
+    UtcSmeared,
+};
+
+#[cfg(all(
+    feature = "std",
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+mod stopwatch;
+#[cfg(all(
+    feature = "std",
+    not(all(target_arch = "wasm32", target_os = "unknown"))
+))]
+pub use stopwatch::Stopwatch;
+
+mod strftime;
+
+#[cfg(feature = "time")]
+mod time_crate;
+
+mod weekday;
+pub use weekday::Weekday;
 
 // When running tests, we setup the logger
 #[cfg(test)]
@@ -59,6 +126,88 @@ macro_rules! define_divmod {
 
 define_divmod!(i64, divmod_i64);
 
+// The whole part (saturating, as an `as` cast does) and the fractional part of
+// a float. This is `f64::trunc`() and `f64::fract`() without needing `std`.
+#[inline]
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_precision_loss)]
+fn trunc_fract(x: f64) -> (i64, f64) {
+    let whole = x as i64;
+    // Floats this large have no fractional part
+    if x.abs() >= 4_503_599_627_370_496.0 {
+        (whole, 0.0)
+    } else {
+        (whole, x - whole as f64)
+    }
+}
+
+// Attoseconds per unit, for units of 10^-n seconds (indexed by n)
+const ATTOS_PER_DECIMAL_UNIT: [u64; 19] = [
+    1_000_000_000_000_000_000,
+    100_000_000_000_000_000,
+    10_000_000_000_000_000,
+    1_000_000_000_000_000,
+    100_000_000_000_000,
+    10_000_000_000_000,
+    1_000_000_000_000,
+    100_000_000_000,
+    10_000_000_000,
+    1_000_000_000,
+    100_000_000,
+    10_000_000,
+    1_000_000,
+    100_000,
+    10_000,
+    1_000,
+    100,
+    10,
+    1,
+];
+
+/// Rescale a sub-second attosecond value to a precision of `target_decimals`
+/// decimal digits of fractional seconds, truncating.
+///
+/// For example, with `target_decimals` of 3 this returns milliseconds, of 6
+/// microseconds, and of 9 nanoseconds.
+///
+/// # Panics
+///
+/// Panics if `target_decimals` is greater than 18.
+#[must_use]
+pub const fn rescale_attos(attos: u64, target_decimals: u8) -> u64 {
+    assert!(target_decimals <= 18);
+    attos / ATTOS_PER_DECIMAL_UNIT[target_decimals as usize]
+}
+
+/// Rescale a sub-second attosecond value to a precision of `target_decimals`
+/// decimal digits of fractional seconds, rounding half up.
+///
+/// Note that rounding up may yield a full second (e.g. 1000 milliseconds).
+///
+/// # Panics
+///
+/// Panics if `target_decimals` is greater than 18.
+#[must_use]
+pub const fn rescale_attos_rounded(attos: u64, target_decimals: u8) -> u64 {
+    assert!(target_decimals <= 18);
+    let unit = ATTOS_PER_DECIMAL_UNIT[target_decimals as usize];
+    (attos / unit) + ((attos % unit) >= unit.div_ceil(2)) as u64
+}
+
+/// The inverse of `rescale_attos`(): converts a value with `decimals` decimal
+/// digits of fractional seconds into attoseconds.
+///
+/// For example, with `decimals` of 3 this converts milliseconds into attoseconds.
+///
+/// # Panics
+///
+/// Panics if `decimals` is greater than 18, or on overflow.
+#[must_use]
+pub const fn attos_from_rescaled(value: u64, decimals: u8) -> u64 {
+    assert!(decimals <= 18);
+    value * ATTOS_PER_DECIMAL_UNIT[decimals as usize]
+}
+
 #[test]
 fn test_divmod() {
     crate::setup_logging();
@@ -71,3 +220,56 @@ fn test_divmod() {
     assert_eq!(div, -5);
     assert_eq!(modulo, 3);
 }
+
+#[test]
+fn test_rescale_attos() {
+    crate::setup_logging();
+
+    let attos: u64 = 123_456_789_012_345_678;
+    let expected: [u64; 19] = [
+        0,
+        1,
+        12,
+        123,
+        1_234,
+        12_345,
+        123_456,
+        1_234_567,
+        12_345_678,
+        123_456_789,
+        1_234_567_890,
+        12_345_678_901,
+        123_456_789_012,
+        1_234_567_890_123,
+        12_345_678_901_234,
+        123_456_789_012_345,
+        1_234_567_890_123_456,
+        12_345_678_901_234_567,
+        123_456_789_012_345_678,
+    ];
+    for (decimals, value) in expected.iter().enumerate() {
+        let decimals = u8::try_from(decimals).unwrap();
+        assert_eq!(rescale_attos(attos, decimals), *value);
+        assert_eq!(
+            attos_from_rescaled(*value, decimals),
+            attos - attos % ATTOS_PER_DECIMAL_UNIT[decimals as usize]
+        );
+    }
+
+    // Rounding
+    assert_eq!(rescale_attos_rounded(attos, 0), 0);
+    assert_eq!(rescale_attos_rounded(attos, 1), 1);
+    assert_eq!(rescale_attos_rounded(attos, 3), 123);
+    assert_eq!(rescale_attos_rounded(attos, 4), 1_235);
+    assert_eq!(rescale_attos_rounded(attos, 17), 12_345_678_901_234_568);
+    assert_eq!(rescale_attos_rounded(attos, 18), attos);
+
+    // Rounding at the boundary
+    assert_eq!(rescale_attos_rounded(499_999_999_999_999_999, 0), 0);
+    assert_eq!(rescale_attos_rounded(500_000_000_000_000_000, 0), 1);
+    assert_eq!(rescale_attos_rounded(1_499_999_999_999_999, 3), 1);
+    assert_eq!(rescale_attos_rounded(1_500_000_000_000_000, 3), 2);
+    assert_eq!(rescale_attos_rounded(999_999_999_999_999_999, 3), 1000);
+    assert_eq!(rescale_attos_rounded(14, 17), 1);
+    assert_eq!(rescale_attos_rounded(15, 17), 2);
+}
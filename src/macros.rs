@@ -0,0 +1,167 @@
+// The `datetime!` macro and its compile time checks
+
+/// Create a `DateTime` from a literal date and time, checked at compile time
+///
+/// The date and time are written as `YYYY-MM-DD HH:MM:SS`, followed by the
+/// `Calendar` (which must be `Gregorian` or `Julian`) and the time `Standard`.
+/// Years before 1 CE may be given with a leading `-`. Unlike `DateTime::new`(),
+/// this can be used in a `const`:
+///
+/// ```
+/// # use astrotime::{datetime, DateTime, Gregorian, Tt};
+/// const J2000: DateTime<Gregorian, Tt> = datetime!(2000-01-01 12:00:00, Gregorian, Tt);
+/// assert_eq!(J2000, DateTime::new(2000, 1, 1, 12, 0, 0, 0).unwrap());
+/// ```
+///
+/// A field which is out of range (where `DateTime::new`() would return an error)
+/// fails to compile:
+///
+/// ```compile_fail
+/// # use astrotime::{datetime, Gregorian, Tt};
+/// let dt = datetime!(2023-02-29 00:00:00, Gregorian, Tt);
+/// ```
+///
+/// ```compile_fail
+/// # use astrotime::{datetime, Julian, Utc};
+/// let dt = datetime!(2023-01-01 24:00:00, Julian, Utc);
+/// ```
+#[macro_export]
+macro_rules! datetime {
+    ($year:literal - $month:literal - $day:literal
+     $hour:literal : $minute:literal : $second:literal, $cal:ident, $std:ident) => {{
+        const DT: $crate::DateTime<$crate::$cal, $crate::$std> = {
+            // The literals are only handled as text, so that zero padded fields
+            // such as `08` are not taken for numbers
+            let (year, month, day, hour, minute, second) = $crate::__datetime_fields(
+                ::core::matches!(stringify!($cal).as_bytes(), b"Gregorian"),
+                [
+                    stringify!($year),
+                    stringify!($month),
+                    stringify!($day),
+                    stringify!($hour),
+                    stringify!($minute),
+                    stringify!($second),
+                ],
+            );
+            unsafe { $crate::DateTime::new_unchecked(year, month, day, hour, minute, second, 0) }
+        };
+        DT
+    }};
+}
+
+// Parse and check the fields given to `datetime!`, panicking (which fails
+// compilation, as this is evaluated in a const) if any is out of range
+#[doc(hidden)]
+#[must_use]
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_sign_loss)]
+pub const fn __datetime_fields(gregorian: bool, fields: [&str; 6]) -> (i32, u8, u8, u8, u8, u8) {
+    let year = parse_field(fields[0]);
+    let month = parse_field(fields[1]);
+    let day = parse_field(fields[2]);
+    let hour = parse_field(fields[3]);
+    let minute = parse_field(fields[4]);
+    let second = parse_field(fields[5]);
+
+    assert!(
+        year >= i32::MIN as i64 && year <= i32::MAX as i64,
+        "datetime!: the year is out of range"
+    );
+    assert!(
+        month >= 1 && month <= 12 && day >= 1 && day <= 31,
+        "datetime!: the date is out of range"
+    );
+    assert!(
+        date_is_valid(gregorian, year as i32, month as u8, day as u8),
+        "datetime!: the date is out of range"
+    );
+    assert!(
+        hour >= 0 && hour <= 23 && minute >= 0 && minute <= 59 && second >= 0 && second <= 60,
+        "datetime!: the time is out of range"
+    );
+    (
+        year as i32,
+        month as u8,
+        day as u8,
+        hour as u8,
+        minute as u8,
+        second as u8,
+    )
+}
+
+// Parse an optionally negative decimal integer written as a literal
+const fn parse_field(s: &str) -> i64 {
+    let bytes = s.as_bytes();
+    let mut negative = false;
+    let mut value: i64 = 0;
+    let mut digits = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'-' if digits == 0 && !negative => negative = true,
+            b' ' if digits == 0 => {}
+            b'_' if digits > 0 => {}
+            b @ b'0'..=b'9' => {
+                assert!(value < 1 << 40, "datetime!: a field is out of range");
+                value = value * 10 + (b - b'0') as i64;
+                digits += 1;
+            }
+            _ => panic!("datetime!: each field must be a plain decimal integer"),
+        }
+        i += 1;
+    }
+    assert!(
+        digits > 0,
+        "datetime!: each field must be a plain decimal integer"
+    );
+    if negative {
+        -value
+    } else {
+        value
+    }
+}
+
+// Whether the month and day are valid in the year, checked the same way as
+// `DateTime::new`() but usable in a const context
+const fn date_is_valid(gregorian: bool, year: i32, month: u8, day: u8) -> bool {
+    let leap = if gregorian {
+        year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+    } else {
+        year % 4 == 0
+    };
+    let month_days = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => return false,
+    };
+    day >= 1 && day <= month_days
+}
+
+#[cfg(test)]
+mod test {
+    use crate::calendar::{Gregorian, Julian};
+    use crate::date_time::DateTime;
+    use crate::standard::{Tt, Utc};
+
+    #[test]
+    fn test_datetime_macro() {
+        const LEAP: DateTime<Gregorian, Utc> = datetime!(2016-12-31 23:59:60, Gregorian, Utc);
+        crate::setup_logging();
+
+        assert_eq!(LEAP, DateTime::new(2016, 12, 31, 23, 59, 60, 0).unwrap());
+        assert_eq!(
+            datetime!(1900-02-29 08:09:05, Julian, Tt),
+            DateTime::<Julian, Tt>::new(1900, 2, 29, 8, 9, 5, 0).unwrap()
+        );
+        assert_eq!(
+            datetime!(-44-03-15 12:00:00, Julian, Tt),
+            DateTime::<Julian, Tt>::new(-44, 3, 15, 12, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            datetime!(-4-02-29 00:00:00, Gregorian, Tt),
+            DateTime::<Gregorian, Tt>::new(-4, 2, 29, 0, 0, 0, 0).unwrap()
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use core::convert::TryFrom;
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::calendar::{Calendar, Gregorian, Julian};
+use crate::error::Error;
+
+/// A month of the year
+///
+/// Months are ordered and numbered from January (1) through December (12).
+///
+/// A `Month` converts into the `u8` month number that `DateTime` uses, so it can
+/// be passed to methods such as `DateTime::set_month`():
+///
+/// ```
+/// # use astrotime::{DateTime, Gregorian, Month, Tt};
+/// let mut dt = DateTime::<Gregorian, Tt>::new(2000, 1, 15, 0, 0, 0, 0).unwrap();
+/// dt.set_month(Month::March.into()).unwrap();
+/// assert_eq!(dt.month_enum(), Month::March);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Month {
+    /// January (month 1)
+    January,
+    /// February (month 2)
+    February,
+    /// March (month 3)
+    March,
+    /// April (month 4)
+    April,
+    /// May (month 5)
+    May,
+    /// June (month 6)
+    June,
+    /// July (month 7)
+    July,
+    /// August (month 8)
+    August,
+    /// September (month 9)
+    September,
+    /// October (month 10)
+    October,
+    /// November (month 11)
+    November,
+    /// December (month 12)
+    December,
+}
+
+impl Month {
+    pub(crate) const ALL: [Self; 12] = [
+        Self::January,
+        Self::February,
+        Self::March,
+        Self::April,
+        Self::May,
+        Self::June,
+        Self::July,
+        Self::August,
+        Self::September,
+        Self::October,
+        Self::November,
+        Self::December,
+    ];
+
+    /// The full English name, e.g. "January"
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match *self {
+            Self::January => "January",
+            Self::February => "February",
+            Self::March => "March",
+            Self::April => "April",
+            Self::May => "May",
+            Self::June => "June",
+            Self::July => "July",
+            Self::August => "August",
+            Self::September => "September",
+            Self::October => "October",
+            Self::November => "November",
+            Self::December => "December",
+        }
+    }
+
+    /// The three letter English abbreviation, e.g. "Jan"
+    #[must_use]
+    pub const fn short_name(&self) -> &'static str {
+        match *self {
+            Self::January => "Jan",
+            Self::February => "Feb",
+            Self::March => "Mar",
+            Self::April => "Apr",
+            Self::May => "May",
+            Self::June => "Jun",
+            Self::July => "Jul",
+            Self::August => "Aug",
+            Self::September => "Sep",
+            Self::October => "Oct",
+            Self::November => "Nov",
+            Self::December => "Dec",
+        }
+    }
+
+    /// The month number, from January (1) through December (12)
+    #[must_use]
+    pub const fn number(&self) -> u8 {
+        *self as u8 + 1
+    }
+
+    /// The month with the given number, from January (1) through December (12)
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if `number` is not in the range 1 .. 12
+    pub fn from_number(number: u8) -> Result<Self, Error> {
+        match number {
+            1..=12 => Ok(Self::ALL[usize::from(number - 1)]),
+            _ => Err(Error::RangeError),
+        }
+    }
+
+    /// The following month, wrapping from December to January
+    #[must_use]
+    pub const fn succ(&self) -> Self {
+        Self::ALL[(*self as usize + 1) % 12]
+    }
+
+    /// The preceding month, wrapping from January to December
+    #[must_use]
+    pub const fn pred(&self) -> Self {
+        Self::ALL[(*self as usize + 11) % 12]
+    }
+
+    /// The number of days in this month of the given `year`, in the Gregorian
+    /// calendar if `is_gregorian` is true, or else in the Julian calendar
+    #[must_use]
+    pub fn days_in(&self, year: i32, is_gregorian: bool) -> u8 {
+        if is_gregorian {
+            Gregorian::month_days(self.number(), year)
+        } else {
+            Julian::month_days(self.number(), year)
+        }
+    }
+}
+
+impl fmt::Display for Month {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl TryFrom<u8> for Month {
+    type Error = Error;
+
+    fn try_from(number: u8) -> Result<Self, Self::Error> {
+        Self::from_number(number)
+    }
+}
+
+impl From<Month> for u8 {
+    fn from(month: Month) -> Self {
+        month.number()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Month;
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_month() {
+        crate::setup_logging();
+
+        for n in 1..=12 {
+            let m = Month::try_from(n).unwrap();
+            assert_eq!(m.number(), n);
+            assert_eq!(u8::from(m), n);
+            assert_eq!(m.succ().pred(), m);
+            assert_eq!(m.short_name(), &m.name()[..3]);
+        }
+        assert!(Month::from_number(0).is_err());
+        assert!(Month::try_from(13).is_err());
+
+        assert_eq!(Month::December.succ(), Month::January);
+        assert_eq!(Month::January.pred(), Month::December);
+        assert_eq!(format!("{}", Month::September), "September");
+    }
+
+    #[test]
+    fn test_month_days_in() {
+        crate::setup_logging();
+
+        assert_eq!(Month::February.days_in(2000, true), 29);
+        assert_eq!(Month::February.days_in(1900, true), 28);
+        assert_eq!(Month::February.days_in(1900, false), 29);
+        assert_eq!(Month::February.days_in(1901, false), 28);
+        assert_eq!(Month::February.days_in(-4, true), 29);
+        assert_eq!(Month::April.days_in(2023, true), 30);
+        assert_eq!(Month::December.days_in(2023, false), 31);
+    }
+}
@@ -0,0 +1,123 @@
+use alloc::format;
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::date_time::iso8601_fraction;
+
+/// A calendar period, such as 1 year, 2 months and 3 days
+///
+/// Unlike a `Duration`, which is a fixed number of seconds, the length of a
+/// period depends upon where it starts: a month may be from 28 to 31 days, and
+/// a day may have a leap second. See `DateTime::period_until`().
+///
+/// The fields are all non-negative, and `negative` gives the direction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Period {
+    /// Whether the period runs backwards in time
+    pub negative: bool,
+    /// Whole years
+    pub years: u32,
+    /// Whole months, from 0 .. 11
+    pub months: u8,
+    /// Whole days, from 0 .. 30
+    pub days: u8,
+    /// Whole hours, from 0 .. 23
+    pub hours: u8,
+    /// Whole minutes, from 0 .. 59
+    pub minutes: u8,
+    /// Whole seconds, from 0 .. 60 (60 only when a leap second is spanned)
+    pub seconds: u8,
+    /// Attoseconds, from 0 .. `999_999_999_999_999_999`
+    pub attoseconds: u64,
+}
+
+impl Period {
+    /// Whether every field is zero
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.years == 0
+            && self.months == 0
+            && self.days == 0
+            && self.hours == 0
+            && self.minutes == 0
+            && self.seconds == 0
+            && self.attoseconds == 0
+    }
+}
+
+/// Written out in words, largest unit first and skipping zero units, such as
+/// `1 year, 2 months, 3 days, 4.5 seconds`
+///
+/// A negative period starts with a `-`, and an empty period is `0 seconds`.
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0 seconds");
+        }
+        if self.negative {
+            write!(f, "-")?;
+        }
+
+        let seconds = format!("{}{}", self.seconds, iso8601_fraction(self.attoseconds));
+        let units = [
+            (format!("{}", self.years), self.years == 1, "year"),
+            (format!("{}", self.months), self.months == 1, "month"),
+            (format!("{}", self.days), self.days == 1, "day"),
+            (format!("{}", self.hours), self.hours == 1, "hour"),
+            (format!("{}", self.minutes), self.minutes == 1, "minute"),
+            (
+                seconds,
+                self.seconds == 1 && self.attoseconds == 0,
+                "second",
+            ),
+        ];
+        let mut first = true;
+        for (count, singular, unit) in units {
+            if count == "0" {
+                continue;
+            }
+            if !first {
+                write!(f, ", ")?;
+            }
+            first = false;
+            write!(f, "{count} {unit}{}", if singular { "" } else { "s" })?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Period;
+
+    #[test]
+    fn test_period_display() {
+        crate::setup_logging();
+
+        assert_eq!(format!("{}", Period::default()), "0 seconds");
+        let p = Period {
+            negative: false,
+            years: 1,
+            months: 2,
+            days: 3,
+            hours: 0,
+            minutes: 1,
+            seconds: 4,
+            attoseconds: 500_000_000_000_000_000,
+        };
+        assert_eq!(
+            format!("{p}"),
+            "1 year, 2 months, 3 days, 1 minute, 4.5 seconds"
+        );
+        let p = Period {
+            negative: true,
+            days: 1,
+            seconds: 1,
+            ..Period::default()
+        };
+        assert_eq!(format!("{p}"), "-1 day, 1 second");
+    }
+}
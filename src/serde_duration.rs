@@ -0,0 +1,175 @@
+//! Serialize a `Duration` as a string
+//!
+//! The derived serde implementation of `Duration` serializes as a struct of
+//! seconds and attoseconds. Use `#[serde(with = "astrotime::serde_duration::decimal")]`
+//! to serialize as a decimal number of seconds such as `"86401.000000000000000120"`,
+//! or `#[serde(with = "astrotime::serde_duration::iso8601")]` to serialize as an
+//! ISO 8601 duration such as `"P1DT1.000000000000000120S"`.
+//!
+//! Both are exact; no floating point is involved.
+
+use core::fmt;
+
+use serde::de::{self, Visitor};
+
+use crate::duration::Duration;
+use crate::error::Error;
+
+// A visitor for a string, parsed with the given function
+struct StrVisitor {
+    expecting: &'static str,
+    parse: fn(&str) -> Result<Duration, Error>,
+}
+
+impl Visitor<'_> for StrVisitor {
+    type Value = Duration;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.expecting)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        (self.parse)(v).map_err(E::custom)
+    }
+}
+
+/// Serialize a `Duration` as a decimal number of seconds in a string, with
+/// all 18 digits of attoseconds, such as `"-1.500000000000000000"`
+pub mod decimal {
+    use alloc::format;
+    use serde::{Deserializer, Serializer};
+
+    use super::StrVisitor;
+    use crate::duration::Duration;
+
+    /// Serialize a `Duration` as a decimal string
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the serializer fails.
+    pub fn serialize<S>(d: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let sign = if d.secs < 0 || d.attos < 0 { "-" } else { "" };
+        serializer.serialize_str(&format!(
+            "{}{}.{:018}",
+            sign,
+            d.secs.unsigned_abs(),
+            d.attos.unsigned_abs()
+        ))
+    }
+
+    /// Deserialize a `Duration` from a decimal string
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the string is not a decimal number of seconds
+    /// with no more than 18 fractional digits, or if the deserializer fails.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(StrVisitor {
+            expecting: "a decimal number of seconds",
+            parse: crate::iso8601::parse_decimal_seconds,
+        })
+    }
+}
+
+/// Serialize a `Duration` as an ISO 8601 duration string, such as `"PT1.5S"`
+pub mod iso8601 {
+    use alloc::format;
+    use serde::{Deserializer, Serializer};
+
+    use super::StrVisitor;
+    use crate::duration::Duration;
+
+    /// Serialize a `Duration` as an ISO 8601 string
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the serializer fails.
+    pub fn serialize<S>(d: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&format!("{d}"))
+    }
+
+    /// Deserialize a `Duration` from an ISO 8601 string
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if the string is not a valid ISO 8601 duration,
+    /// or if the deserializer fails.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(StrVisitor {
+            expecting: "an ISO 8601 duration",
+            parse: Duration::parse_iso8601,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::{Deserialize, Serialize};
+
+    use crate::duration::Duration;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Span {
+        #[serde(with = "crate::serde_duration::decimal")]
+        decimal: Duration,
+        #[serde(with = "crate::serde_duration::iso8601")]
+        iso: Duration,
+        compact: Duration,
+    }
+
+    fn round_trip(d: Duration) -> String {
+        let span = Span {
+            decimal: d,
+            iso: d,
+            compact: d,
+        };
+        let json = serde_json::to_string(&span).unwrap();
+        let back: Span = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, span);
+        json
+    }
+
+    #[test]
+    fn test_serde_duration() {
+        crate::setup_logging();
+
+        let json = round_trip(Duration::new(86401, 120));
+        assert_eq!(
+            json,
+            r#"{"decimal":"86401.000000000000000120","iso":"P1DT1.000000000000000120S","compact":{"secs":86401,"attos":120}}"#
+        );
+
+        let json = round_trip(Duration::new(-1, -500_000_000_000_000_000));
+        assert!(json.starts_with(r#"{"decimal":"-1.500000000000000000","iso":"-PT1.5"#));
+
+        // Normalization: sub-second negative values keep their sign
+        let json = round_trip(Duration::new(0, -5));
+        assert!(json.starts_with(r#"{"decimal":"-0.000000000000000005""#));
+
+        // Normalization: mixed signs are normalized before serializing
+        let json = round_trip(Duration::new(1, -1));
+        assert!(json.starts_with(r#"{"decimal":"0.999999999999999999""#));
+
+        round_trip(Duration::new(0, 0));
+        round_trip(Duration::new(i64::MAX, 999_999_999_999_999_999));
+        round_trip(Duration::new(-i64::MAX, -999_999_999_999_999_999));
+
+        let bad = r#"{"decimal":"1.5e3","iso":"P","compact":{"secs":0,"attos":0}}"#;
+        assert!(serde_json::from_str::<Span>(bad).is_err());
+    }
+}
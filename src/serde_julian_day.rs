@@ -0,0 +1,108 @@
+//! Serialize an `Instant` as a Julian Day string
+//!
+//! The derived serde implementation of `Instant` serializes its internal
+//! duration from an internally chosen epoch, as a struct of seconds and
+//! attoseconds. Use this module with
+//! `#[serde(with = "astrotime::serde_julian_day")]` to serialize as a Julian
+//! Day (in TT) in a decimal string such as `"2451545.0"` instead.
+//!
+//! This is exact; no floating point is involved. See
+//! `Instant::as_julian_day_str`().
+
+use core::fmt;
+
+use serde::de::{self, Visitor};
+use serde::{Deserializer, Serializer};
+
+use crate::instant::Instant;
+
+/// Serialize an `Instant` as a Julian Day string
+///
+/// # Errors
+///
+/// Will return an error if the serializer fails.
+pub fn serialize<S>(instant: &Instant, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&instant.as_julian_day_str())
+}
+
+/// Deserialize an `Instant` from a Julian Day string
+///
+/// # Errors
+///
+/// Will return an error if the string is not a decimal number of days, or if
+/// the deserializer fails.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Instant, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(JulianDayVisitor)
+}
+
+struct JulianDayVisitor;
+
+impl Visitor<'_> for JulianDayVisitor {
+    type Value = Instant;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a Julian Day decimal string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Instant::from_julian_day_str(v).map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::{Deserialize, Serialize};
+
+    use crate::duration::Duration;
+    use crate::epoch::Epoch;
+    use crate::instant::Instant;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Observation {
+        #[serde(with = "crate::serde_julian_day")]
+        at: Instant,
+        compact: Instant,
+    }
+
+    #[test]
+    fn test_serde_julian_day() {
+        crate::setup_logging();
+
+        let at = Epoch::J2000_0.as_instant();
+        let observation = Observation { at, compact: at };
+        let json = serde_json::to_string(&observation).unwrap();
+        assert!(json.starts_with(r#"{"at":"2451545.0","compact":{"#));
+        let back: Observation = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, observation);
+
+        let at = Epoch::J2000_0.as_instant() + Duration::new(0, 1);
+        let observation = Observation { at, compact: at };
+        let json = serde_json::to_string(&observation).unwrap();
+        assert!(json.starts_with(r#"{"at":"2451545.00000000000000000000002","#));
+        let back: Observation = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, observation);
+
+        let bad = r#"{"at":"JD 2451545.0","compact":{"secs":0,"attos":0}}"#;
+        assert!(serde_json::from_str::<Observation>(bad).is_err());
+
+        // Too large to represent, which is an error rather than a panic
+        for jd in [
+            "1969226660422097.9",
+            "-1969226660422097.9",
+            "99999999999999999999",
+        ] {
+            let bad = format!(r#"{{"at":"{jd}","compact":{{"secs":0,"attos":0}}}}"#);
+            let error = serde_json::from_str::<Observation>(&bad).unwrap_err();
+            assert!(error.is_data(), "{jd}");
+        }
+    }
+}
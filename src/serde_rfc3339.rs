@@ -0,0 +1,125 @@
+//! Serialize a `DateTime<Gregorian, Utc>` as an RFC 3339 string
+//!
+//! The derived serde implementation of `DateTime` serializes its packed internal
+//! representation, which is compact but opaque. Use this module with
+//! `#[serde(with = "astrotime::serde_rfc3339")]` to serialize as a string such as
+//! `2016-12-31T23:59:60.5Z` instead.
+//!
+//! Trailing zeroes in the fractional seconds are omitted. RFC 3339 only permits
+//! years from 0000 to 9999, so other years will fail to serialize.
+
+use alloc::format;
+use core::fmt;
+
+use serde::de::{self, Visitor};
+use serde::{Deserializer, Serializer};
+
+use crate::calendar::Gregorian;
+use crate::date_time::{iso8601_fraction, DateTime};
+use crate::standard::Utc;
+
+/// Serialize a `DateTime<Gregorian, Utc>` as an RFC 3339 string
+///
+/// # Errors
+///
+/// Will return an error if the year is outside of 0 .. 9999, or if the
+/// serializer fails.
+pub fn serialize<S>(dt: &DateTime<Gregorian, Utc>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if !(0..=9999).contains(&dt.year()) {
+        return Err(serde::ser::Error::custom(
+            "year is out of the RFC 3339 range",
+        ));
+    }
+
+    let mut s = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        dt.year(),
+        dt.month(),
+        dt.day(),
+        dt.hour(),
+        dt.minute(),
+        dt.second()
+    );
+    s.push_str(&iso8601_fraction(dt.attosecond()));
+    s.push('Z');
+
+    serializer.serialize_str(&s)
+}
+
+/// Deserialize a `DateTime<Gregorian, Utc>` from an RFC 3339 string
+///
+/// # Errors
+///
+/// Will return an error if the string is not a valid date and time, or if the
+/// deserializer fails.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<Gregorian, Utc>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(Rfc3339Visitor)
+}
+
+struct Rfc3339Visitor;
+
+impl Visitor<'_> for Rfc3339Visitor {
+    type Value = DateTime<Gregorian, Utc>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "an RFC 3339 date and time string")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        DateTime::parse_iso8601(v).map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::{Deserialize, Serialize};
+
+    use crate::calendar::Gregorian;
+    use crate::date_time::DateTime;
+    use crate::standard::Utc;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Event {
+        #[serde(with = "crate::serde_rfc3339")]
+        at: DateTime<Gregorian, Utc>,
+        compact: DateTime<Gregorian, Utc>,
+    }
+
+    #[test]
+    fn test_serde_rfc3339() {
+        crate::setup_logging();
+
+        let at = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 500_000_000_000_000_000)
+            .unwrap();
+        let event = Event { at, compact: at };
+
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.starts_with(r#"{"at":"2016-12-31T23:59:60.5Z","compact":{"packed":"#));
+
+        let back: Event = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, event);
+
+        let at = DateTime::<Gregorian, Utc>::new(2000, 1, 1, 0, 0, 0, 1).unwrap();
+        let event = Event { at, compact: at };
+        let json = serde_json::to_string(&event).unwrap();
+        assert!(json.starts_with(r#"{"at":"2000-01-01T00:00:00.000000000000000001Z""#));
+        let back: Event = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, event);
+
+        let at = DateTime::<Gregorian, Utc>::new(10000, 1, 1, 0, 0, 0, 0).unwrap();
+        let event = Event { at, compact: at };
+        assert!(serde_json::to_string(&event).is_err());
+
+        let bad = r#"{"at":"2000-13-01T00:00:00Z","compact":{"packed":0,"attos":0}}"#;
+        assert!(serde_json::from_str::<Event>(bad).is_err());
+    }
+}
@@ -1,10 +1,11 @@
-use std::fmt::Debug;
+use core::fmt::Debug;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::duration::Duration;
 use crate::instant::Instant;
+use crate::leaps::{self, LeapPolicy};
 
 /// A standard of time
 pub trait Standard: Debug + Sized + Clone {
@@ -30,9 +31,57 @@ pub trait Standard: Debug + Sized + Clone {
     /// and converts it to a `Duration` from January 1st, 1977 CE gregorian, 00:00:32.184
     /// as defined by this `Standard`.
     fn from_tt(dur: Duration) -> Duration;
+
+    /// Whether every day in this `Standard` is exactly 86400 seconds long, as
+    /// in the `Continuous` standards
+    ///
+    /// If so, the time elapsed between two `DateTime`s can be worked out from
+    /// their fields alone. This defaults to false, which is always correct, but
+    /// slower.
+    #[must_use]
+    fn is_continuous() -> bool {
+        false
+    }
+
+    /// This function is not meant to be called from outside the library.
+    ///
+    /// It takes a `Duration` (as passed to `to_tt`()) at the start of a day, and
+    /// returns the number of leap seconds at the end of that day: 1 if it ends at
+    /// `23:59:60`, -1 if it skips `23:59:59`, and otherwise 0. This defaults to 0,
+    /// which is correct for standards which never show leap seconds, including
+    /// those which smear them.
+    #[must_use]
+    fn leap_seconds_in_day(_start_of_day: Duration) -> i64 {
+        0
+    }
+}
+
+/// TT - TAI, which is always exactly 32.184 seconds
+#[must_use]
+pub const fn tt_minus_tai() -> Duration {
+    Duration::new(32, 184_000_000_000_000_000)
+}
+
+/// TAI - TT, which is always exactly -32.184 seconds
+#[must_use]
+pub const fn tai_minus_tt() -> Duration {
+    Duration::new(-32, -184_000_000_000_000_000)
+}
+
+/// The total offset TT - UTC at an instant
+///
+/// This is `tt_minus_tai`() plus `leaps::tai_minus_utc`(), so it is 69.184
+/// seconds since 2017-01-01. During a leap second, the offset from before it is
+/// returned.
+#[must_use]
+pub fn tt_minus_utc(i: Instant) -> Duration {
+    tt_minus_tai() + leaps::tai_minus_utc(i)
 }
 
 /// Whether a Standard is Continuous or not
+///
+/// Implementors must also return true from `Standard::is_continuous`(), which
+/// is how code that is generic over any `Standard` finds this out.
 pub trait Continuous {}
 
 /// Terrestrial Time
@@ -50,6 +99,10 @@ impl Standard for Tt {
         "TT"
     }
 
+    fn is_continuous() -> bool {
+        true
+    }
+
     fn to_tt(dur: Duration) -> Duration {
         dur
     }
@@ -75,12 +128,16 @@ impl Standard for Tai {
         "TAI"
     }
 
+    fn is_continuous() -> bool {
+        true
+    }
+
     fn to_tt(dur: Duration) -> Duration {
-        dur + Duration::new(32, 184_000_000_000_000_000)
+        dur + tt_minus_tai()
     }
 
     fn from_tt(dur: Duration) -> Duration {
-        dur - Duration::new(32, 184_000_000_000_000_000)
+        dur + tai_minus_tt()
     }
 }
 impl Continuous for Tai {}
@@ -106,103 +163,259 @@ impl Standard for Utc {
     }
 
     fn to_tt(dur: Duration) -> Duration {
-        Tai::to_tt(dur)
-            + Duration::new(9, 0) // 9 leaps before 1972
-            + Duration::new(leap_seconds_elapsed_for_utc(dur), 0) // leaps on or after 1972
+        let tt = Tai::to_tt(dur)
+            + Duration::new(leaps::LEAPS_BEFORE_1972, 0)
+            + Duration::new(leaps::leap_seconds_elapsed_for_utc(dur), 0); // leaps on or after 1972
+        leaps::warn_if_expired(Instant(tt));
+        tt
     }
 
     fn from_tt(dur: Duration) -> Duration {
+        leaps::warn_if_expired(Instant(dur));
         Tai::from_tt(dur)
-            - Duration::new(9, 0) // 9 leaps before 1972
-            - Duration::new(leap_seconds_elapsed(Instant(dur)), 0) // leaps on or after 1972
+            - Duration::new(leaps::LEAPS_BEFORE_1972, 0)
+            - Duration::new(leaps::leap_seconds_elapsed(Instant(dur)), 0) // leaps on or after 1972
+    }
+
+    fn leap_seconds_in_day(start_of_day: Duration) -> i64 {
+        i64::from(utc_seconds_in_day(start_of_day)) - 86400
     }
 }
 
-// https://www.ietf.org/timezones/data/leap-seconds.list
-// FIXME: fetch the list dynamically if the user allows
-#[allow(clippy::unreadable_literal)]
-fn leap_seconds() -> Vec<i64> {
-    vec![
-        2272060800, //	10	# 1 Jan 1972
-        2287785600, //	11	# 1 Jul 1972
-        2303683200, //	12	# 1 Jan 1973
-        2335219200, //	13	# 1 Jan 1974
-        2366755200, //	14	# 1 Jan 1975
-        2398291200, //	15	# 1 Jan 1976
-        2429913600, //	16	# 1 Jan 1977
-        2461449600, //	17	# 1 Jan 1978
-        2492985600, //	18	# 1 Jan 1979
-        2524521600, //	19	# 1 Jan 1980
-        2571782400, //	20	# 1 Jul 1981
-        2603318400, //	21	# 1 Jul 1982
-        2634854400, //	22	# 1 Jul 1983
-        2698012800, //	23	# 1 Jul 1985
-        2776982400, //	24	# 1 Jan 1988
-        2840140800, //	25	# 1 Jan 1990
-        2871676800, //	26	# 1 Jan 1991
-        2918937600, //	27	# 1 Jul 1992
-        2950473600, //	28	# 1 Jul 1993
-        2982009600, //	29	# 1 Jul 1994
-        3029443200, //	30	# 1 Jan 1996
-        3076704000, //	31	# 1 Jul 1997
-        3124137600, //	32	# 1 Jan 1999
-        3345062400, //	33	# 1 Jan 2006
-        3439756800, //	34	# 1 Jan 2009
-        3550089600, //	35	# 1 Jul 2012
-        3644697600, //	36	# 1 Jul 2015
-        3692217600, //	37	# 1 Jan 2017
-    ]
+impl Utc {
+    /// Like `Utc::to_tt`(), but applying leap seconds according to `policy`
+    ///
+    /// This function is not meant to be called from outside the library except
+    /// by implementors of other `Standard`s. See `DateTime::to_instant_with_policy`().
+    #[must_use]
+    pub fn to_tt_with_policy(dur: Duration, policy: LeapPolicy) -> Duration {
+        Tai::to_tt(dur) + leaps::tai_minus_utc_for_utc_with(dur, policy)
+    }
+
+    /// Like `Utc::from_tt`(), but applying leap seconds according to `policy`
+    ///
+    /// This function is not meant to be called from outside the library except
+    /// by implementors of other `Standard`s. See `DateTime::from_instant_with_policy`().
+    #[must_use]
+    pub fn from_tt_with_policy(dur: Duration, policy: LeapPolicy) -> Duration {
+        Tai::from_tt(dur) - leaps::tai_minus_utc_for_tt_with(dur, policy)
+    }
 }
 
-// This returns how many leap seconds have passed.
-// (if the instant is inside of a leap second, that one does not get counted yet)
-pub fn leap_seconds_elapsed(at: Instant) -> i64 {
-    use crate::epoch::Epoch;
+/// Universal Coordinated Time with leap seconds smeared out
+///
+/// This is UTC as reported by many servers, which never show `23:59:60`.
+/// Instead each leap second is spread out evenly over the `WINDOW` seconds
+/// centered on the midnight at which it ends (24 hours, from noon to noon, by
+/// default), making each second in that window slightly longer than an SI
+/// second. Outside of the windows, this is the same as `Utc`.
+///
+/// `WINDOW` must be from 1 second up to 180 days, so that windows never
+/// overlap.
+///
+/// ```
+/// # use astrotime::{DateTime, Gregorian, Instant, UtcSmeared};
+/// // Smear over the 4 hours around midnight
+/// let dt = DateTime::<Gregorian, UtcSmeared<14400>>::new(2017, 1, 1, 0, 0, 0, 0).unwrap();
+/// let i = Instant::from(dt);
+/// ```
+///
+/// ```compile_fail
+/// # use astrotime::{DateTime, Gregorian, Instant, UtcSmeared};
+/// let dt = DateTime::<Gregorian, UtcSmeared<0>>::new(2017, 1, 1, 0, 0, 0, 0).unwrap();
+/// let i = Instant::from(dt);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct UtcSmeared<const WINDOW: i64 = 86400>;
+
+impl<const WINDOW: i64> UtcSmeared<WINDOW> {
+    const CHECK_WINDOW: () = assert!(
+        WINDOW > 0 && WINDOW <= 180 * 86400,
+        "The smear window must be from 1 second up to 180 days"
+    );
+}
 
-    // NOTE: if our instants ever differ from TimeStandard, we need to run this
-    // instead:
-    // let cmp = at + (Epoch::TimeStandard.as_instant() - Epoch::E1900_0.as_instant());
-    let cmp = at - Epoch::E1900_0.as_instant();
-    let secs = cmp.seconds_part();
+impl<const WINDOW: i64> Standard for UtcSmeared<WINDOW> {
+    fn abbrev() -> &'static str {
+        "UTCS"
+    }
 
-    trace!("Comparing seconds {} to leap second list", secs);
+    #[allow(clippy::let_unit_value)]
+    fn to_tt(dur: Duration) -> Duration {
+        let () = Self::CHECK_WINDOW;
+        let tt = Tai::to_tt(dur) + leaps::smeared_tai_minus_utc_for_utc(dur, WINDOW);
+        leaps::warn_if_expired(Instant(tt));
+        tt
+    }
 
-    leap_seconds()
-        .iter()
-        .enumerate()
-        .find(|(_n, &leap)| secs < leap)
-        .map_or(leap_seconds().len(), |(n, _d)| n) as i64
+    #[allow(clippy::let_unit_value)]
+    fn from_tt(dur: Duration) -> Duration {
+        let () = Self::CHECK_WINDOW;
+        leaps::warn_if_expired(Instant(dur));
+        Tai::from_tt(dur) - leaps::smeared_tai_minus_utc_for_tt(dur, WINDOW)
+    }
 }
 
-// Similar to leap_seconds_elapsed(), but using an incorrect/unadjusted duration
-// computed using UTC as if there were no leap seconds. This function is for
-// converting from UTC to TAI.
-fn leap_seconds_elapsed_for_utc(mut unadjusted_dur: Duration) -> i64 {
-    use crate::epoch::Epoch;
+/// Geocentric Coordinate Time
+///
+/// This is the proper time of a clock at the center of the Earth, outside of
+/// its gravity well, so it runs faster than TT by the defining rate
+/// `L_G` = 6.969290134e-10. It reads the same as TT at 1977-01-01 00:00:32.184 TT.
+///
+/// See [Wikipedia](https://en.wikipedia.org/wiki/Geocentric_Coordinate_Time)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Tcg;
+impl Standard for Tcg {
+    fn abbrev() -> &'static str {
+        "TCG"
+    }
+
+    fn is_continuous() -> bool {
+        true
+    }
+
+    fn to_tt(dur: Duration) -> Duration {
+        rate_to_tt(dur, TCG_FACTOR)
+    }
+
+    fn from_tt(dur: Duration) -> Duration {
+        rate_from_tt(dur, TCG_FACTOR)
+    }
+}
+impl Continuous for Tcg {}
+
+/// Barycentric Coordinate Time
+///
+/// This is the proper time of a clock at the barycenter of the solar system,
+/// outside of its gravity well, so it runs faster than TT, on average by the
+/// rate `L_B` = 1.550519768e-8. It reads the same as TT at 1977-01-01
+/// 00:00:32.184 TT.
+///
+/// Only that average rate is applied. The periodic terms (of up to about 1.7
+/// milliseconds) from the Earth's orbit, which need a solar system ephemeris,
+/// are not, and neither is the `TDB0` offset of 65.5 microseconds.
+///
+/// See [Wikipedia](https://en.wikipedia.org/wiki/Barycentric_Coordinate_Time)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Tcb;
+impl Standard for Tcb {
+    fn abbrev() -> &'static str {
+        "TCB"
+    }
+
+    fn is_continuous() -> bool {
+        true
+    }
+
+    fn to_tt(dur: Duration) -> Duration {
+        rate_to_tt(dur, TCB_FACTOR)
+    }
 
-    // Adjust the UTC based duration as close to TT as we can (all but leaps)
-    unadjusted_dur = unadjusted_dur + Duration::new(9 + 32, 184_000_000_000_000_000);
-    let cmp = unadjusted_dur - Epoch::E1900_0.as_instant().0;
-    let secs = cmp.seconds_part();
+    fn from_tt(dur: Duration) -> Duration {
+        rate_from_tt(dur, TCB_FACTOR)
+    }
+}
+impl Continuous for Tcb {}
+
+// TT / TCG = 1 - L_G, as an exact ratio (L_G = 3484645067 / 5e18)
+const TCG_FACTOR: (i64, i64) = (4_999_999_996_515_354_933, 5_000_000_000_000_000_000);
+
+// TT / TCB = 1 - L_B, as an exact ratio (L_B = 193814971 / 1.25e16)
+const TCB_FACTOR: (i64, i64) = (12_499_999_806_185_029, 12_500_000_000_000_000);
+
+// Convert to TT from a standard which reads the same as TT at
+// `Epoch::TimeStandard` (where durations are zero), and then runs slower than
+// it by `factor`
+fn rate_to_tt(dur: Duration, factor: (i64, i64)) -> Duration {
+    // The factor is less than one, so this cannot overflow
+    dur.mul_div(factor.0, factor.1).expect("Duration overflow")
+}
+
+// The inverse of rate_to_tt(). Each TT attosecond corresponds to one or two
+// attoseconds in the faster standard, and this returns the one nearer to zero,
+// so that converting back gives exactly `dur`.
+fn rate_from_tt(dur: Duration, factor: (i64, i64)) -> Duration {
+    let atto = Duration::new(0, 1);
+    let mut faster = dur.mul_div(factor.1, factor.0).expect("Duration overflow");
+    while rate_to_tt(faster, factor) < dur {
+        faster = faster + atto;
+    }
+    while rate_to_tt(faster, factor) > dur {
+        faster = faster - atto;
+    }
+    let toward_zero = Duration::new(0, -i64::from(faster.signum()));
+    if rate_to_tt(faster + toward_zero, factor) == dur {
+        faster = faster + toward_zero;
+    }
+    faster
+}
 
-    trace!("Comparing seconds {} to leap second list (from UTC)", secs);
+// The number of seconds in the UTC day starting at `start_of_day` (an unadjusted
+// UTC based duration, as passed to `Utc::to_tt()`).
+//
+// This is 86400 plus however many leap seconds occur before the start of the next
+// day, which is 86401 for an inserted leap second and 86399 for a removed one.
+pub fn utc_seconds_in_day(start_of_day: Duration) -> u32 {
+    utc_seconds_in_day_with(start_of_day, leaps::leap_seconds_elapsed_for_utc)
+}
 
-    leap_seconds()
-        .iter()
-        .enumerate()
-        .map(|(n, leap)| (n, leap - n as i64)) // each leap successively drug backwards
-        .find(|(_n, leap)| secs < *leap)
-        .map_or(leap_seconds().len(), |(n, _d)| n) as i64
+// Same as utc_seconds_in_day() but with the leap second counting function supplied
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_sign_loss)]
+fn utc_seconds_in_day_with<F: Fn(Duration) -> i64>(
+    start_of_day: Duration,
+    leaps_elapsed: F,
+) -> u32 {
+    let start_of_next_day = start_of_day + Duration::new(86400, 0);
+    let leaps = leaps_elapsed(start_of_next_day) - leaps_elapsed(start_of_day);
+    (86400 + leaps) as u32
 }
 
 #[cfg(test)]
 mod test {
-    use super::leap_seconds_elapsed;
+    use super::{tai_minus_tt, tt_minus_tai, tt_minus_utc, utc_seconds_in_day_with};
     use crate::calendar::Gregorian;
     use crate::date_time::DateTime;
     use crate::duration::Duration;
+    use crate::epoch::Epoch;
     use crate::instant::Instant;
-    use crate::standard::{Standard, Tai, Tt, Utc};
+    use crate::leaps::leap_seconds_elapsed;
+    use crate::standard::{Continuous, Standard, Tai, Tcb, Tcg, Tt, Utc, UtcSmeared};
+
+    #[test]
+    fn test_offsets() {
+        crate::setup_logging();
+
+        assert_eq!(tt_minus_tai(), Duration::new(32, 184_000_000_000_000_000));
+        assert_eq!(tai_minus_tt(), -tt_minus_tai());
+
+        let i: Instant =
+            From::from(DateTime::<Gregorian, Utc>::new(2017, 1, 1, 0, 0, 0, 0).unwrap());
+        assert_eq!(tt_minus_utc(i), Duration::new(69, 184_000_000_000_000_000));
+        let i: Instant =
+            From::from(DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 59, 0).unwrap());
+        assert_eq!(tt_minus_utc(i), Duration::new(68, 184_000_000_000_000_000));
+
+        // The offsets agree with the conversions
+        let tt = DateTime::<Gregorian, Tt>::from(i);
+        let utc = DateTime::<Gregorian, Utc>::from(i);
+        assert_eq!(
+            tt - tt_minus_utc(i),
+            DateTime::new(
+                utc.year(),
+                utc.month(),
+                utc.day(),
+                utc.hour(),
+                utc.minute(),
+                utc.second(),
+                utc.attosecond()
+            )
+            .unwrap()
+        );
+    }
 
     #[test]
     fn test_to_from_tt() {
@@ -220,17 +433,16 @@ mod test {
         assert_eq!(i, j);
 
         // Test UTC in the vacinity of a leap second (1 January 1999)
-        let leap_instant: Instant = From::from(
-            DateTime::<Gregorian, Tt>::new(1999, 1, 1, 0, 0, 0, 0).unwrap()
-                - Duration::new(32 + 32, 184_000_000_000_000_000),
-        );
+        let leap_instant: Instant =
+            From::from(DateTime::<Gregorian, Utc>::new(1999, 1, 1, 0, 0, 0, 0).unwrap());
         for s in -100..100 {
-            // leap happens at s=65 or 66
+            // the leap second is the TT second at s=-1
             // NOTE: we cannot possibly map in a lossy way to UTC and back again
-            //       without an error somewhere. 3124137577 repeats.  Which TT
-            //       second should it refer to?
+            //       without an error somewhere. Inside of the leap second, UTC
+            //       durations repeat the first second of 1 January 1999.  Which
+            //       TT second should it refer to?
             //       So we skip that one nasty value of s
-            if s == 65 {
+            if s == -1 {
                 continue;
             }
 
@@ -239,13 +451,126 @@ mod test {
             // has lost such information. Perhaps we need to do conversions
             // between DateTime objects instead of between Instants.
 
-            trace!("s={}", s);
+            trace!("s={s}");
             let a = leap_instant + Duration::new(s, 0);
             let b = Instant(Utc::to_tt(Utc::from_tt(a.0)));
             assert_eq!(a, b);
         }
     }
 
+    #[test]
+    #[allow(clippy::cast_possible_wrap)]
+    fn test_tcg() {
+        crate::setup_logging();
+
+        // TCG and TT agree at the start of 1977
+        let t0 = Epoch::TimeStandard.as_instant();
+        assert_eq!(Tcg::to_tt(t0.0), t0.0);
+        assert_eq!(Tcg::from_tt(t0.0), t0.0);
+
+        // TCG is ahead by about half a second by J2000.0
+        let j2000 = Epoch::J2000_0.as_instant();
+        let tcg = Tcg::from_tt(j2000.0);
+        assert_eq!(tcg - j2000.0, Duration::new(0, 505_833_286_021_129_406));
+
+        // Conversions round trip exactly, either side of 1977
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        for _ in 0..1000 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let d = Duration::new(
+                (state >> 20) as i64 - (1 << 43),
+                (state % 1_000_000_007) as i64,
+            );
+            assert_eq!(Tcg::to_tt(Tcg::from_tt(d)), d);
+            assert_eq!(Tcb::to_tt(Tcb::from_tt(d)), d);
+            let i = Instant(d);
+            assert_eq!(Instant::from(DateTime::<Gregorian, Tcg>::from(i)), i);
+        }
+    }
+
+    #[test]
+    fn test_tcb() {
+        crate::setup_logging();
+
+        // TCB is ahead by about 11.25 seconds by J2000.0
+        let j2000 = Epoch::J2000_0.as_instant();
+        let ahead = Tcb::from_tt(j2000.0) - j2000.0;
+        assert!(ahead > Duration::new(11, 250_000_000_000_000_000));
+        assert!(ahead < Duration::new(11, 260_000_000_000_000_000));
+
+        // It runs faster by L_B
+        let day = Tcb::from_tt(Duration::new(86400, 0)) - Tcb::from_tt(Duration::new(0, 0));
+        assert_eq!(day, Duration::new(86400, 1_339_649_100_323_525));
+    }
+
+    #[test]
+    fn test_utc_smeared() {
+        type Hourly = UtcSmeared<3600>;
+
+        crate::setup_logging();
+
+        let smeared = |d, h, mi, s| -> Instant {
+            let (y, mo) = if d == 31 { (2016, 12) } else { (2017, 1) };
+            From::from(DateTime::<Gregorian, UtcSmeared>::new(y, mo, d, h, mi, s, 0).unwrap())
+        };
+        let utc = |d, h, mi, s| -> Instant {
+            let (y, mo) = if d == 31 { (2016, 12) } else { (2017, 1) };
+            From::from(DateTime::<Gregorian, Utc>::new(y, mo, d, h, mi, s, 0).unwrap())
+        };
+
+        // Outside of the window, the same as UTC
+        for (d, h) in [(31, 0), (31, 11), (1, 12), (1, 23)] {
+            assert_eq!(smeared(d, h, 0, 0), utc(d, h, 0, 0));
+        }
+        assert_eq!(smeared(31, 12, 0, 0), utc(31, 12, 0, 0));
+        assert_eq!(smeared(1, 12, 0, 0), utc(1, 12, 0, 0));
+
+        // Half of the leap second has been smeared in by midnight
+        assert_eq!(
+            smeared(1, 0, 0, 0),
+            utc(1, 0, 0, 0) - Duration::new(0, 500_000_000_000_000_000)
+        );
+
+        // Seconds are longer within the window, and SI seconds outside of it
+        let hour = smeared(31, 19, 0, 0) - smeared(31, 18, 0, 0);
+        let expected = Duration::new(3600, 41_666_666_666_666_666);
+        assert!((hour - expected).abs() <= Duration::new(0, 1));
+        assert_eq!(
+            smeared(31, 11, 0, 0) - smeared(31, 10, 0, 0),
+            Duration::new(3600, 0)
+        );
+        assert_eq!(
+            smeared(1, 12, 0, 0) - smeared(31, 12, 0, 0),
+            Duration::new(86401, 0)
+        );
+
+        // Converting back never shows a leap second
+        let leap = utc(1, 0, 0, 0) - Duration::new(1, 0);
+        let dt = DateTime::<Gregorian, UtcSmeared>::from(leap);
+        assert_eq!(dt.date(), (2016, 12, 31));
+        assert_eq!((dt.hour(), dt.minute(), dt.second()), (23, 59, 59));
+        for i in [
+            leap,
+            utc(31, 12, 0, 0),
+            smeared(31, 18, 0, 0),
+            utc(1, 11, 59, 59),
+        ] {
+            let back = Instant::from(DateTime::<Gregorian, UtcSmeared>::from(i));
+            assert!((back - i).abs() <= Duration::new(0, 2));
+        }
+
+        // A shorter window
+        let hourly = |i: Instant| Hourly::to_tt(Utc::from_tt(i.0));
+        assert_eq!(hourly(utc(31, 23, 30, 0)), utc(31, 23, 30, 0).0);
+        assert_eq!(
+            hourly(utc(1, 0, 0, 0)),
+            utc(1, 0, 0, 0).0 - Duration::new(0, 500_000_000_000_000_000)
+        );
+        assert_eq!(hourly(utc(1, 0, 30, 0)), utc(1, 0, 30, 0).0);
+    }
+
     #[test]
     fn test_leap_seconds_elapsed() {
         crate::setup_logging();
@@ -278,4 +603,45 @@ mod test {
             From::from(DateTime::<Gregorian, Utc>::new(2019, 9, 17, 13, 45, 18, 0).unwrap());
         assert_eq!(leap_seconds_elapsed(at), 28);
     }
+
+    #[test]
+    fn test_utc_seconds_in_day_with_negative_leap() {
+        crate::setup_logging();
+
+        // A synthetic table with a single removed leap second at the end of the
+        // day starting at duration 86400 * 10
+        let leaps_elapsed = |d: Duration| -> i64 {
+            if d.seconds_part() >= 86400 * 11 {
+                -1
+            } else {
+                0
+            }
+        };
+
+        let day = Duration::new(86400 * 10, 0);
+        assert_eq!(utc_seconds_in_day_with(day, leaps_elapsed), 86399);
+
+        let day_before = Duration::new(86400 * 9, 0);
+        assert_eq!(utc_seconds_in_day_with(day_before, leaps_elapsed), 86400);
+
+        let day_after = Duration::new(86400 * 11, 0);
+        assert_eq!(utc_seconds_in_day_with(day_after, leaps_elapsed), 86400);
+    }
+
+    #[test]
+    fn test_continuous_standards_are_continuous() {
+        fn is_continuous<S: Standard + Continuous>() -> bool {
+            S::is_continuous()
+        }
+
+        crate::setup_logging();
+
+        assert!(is_continuous::<Tt>());
+        assert!(is_continuous::<Tai>());
+        assert!(is_continuous::<Tcg>());
+        assert!(is_continuous::<Tcb>());
+
+        assert!(!Utc::is_continuous());
+        assert!(!<UtcSmeared>::is_continuous());
+    }
 }
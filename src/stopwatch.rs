@@ -0,0 +1,92 @@
+use core::convert::TryFrom;
+
+use crate::duration::Duration;
+
+/// A stopwatch measuring elapsed time as a `Duration`
+///
+/// This reads the operating system's monotonic clock through
+/// `std::time::Instant`, so the measurements never go backwards, even if the
+/// system clock is adjusted while the stopwatch is running. Although they are
+/// in attoseconds, their real resolution is that of the monotonic clock,
+/// typically a nanosecond to a microsecond (and much coarser on some
+/// platforms).
+///
+/// Requires the `std` feature, and is not available on
+/// `wasm32-unknown-unknown`, which has no clock.
+///
+/// ```
+/// # use astrotime::{Duration, Stopwatch};
+/// let mut stopwatch = Stopwatch::start();
+/// let first = stopwatch.lap();
+/// let second = stopwatch.lap();
+/// assert!(first + second <= stopwatch.elapsed());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stopwatch {
+    start: std::time::Instant,
+    lap_start: std::time::Instant,
+}
+
+impl Stopwatch {
+    /// Start a new stopwatch
+    #[must_use]
+    pub fn start() -> Self {
+        let now = std::time::Instant::now();
+        Self {
+            start: now,
+            lap_start: now,
+        }
+    }
+
+    /// The time since the stopwatch was started (or last reset)
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        duration(self.start.elapsed())
+    }
+
+    /// The time since the previous lap (or since the stopwatch was started, for
+    /// the first lap), starting a new lap
+    pub fn lap(&mut self) -> Duration {
+        let now = std::time::Instant::now();
+        let lap = duration(now - self.lap_start);
+        self.lap_start = now;
+        lap
+    }
+
+    /// Restart the stopwatch from zero
+    pub fn reset(&mut self) {
+        *self = Self::start();
+    }
+}
+
+// Convert a measurement from `std::time::Instant`
+fn duration(d: core::time::Duration) -> Duration {
+    // Only a duration of more than 2^63 seconds does not fit
+    Duration::try_from(d).unwrap_or(Duration::MAX)
+}
+
+#[cfg(test)]
+mod test {
+    use super::Stopwatch;
+    use crate::duration::Duration;
+
+    #[test]
+    fn test_stopwatch() {
+        crate::setup_logging();
+
+        let mut stopwatch = Stopwatch::start();
+        let mut previous = Duration::new(0, 0);
+        for _ in 0..1000 {
+            let elapsed = stopwatch.elapsed();
+            assert!(elapsed >= previous);
+            previous = elapsed;
+        }
+
+        let lap = stopwatch.lap();
+        assert!(!lap.is_negative());
+        assert!(lap <= stopwatch.elapsed());
+
+        stopwatch.reset();
+        assert!(stopwatch.elapsed() < Duration::new(60, 0));
+    }
+}
@@ -0,0 +1,152 @@
+// Parsing of dates and times laid out according to a strftime-style pattern
+
+use alloc::borrow::ToOwned;
+use alloc::format;
+
+use crate::error::Error;
+use crate::iso8601::{fraction_to_attos, Cursor};
+
+// The fields extracted by a pattern. Fields that the pattern did not mention
+// are left as `None`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Fields {
+    pub year: Option<i32>,
+    pub month: Option<u8>,
+    pub day: Option<u8>,
+    pub day_of_year: Option<u16>,
+    pub hour: Option<u8>,
+    pub minute: Option<u8>,
+    pub second: Option<u8>,
+    pub attosecond: Option<u64>,
+}
+
+// Parse `input` according to `pattern`.
+//
+// Supported specifiers are `%Y` (year, optionally signed), `%m`, `%d`, `%H`,
+// `%M`, `%S` (one or two digits each), `%j` (day of the year, one to three
+// digits), `%f` (one to eighteen fractional second digits) and `%%` (a literal
+// percent sign). All other characters in the pattern must match the input
+// exactly, and the entire input must be consumed.
+//
+// Only the syntax is checked here; range checking is left to the caller.
+#[allow(clippy::cast_possible_truncation)]
+#[allow(clippy::cast_sign_loss)]
+pub fn parse(pattern: &str, input: &str) -> Result<Fields, Error> {
+    let mut c = Cursor::new(input);
+    let mut fields = Fields::default();
+    let mut chars = pattern.chars();
+
+    while let Some(ch) = chars.next() {
+        if ch != '%' {
+            let mut buf = [0; 4];
+            if !c.eat_str(ch.encode_utf8(&mut buf)) {
+                return Err(c.error(&format!("expected '{ch}'")));
+            }
+            continue;
+        }
+
+        let Some(spec) = chars.next() else {
+            return Err(Error::ParseError("Pattern ends with a lone '%'".to_owned()));
+        };
+
+        // Without a separator after the year we cannot tell where it ends, so
+        // in that case it must have exactly four digits.
+        let rest = chars.as_str();
+        let abutting = rest.starts_with('%') && !rest.starts_with("%%");
+
+        match spec {
+            'Y' => {
+                let negative = c.eat(b"+-") == Some(b'-');
+                let year = if abutting {
+                    c.up_to(4, "year")?
+                } else {
+                    c.up_to(usize::MAX, "year")?
+                };
+                let year = if negative { -year } else { year };
+                let year = i32::try_from(year).map_err(|_| Error::RangeError)?;
+                set(&mut fields.year, year, "%Y")?;
+            }
+            'm' => set(&mut fields.month, c.up_to(2, "month")? as u8, "%m")?,
+            'd' => set(&mut fields.day, c.up_to(2, "day")? as u8, "%d")?,
+            'H' => set(&mut fields.hour, c.up_to(2, "hour")? as u8, "%H")?,
+            'M' => set(&mut fields.minute, c.up_to(2, "minute")? as u8, "%M")?,
+            'S' => set(&mut fields.second, c.up_to(2, "second")? as u8, "%S")?,
+            'j' => set(
+                &mut fields.day_of_year,
+                c.up_to(3, "day of year")? as u16,
+                "%j",
+            )?,
+            'f' => {
+                let digits = c.digits();
+                if digits.is_empty() {
+                    return Err(c.error("expected fractional seconds"));
+                }
+                set(&mut fields.attosecond, fraction_to_attos(digits)?, "%f")?;
+            }
+            '%' => {
+                if !c.eat_str("%") {
+                    return Err(c.error("expected '%'"));
+                }
+            }
+            other => {
+                return Err(Error::ParseError(format!(
+                    "Unsupported pattern specifier '%{other}'"
+                )));
+            }
+        }
+    }
+
+    if !c.at_end() {
+        return Err(c.error("unexpected trailing characters"));
+    }
+    Ok(fields)
+}
+
+// Store a field, rejecting patterns that mention it twice
+fn set<T>(field: &mut Option<T>, value: T, spec: &str) -> Result<(), Error> {
+    if field.is_some() {
+        return Err(Error::ParseError(format!(
+            "Pattern specifier {spec} is repeated"
+        )));
+    }
+    *field = Some(value);
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse, Fields};
+    use crate::error::Error;
+
+    #[test]
+    fn test_parse_pattern() {
+        crate::setup_logging();
+
+        let f = parse("%Y%m%d", "20240229").unwrap();
+        assert_eq!(f.year, Some(2024));
+        assert_eq!(f.month, Some(2));
+        assert_eq!(f.day, Some(29));
+
+        let f = parse("%Y-%j", "-0044-074").unwrap();
+        assert_eq!(f.year, Some(-44));
+        assert_eq!(f.day_of_year, Some(74));
+
+        let f = parse("%S.%f 100%%", "7.25 100%").unwrap();
+        assert_eq!(f.second, Some(7));
+        assert_eq!(f.attosecond, Some(250_000_000_000_000_000));
+
+        assert_eq!(parse("", "").unwrap(), Fields::default());
+
+        assert!(matches!(parse("%Y", "2024x"), Err(Error::ParseError(_))));
+        assert!(matches!(
+            parse("%Y %q", "2024 1"),
+            Err(Error::ParseError(_))
+        ));
+        assert!(matches!(
+            parse("%Y %Y", "2024 2024"),
+            Err(Error::ParseError(_))
+        ));
+        assert!(matches!(parse("%Y%", "2024"), Err(Error::ParseError(_))));
+        assert!(matches!(parse("%m", "123"), Err(Error::ParseError(_))));
+    }
+}
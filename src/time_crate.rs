@@ -0,0 +1,135 @@
+// Conversions to and from types in the `time` crate
+
+use core::convert::TryFrom;
+
+use crate::calendar::Gregorian;
+use crate::date_time::DateTime;
+use crate::error::Error;
+use crate::standard::Utc;
+
+impl TryFrom<time::PrimitiveDateTime> for DateTime<Gregorian, Utc> {
+    type Error = Error;
+
+    /// The `PrimitiveDateTime` is presumed to be in UTC
+    fn try_from(pdt: time::PrimitiveDateTime) -> Result<Self, Self::Error> {
+        Self::new(
+            pdt.year(),
+            u8::from(pdt.month()),
+            pdt.day(),
+            pdt.hour(),
+            pdt.minute(),
+            pdt.second(),
+            crate::attos_from_rescaled(u64::from(pdt.nanosecond()), 9),
+        )
+    }
+}
+
+impl TryFrom<time::OffsetDateTime> for DateTime<Gregorian, Utc> {
+    type Error = Error;
+
+    fn try_from(odt: time::OffsetDateTime) -> Result<Self, Self::Error> {
+        let odt = odt
+            .checked_to_offset(time::UtcOffset::UTC)
+            .ok_or(Error::RangeError)?;
+        TryFrom::try_from(time::PrimitiveDateTime::new(odt.date(), odt.time()))
+    }
+}
+
+impl TryFrom<DateTime<Gregorian, Utc>> for time::PrimitiveDateTime {
+    type Error = Error;
+
+    /// The `PrimitiveDateTime` will be in UTC
+    ///
+    /// The `time` crate only has nanosecond precision, so attoseconds are truncated.
+    ///
+    /// The `time` crate does not handle leap seconds, so a leap second (`:60`) is
+    /// mapped to `:59.999999999`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if the year is outside of the range
+    /// supported by the `time` crate.
+    #[allow(clippy::cast_possible_truncation)]
+    fn try_from(dt: DateTime<Gregorian, Utc>) -> Result<Self, Self::Error> {
+        let month = time::Month::try_from(dt.month()).map_err(|_| Error::RangeError)?;
+        let date = time::Date::from_calendar_date(dt.year(), month, dt.day())
+            .map_err(|_| Error::RangeError)?;
+
+        // time does not handle leap seconds
+        let (second, nanosecond) = if dt.second() == 60 {
+            (59, 999_999_999)
+        } else {
+            (dt.second(), crate::rescale_attos(dt.attosecond(), 9) as u32)
+        };
+        let time = time::Time::from_hms_nano(dt.hour(), dt.minute(), second, nanosecond)
+            .map_err(|_| Error::RangeError)?;
+
+        Ok(Self::new(date, time))
+    }
+}
+
+impl TryFrom<DateTime<Gregorian, Utc>> for time::OffsetDateTime {
+    type Error = Error;
+
+    /// The `OffsetDateTime` will have a UTC offset
+    ///
+    /// The `time` crate only has nanosecond precision, so attoseconds are truncated.
+    ///
+    /// The `time` crate does not handle leap seconds, so a leap second (`:60`) is
+    /// mapped to `:59.999999999`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if the year is outside of the range
+    /// supported by the `time` crate.
+    fn try_from(dt: DateTime<Gregorian, Utc>) -> Result<Self, Self::Error> {
+        let pdt: time::PrimitiveDateTime = TryFrom::try_from(dt)?;
+        Ok(pdt.assume_utc())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::calendar::Gregorian;
+    use crate::date_time::DateTime;
+    use crate::epoch::Epoch;
+    use crate::standard::Utc;
+
+    #[test]
+    fn test_time_j2000() {
+        crate::setup_logging();
+
+        let j2000: DateTime<Gregorian, Utc> = From::from(Epoch::J2000_0.as_instant());
+
+        let expected = time::PrimitiveDateTime::new(
+            time::Date::from_calendar_date(2000, time::Month::January, 1).unwrap(),
+            time::Time::from_hms_milli(11, 58, 55, 816).unwrap(),
+        )
+        .assume_utc();
+
+        let odt: time::OffsetDateTime = TryFrom::try_from(j2000).unwrap();
+        assert_eq!(odt, expected);
+
+        let back: DateTime<Gregorian, Utc> = TryFrom::try_from(expected).unwrap();
+        assert_eq!(back, j2000);
+
+        // Non-UTC offsets are converted to UTC
+        let offset = expected.to_offset(time::UtcOffset::from_hms(5, 30, 0).unwrap());
+        let back: DateTime<Gregorian, Utc> = TryFrom::try_from(offset).unwrap();
+        assert_eq!(back, j2000);
+    }
+
+    #[test]
+    fn test_time_leap_second_and_range() {
+        crate::setup_logging();
+
+        let leap = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 60, 0).unwrap();
+        let pdt: time::PrimitiveDateTime = TryFrom::try_from(leap).unwrap();
+        assert_eq!(pdt.second(), 59);
+        assert_eq!(pdt.nanosecond(), 999_999_999);
+
+        let far = DateTime::<Gregorian, Utc>::new(1_000_000, 1, 1, 0, 0, 0, 0).unwrap();
+        let r: Result<time::OffsetDateTime, _> = TryFrom::try_from(far);
+        assert!(r.is_err());
+    }
+}
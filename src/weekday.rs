@@ -0,0 +1,129 @@
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// A day of the week
+///
+/// Days are ordered and numbered as in ISO 8601, from Monday (1) through Sunday (7).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Weekday {
+    /// Monday (ISO day 1)
+    Monday,
+    /// Tuesday (ISO day 2)
+    Tuesday,
+    /// Wednesday (ISO day 3)
+    Wednesday,
+    /// Thursday (ISO day 4)
+    Thursday,
+    /// Friday (ISO day 5)
+    Friday,
+    /// Saturday (ISO day 6)
+    Saturday,
+    /// Sunday (ISO day 7)
+    Sunday,
+}
+
+impl Weekday {
+    pub(crate) const ALL: [Self; 7] = [
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+        Self::Saturday,
+        Self::Sunday,
+    ];
+
+    /// The full English name, e.g. "Monday"
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match *self {
+            Self::Monday => "Monday",
+            Self::Tuesday => "Tuesday",
+            Self::Wednesday => "Wednesday",
+            Self::Thursday => "Thursday",
+            Self::Friday => "Friday",
+            Self::Saturday => "Saturday",
+            Self::Sunday => "Sunday",
+        }
+    }
+
+    /// The three letter English abbreviation, e.g. "Mon"
+    #[must_use]
+    pub const fn short_str(&self) -> &'static str {
+        match *self {
+            Self::Monday => "Mon",
+            Self::Tuesday => "Tue",
+            Self::Wednesday => "Wed",
+            Self::Thursday => "Thu",
+            Self::Friday => "Fri",
+            Self::Saturday => "Sat",
+            Self::Sunday => "Sun",
+        }
+    }
+
+    /// The ISO 8601 day number, from Monday (1) through Sunday (7)
+    #[must_use]
+    pub const fn number(&self) -> u8 {
+        *self as u8 + 1
+    }
+
+    /// The weekday with the given ISO 8601 day number, from Monday (1) through
+    /// Sunday (7)
+    ///
+    /// # Errors
+    ///
+    /// Will return `Error::RangeError` if `number` is not in the range 1 .. 7
+    pub fn from_iso(number: u8) -> Result<Self, Error> {
+        match number {
+            1..=7 => Ok(Self::ALL[usize::from(number - 1)]),
+            _ => Err(Error::RangeError),
+        }
+    }
+
+    /// The following day, wrapping from Sunday to Monday
+    #[must_use]
+    pub const fn succ(&self) -> Self {
+        Self::ALL[(*self as usize + 1) % 7]
+    }
+
+    /// The preceding day, wrapping from Monday to Sunday
+    #[must_use]
+    pub const fn pred(&self) -> Self {
+        Self::ALL[(*self as usize + 6) % 7]
+    }
+}
+
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Weekday;
+
+    #[test]
+    fn test_weekday() {
+        crate::setup_logging();
+
+        for n in 1..=7 {
+            let w = Weekday::from_iso(n).unwrap();
+            assert_eq!(w.number(), n);
+            assert_eq!(w.succ().pred(), w);
+            assert_eq!(w.short_str(), &w.as_str()[..3]);
+        }
+        assert!(Weekday::from_iso(0).is_err());
+        assert!(Weekday::from_iso(8).is_err());
+
+        assert_eq!(Weekday::Sunday.succ(), Weekday::Monday);
+        assert_eq!(Weekday::Monday.pred(), Weekday::Sunday);
+        assert_eq!(format!("{}", Weekday::Wednesday), "Wednesday");
+        assert!(Weekday::Monday < Weekday::Sunday);
+    }
+}
@@ -0,0 +1,69 @@
+// Check the warning logged when converting UTC after the leap second table
+// expires.
+//
+// This installs its own logger, which can only be done once per process, so it
+// is kept apart from the unit tests, which use `pretty_env_logger`.
+
+use std::sync::{Mutex, PoisonError};
+
+use astrotime::{DateTime, Gregorian, Instant, Utc};
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+// Keeps the level and message of every record logged by this crate
+struct Capture(Mutex<Vec<(Level, String)>>);
+
+impl Log for Capture {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.target().starts_with("astrotime")
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if self.enabled(record.metadata()) {
+            self.0
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push((record.level(), record.args().to_string()));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static CAPTURE: Capture = Capture(Mutex::new(Vec::new()));
+
+fn captured() -> Vec<(Level, String)> {
+    CAPTURE
+        .0
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()
+}
+
+#[test]
+fn test_expiry_warning() {
+    log::set_logger(&CAPTURE).unwrap();
+    log::set_max_level(LevelFilter::Info);
+
+    let utc = |year| -> Instant {
+        From::from(DateTime::<Gregorian, Utc>::new(year, 1, 1, 0, 0, 0, 0).unwrap())
+    };
+
+    // Nothing is logged before the table expires
+    let _ = utc(2026);
+    assert!(captured().is_empty());
+
+    // Afterwards a warning is logged, the first time only
+    let _ = utc(2027);
+    let _ = utc(2028);
+    let _: DateTime<Gregorian, Utc> = From::from(utc(2029));
+    let records = captured();
+    assert_eq!(records.len(), 1);
+    let (level, message) = &records[0];
+    assert_eq!(*level, Level::Warn);
+    assert_eq!(
+        message,
+        "Converting UTC after the leap second table expires \
+         (2026-06-28 00:00:00.000000000000000000 Gregorian UTC). Any leap \
+         seconds announced since are not accounted for."
+    );
+}
@@ -0,0 +1,226 @@
+// Replace the leap second table with hypothetical ones.
+//
+// The table is global, so this is kept apart from the unit tests, which run in
+// the same process and expect the compiled in table, and the tests here take
+// turns.
+
+#![cfg(feature = "std")]
+
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use astrotime::leaps::{self, LeapEvent, LeapPolicy};
+use astrotime::{DateTime, Duration, Error, Gregorian, Instant, Tai, Utc};
+
+static TABLE: Mutex<()> = Mutex::new(());
+
+// Start with the compiled in table, even if a failed test left another one
+fn lock_table() -> MutexGuard<'static, ()> {
+    let lock = TABLE.lock().unwrap_or_else(PoisonError::into_inner);
+    leaps::reset_leap_events();
+    lock
+}
+
+// The compiled in table with an inserted leap second at the end of 2028
+fn with_2028_leap() -> (Vec<LeapEvent>, Instant) {
+    let mut events = leaps::leap_events();
+    let end = Instant::from(DateTime::<Gregorian, Tai>::new(2029, 1, 1, 0, 0, 38, 0).unwrap());
+    events.push(LeapEvent {
+        instant: end,
+        delta: 1,
+    });
+    (events, end - Duration::new(1, 0))
+}
+
+#[test]
+fn test_negative_leap_second() {
+    let _lock = lock_table();
+    let mut events = leaps::leap_events();
+    assert_eq!(events.len(), 28);
+    assert!(events.iter().all(|event| event.delta == 1));
+    assert_eq!(
+        events[27].instant,
+        Instant::from(DateTime::<Gregorian, Utc>::new(2017, 1, 1, 0, 0, 0, 0).unwrap())
+    );
+
+    // A hypothetical removed leap second at the end of June 2030, after which
+    // TAI - UTC is 36 seconds
+    let removal = Instant::from(DateTime::<Gregorian, Tai>::new(2030, 7, 1, 0, 0, 36, 0).unwrap());
+    events.push(LeapEvent {
+        instant: removal,
+        delta: -1,
+    });
+
+    // Bad tables are refused
+    let mut unordered = events.clone();
+    unordered.swap(27, 28);
+    assert!(matches!(
+        leaps::set_leap_events(unordered),
+        Err(Error::General(_))
+    ));
+    let mut double = events.clone();
+    double[28].delta = -2;
+    assert!(matches!(
+        leaps::set_leap_events(double),
+        Err(Error::RangeError)
+    ));
+
+    leaps::set_leap_events(events.clone()).unwrap();
+    assert_eq!(leaps::leap_events(), events);
+
+    // 23:59:58 is followed by 00:00:00
+    let utc = DateTime::<Gregorian, Utc>::from;
+    let second = Duration::new(1, 0);
+    assert_eq!(utc(removal - second).time(), (23, 59, 58, 0));
+    assert_eq!(utc(removal - second).date(), (2030, 6, 30));
+    assert_eq!(utc(removal).time(), (0, 0, 0, 0));
+    assert_eq!(utc(removal).date(), (2030, 7, 1));
+
+    // And in the other direction
+    let before = DateTime::<Gregorian, Utc>::new(2030, 6, 30, 23, 59, 58, 0).unwrap();
+    let after = DateTime::<Gregorian, Utc>::new(2030, 7, 1, 0, 0, 0, 0).unwrap();
+    assert_eq!(Instant::from(after), removal);
+    assert_eq!(Instant::from(after) - Instant::from(before), second);
+    assert_eq!(before.seconds_in_day(), 86399);
+    assert_eq!(after.seconds_in_day(), 86400);
+    // 23:59:59 does not exist that day
+    assert!(DateTime::<Gregorian, Utc>::checked_new(2030, 6, 30, 23, 59, 58, 0).is_ok());
+    assert!(matches!(
+        DateTime::<Gregorian, Utc>::checked_new(2030, 6, 30, 23, 59, 59, 0),
+        Err(Error::RangeError)
+    ));
+    assert!(DateTime::<Gregorian, Utc>::checked_new(2030, 6, 29, 23, 59, 59, 0).is_ok());
+    assert_eq!(leaps::tai_minus_utc(removal - second), Duration::new(37, 0));
+    assert_eq!(leaps::tai_minus_utc(removal), Duration::new(36, 0));
+
+    // It has no 23:59:60, so the leap second queries skip it
+    assert_eq!(leaps::leap_instants().len(), 28);
+    assert_eq!(leaps::next_leap_instant(removal - second * 86400.0), None);
+    assert!(!leaps::is_during_leap_second(removal - second));
+
+    // Which counts as -1 when reckoning elapsed time from the fields
+    assert_eq!(
+        leaps::leap_seconds_between(Instant::from(before), Instant::from(after)),
+        -1
+    );
+    assert_eq!(leaps::leap_seconds_between(removal - second, removal), -1);
+    assert_eq!(leaps::leap_seconds_between(removal, removal + second), 0);
+    let start = Instant::from(DateTime::<Gregorian, Utc>::new(2016, 1, 1, 0, 0, 0, 0).unwrap());
+    assert_eq!(leaps::leap_seconds_between(start, Instant::from(after)), 0);
+
+    // The compiled in table has no such thing
+    leaps::reset_leap_events();
+    assert_eq!(leaps::leap_events().len(), 28);
+    assert_eq!(
+        Instant::from(after) - Instant::from(before),
+        Duration::new(2, 0)
+    );
+    assert_eq!(leaps::tai_minus_utc(removal), Duration::new(37, 0));
+    assert_eq!(
+        leaps::leap_seconds_between(Instant::from(before), Instant::from(after)),
+        0
+    );
+}
+
+#[test]
+fn test_next_and_previous_leap_instants() {
+    let _lock = lock_table();
+
+    let (events, leap) = with_2028_leap();
+    let jan_2017 = Instant::from(DateTime::<Gregorian, Utc>::new(2017, 1, 1, 0, 0, 0, 0).unwrap());
+    let jan_2030 = Instant::from(DateTime::<Gregorian, Utc>::new(2030, 1, 1, 0, 0, 0, 0).unwrap());
+    assert_eq!(leaps::next_leap_instant(jan_2017), None);
+
+    leaps::set_leap_events(events).unwrap();
+    assert_eq!(leaps::leap_instants().len(), 29);
+    assert_eq!(leaps::leap_instants().last(), Some(leap));
+    assert_eq!(leaps::next_leap_instant(jan_2017), Some(leap));
+    assert_eq!(leaps::next_leap_instant(leap), None);
+    assert_eq!(leaps::previous_leap_instant(jan_2030), Some(leap));
+    assert_eq!(
+        DateTime::<Gregorian, Utc>::from(leap - Duration::new(1, 0)),
+        DateTime::new(2028, 12, 31, 23, 59, 59, 0).unwrap()
+    );
+
+    leaps::reset_leap_events();
+    assert_eq!(leaps::next_leap_instant(jan_2017), None);
+    assert_eq!(
+        leaps::previous_leap_instant(jan_2030),
+        Some(jan_2017 - Duration::new(1, 0))
+    );
+}
+
+#[test]
+fn test_leap_dates_and_during_leap_second() {
+    let _lock = lock_table();
+
+    let (events, leap) = with_2028_leap();
+    let half = Duration::new(0, 500_000_000_000_000_000);
+    assert!(!leaps::is_during_leap_second(leap + half));
+
+    leaps::set_leap_events(events).unwrap();
+    assert!(leaps::is_during_leap_second(leap));
+    assert!(leaps::is_during_leap_second(leap + half));
+    assert!(!leaps::is_during_leap_second(leap + Duration::new(1, 0)));
+    assert_eq!(leaps::leap_dates().len(), 29);
+    let last = leaps::leap_dates().next_back().unwrap();
+    assert_eq!(last, DateTime::new(2028, 12, 31, 23, 59, 60, 0).unwrap());
+    assert_eq!(Instant::from(last), leap);
+
+    leaps::reset_leap_events();
+    assert!(!leaps::is_during_leap_second(leap + half));
+    assert_eq!(leaps::leap_dates().len(), 28);
+}
+
+#[test]
+fn test_install_a_loaded_list() {
+    let _lock = lock_table();
+
+    // The sample list, with the hypothetical leap second at the end of 2028
+    let list = format!(
+        "{}4070908800\t38\t# 1 Jan 2029\n",
+        include_str!("data/leap-seconds.list")
+    );
+    let events = leaps::load_from_str(&list).unwrap();
+    let (expected, leap) = with_2028_leap();
+    assert_eq!(events, expected);
+
+    leaps::set_leap_events(events).unwrap();
+    assert_eq!(leaps::leap_events(), expected);
+    assert_eq!(leaps::leap_instants().last(), Some(leap));
+    let after = Instant::from(DateTime::<Gregorian, Utc>::new(2029, 1, 1, 0, 0, 0, 0).unwrap());
+    assert_eq!(after, leap + Duration::new(1, 0));
+    assert_eq!(leaps::tai_minus_utc(after), Duration::new(38, 0));
+}
+
+#[test]
+fn test_leap_policy_with_extended_table() {
+    let _lock = lock_table();
+
+    let (events, _) = with_2028_leap();
+    let dt = DateTime::<Gregorian, Utc>::new(2030, 6, 1, 0, 0, 0, 0).unwrap();
+    let compiled = Instant::from(dt);
+
+    leaps::set_leap_events(events).unwrap();
+    let table = dt.to_instant_with_policy(LeapPolicy::Table);
+    let frozen = dt.to_instant_with_policy(LeapPolicy::FreezeLatest);
+    assert_eq!(table, Instant::from(dt));
+    assert_eq!(table - compiled, Duration::new(1, 0));
+    assert_eq!(frozen, compiled);
+
+    // And in the other direction
+    assert_eq!(
+        DateTime::<Gregorian, Utc>::from_instant_with_policy(table, LeapPolicy::Table),
+        dt
+    );
+    assert_eq!(
+        DateTime::<Gregorian, Utc>::from_instant_with_policy(frozen, LeapPolicy::FreezeLatest),
+        dt
+    );
+
+    // Before the table expires, they agree
+    let early = DateTime::<Gregorian, Utc>::new(2026, 1, 1, 0, 0, 0, 0).unwrap();
+    assert_eq!(
+        early.to_instant_with_policy(LeapPolicy::FreezeLatest),
+        early.to_instant_with_policy(LeapPolicy::Table)
+    );
+}
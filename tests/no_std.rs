@@ -0,0 +1,73 @@
+// Exercise the core types through their `no_std` API, including the entry
+// points that do floating point arithmetic.
+//
+// This does not prove the library builds without `std`: the dev-dependencies
+// link `std` into the test build, which makes its inherent `f64` methods
+// resolve in the library too. Check that with:
+//
+//     cargo build --no-default-features
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::ToString;
+use astrotime::{DateTime, Duration, Gregorian, Instant, Julian, Tai, Tt, Utc};
+use core::convert::TryFrom;
+
+#[test]
+fn test_no_std_date_time_arithmetic() {
+    let dt = DateTime::<Gregorian, Tt>::new(2000, 2, 28, 23, 0, 0, 0).unwrap();
+    let later = dt + Duration::new(3600 * 2, 500_000_000_000_000_000);
+    assert_eq!(
+        later,
+        DateTime::<Gregorian, Tt>::new(2000, 2, 29, 1, 0, 0, 500_000_000_000_000_000).unwrap()
+    );
+    assert_eq!(later - dt, Duration::new(7200, 500_000_000_000_000_000));
+    assert_eq!(
+        later - Duration::HOUR,
+        dt + Duration::new(3600, 500_000_000_000_000_000)
+    );
+
+    let julian = DateTime::<Julian, Tt>::try_from(later).unwrap();
+    assert_eq!(Instant::from(julian), Instant::from(later));
+}
+
+#[test]
+fn test_no_std_fractional_days() {
+    let dt = DateTime::<Gregorian, Utc>::from_year_and_fractional_day(2008, 264.5).unwrap();
+    assert_eq!(dt.date(), (2008, 9, 20));
+    assert_eq!(dt.time(), (12, 0, 0, 0));
+    assert_eq!(dt.day_fraction(), 0.5);
+
+    let noon = DateTime::<Gregorian, Tt>::from_day_number_and_fraction(0, 0.25).unwrap();
+    assert_eq!(noon.time(), (6, 0, 0, 0));
+
+    let j2000 = Instant::from_julian_day_f64(2_451_545.0);
+    assert_eq!(j2000.as_julian_day_parts(), (2_451_545, 0.0));
+    assert_eq!(Duration::from_days_f64(1.5), Duration::new(129_600, 0));
+}
+
+#[test]
+fn test_no_std_utc_leap_seconds() {
+    let before = DateTime::<Gregorian, Utc>::new(2016, 12, 31, 23, 59, 59, 0).unwrap();
+    let after = DateTime::<Gregorian, Utc>::new(2017, 1, 1, 0, 0, 0, 0).unwrap();
+    assert_eq!(
+        Instant::from(after) - Instant::from(before),
+        Duration::new(2, 0)
+    );
+
+    let tai: DateTime<Gregorian, Tai> = From::from(Instant::from(after));
+    assert_eq!(tai.time(), (0, 0, 37, 0));
+}
+
+#[test]
+fn test_no_std_formatting_and_parsing() {
+    let dt = DateTime::<Gregorian, Tt>::new(1969, 7, 20, 20, 17, 40, 0).unwrap();
+    assert!(dt.to_string().starts_with("1969-07-20 20:17:40"));
+    let parsed: DateTime<Gregorian, Tt> = "1969-07-20T20:17:40".parse().unwrap();
+    assert_eq!(parsed, dt);
+    assert!(Instant::from(dt)
+        .as_julian_day_formatted()
+        .starts_with("JD 2440423"));
+}